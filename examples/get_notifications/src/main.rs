@@ -4,7 +4,8 @@ use clap::Parser;
 use std::path::PathBuf;
 use url::Url;
 use std::sync::Arc;
-use bisky::lexicon::app::bsky::notification::{Notification, NotificationRecord};
+use bisky::lexicon::app::bsky::notification::ListNotificationsNotification;
+use bisky::lexicon::KnownRecord;
 
 #[derive(Parser, Debug)]
 #[clap(author, version, about, long_about = None)]
@@ -44,6 +45,6 @@ async fn main() {
     println!("Notif Count: {:#?}", notification_count);
     let notifications = me.list_notifications(30).await.unwrap();
     // // println!("Notifications\n{:#?}", notifications);
-    // println!("Notifications\n{:#?}", notifications.into_iter().filter(|n| n.reason == "follow").collect::<Vec<Notification<NotificationRecord>>>());
+    // println!("Notifications\n{:#?}", notifications.into_iter().filter(|n| n.reason == "follow").collect::<Vec<ListNotificationsNotification<KnownRecord>>>());
     me.update_seen().await.unwrap();
 }
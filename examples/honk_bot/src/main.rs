@@ -1,12 +1,12 @@
 use bisky::atproto::{Client, ClientBuilder, UserSession};
 use bisky::bluesky::Bluesky;
-use bisky::lexicon::app::bsky::feed::{ImagesEmbed, ReplyRef};
-use bisky::lexicon::app::bsky::embed::{Image};
-use bisky::lexicon::app::bsky::notification::{Notification, NotificationRecord};
+use bisky::lexicon::app::bsky::feed::PostReplyRef;
+use bisky::lexicon::app::bsky::embed::{Images, ImagesImage};
+use bisky::lexicon::app::bsky::notification::ListNotificationsNotification;
 use bisky::lexicon::com::atproto::repo::StrongRef;
 use bisky::lexicon::app::bsky::feed::Post;
-use bisky::lexicon::app::bsky::notification::NotificationRecord::Post as NotificationPost;
-use bisky::lexicon::app::bsky::feed::Embeds;
+use bisky::lexicon::app::bsky::feed::PostEmbed;
+use bisky::lexicon::KnownRecord;
 use bisky::storage::File;
 use clap::Parser;
 use std::path::PathBuf;
@@ -63,7 +63,7 @@ async fn main() {
         let mut me = bsky.me().unwrap();
         let notifications = me.list_notifications(10).await.unwrap();
         me.update_seen().await.unwrap();
-        let mentions =  notifications.into_iter().filter(|n| (n.reason == "mention" && n.is_read == false)).collect::<Vec<Notification<NotificationRecord>>>();
+        let mentions =  notifications.into_iter().filter(|n| (n.reason == "mention" && n.is_read == false)).collect::<Vec<ListNotificationsNotification<KnownRecord>>>();
         if !mentions.is_empty(){
             println!("Mentions\n{:#?}", mentions);
         }
@@ -72,7 +72,7 @@ async fn main() {
             let uri = mention.uri;
             let cid = mention.cid;
             let  (text, reply) = match mention.record{
-                NotificationRecord::Post(p) => (p.text, p.reply),
+                KnownRecord::Post(p) => (p.text, p.reply),
                 _ => panic!("What are you feeding me Seymore?"),
             };
             println!("POSTText {:#?}",text);
@@ -82,7 +82,7 @@ async fn main() {
 
                 // As I understand this, if there is no parent(it is the root), then both parent and root uri/cid can be set to the ones in the root of the mention
                 // If reply is Some(), then we'll need the uri/cid of the post from the root for parent, and the uri/cid from reply: root for the root
-                let mut resp_reply_ref = ReplyRef{
+                let mut resp_reply_ref = PostReplyRef{
                     parent: StrongRef{uri: uri.clone(), cid: cid.clone()}, 
                     root: StrongRef{uri, cid},
                 };
@@ -97,16 +97,21 @@ async fn main() {
 
                 let blob_output = me.upload_blob(&img, "image/jpeg").await.unwrap();
                 println!("Blob: {:#?}", blob_output.blob);
-                let image = Image{image:blob_output.blob, alt: "HONK".to_string()};
-                let images_embed = ImagesEmbed{images: vec!(image)};
-                let embed = Embeds::Images(images_embed);
+                let image = ImagesImage{image:blob_output.blob, alt: "HONK".to_string(), aspect_ratio: None};
+                let images_embed = Images{images: vec!(image)};
+                let embed = PostEmbed::Images(Box::new(images_embed));
         
                 me.post(Post {
-                    rust_type: Some("app.bsky.feed.post".to_string()),
                     text: "HONK".to_string(),
-                    created_at: chrono::Utc::now(),
+                    facets: None,
+                    created_at: chrono::Utc::now().into(),
                     embed: Some(embed),
                     reply: Some(resp_reply_ref),
+                    langs: None,
+                    labels: None,
+                    tags: None,
+                    entities: None,
+                    extra: Default::default(),
                 })
                 .await
                 .unwrap();
@@ -117,7 +122,7 @@ async fn main() {
 
                 // As I understand this, if there is no parent(it is the root), then both parent and root uri/cid can be set to the ones in the root of the mention
                 // If reply is Some(), then we'll need the uri/cid of the post from the root for parent, and the uri/cid from reply: root for the root
-                let mut resp_reply_ref = ReplyRef{
+                let mut resp_reply_ref = PostReplyRef{
                     parent: StrongRef{uri: uri.clone(), cid: cid.clone()}, 
                     root: StrongRef{uri, cid},
                 };
@@ -144,11 +149,16 @@ async fn main() {
                 };
         
                 me.post(Post {
-                    rust_type: Some("app.bsky.feed.post".to_string()),
                     text: format!("You rolled a {roll}.\n{msg}"),
-                    created_at: chrono::Utc::now(),
+                    facets: None,
+                    created_at: chrono::Utc::now().into(),
                     embed: None,
                     reply: Some(resp_reply_ref),
+                    langs: None,
+                    labels: None,
+                    tags: None,
+                    entities: None,
+                    extra: Default::default(),
                 })
                 .await
                 .unwrap();
@@ -1,7 +1,8 @@
 use bisky::atproto::{Client, ClientBuilder, UserSession};
 use bisky::bluesky::Bluesky;
-use bisky::lexicon::app::bsky::feed::{Post, Embeds, ImagesEmbed};
-use bisky::lexicon::app::bsky::embed::{Image};
+use bisky::lexicon::app::bsky::feed::{Post, PostEmbed};
+use bisky::lexicon::app::bsky::embed::{Images, ImagesImage};
+use bisky::richtext::RichText;
 
 use bisky::storage::{File, Storage as _};
 use clap::Parser;
@@ -41,9 +42,9 @@ async fn main() {
     
     let blob_output = me.upload_blob(&image, "image/jpeg").await.unwrap();
     println!("Blob: {:#?}", blob_output.blob);
-    let image = Image{image:blob_output.blob, alt: "HONK WITH RUST".to_string()};
-    let images_embed = ImagesEmbed{images: vec!(image)};
-    let embed = Embeds::Images(images_embed);
+    let image = ImagesImage{image:blob_output.blob, alt: "HONK WITH RUST".to_string(), aspect_ratio: None};
+    let images_embed = Images{images: vec!(image)};
+    let embed = PostEmbed::Images(Box::new(images_embed));
 
     println!(
         "{:#?}",
@@ -51,10 +52,8 @@ async fn main() {
             .me()
             .unwrap()
             .post(Post {
-                rust_type: Some("app.bsky.feed.post".to_string()),
-                text: args.post_text,
-                created_at: chrono::Utc::now(),
                 embed: Some(embed),
+                ..RichText::new(args.post_text).into_post()
             })
             .await
             .unwrap()
@@ -31,7 +31,7 @@ async fn main() {
     .unwrap();
 
     let mut bsky = Bluesky::new(client);
-    let mut profile = bsky.user(args.username).unwrap();
+    let mut profile = bsky.user(&args.username).unwrap();
     let mut stream = profile.stream_posts().await.unwrap();
 
     while let Ok(record) = stream.next().await {
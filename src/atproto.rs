@@ -1,22 +1,38 @@
+use crate::car::{CarIndex, CarReader};
 use crate::errors::{ApiError, BiskyError};
+use crate::firehose::Firehose;
 use crate::lexicon::app::bsky::actor::ProfileView;
 use crate::lexicon::app::bsky::feed::{
-    GetLikesLike, GetLikesOutput, GetPostThreadOutput, ThreadViewPostEnum,
+    GetLikesLike, GetLikesOutput, GetPostThreadOutput, GetPostThreadOutputThread,
 };
 use crate::lexicon::app::bsky::graph::{GetFollowersOutput, GetFollowsOutput};
 use crate::lexicon::app::bsky::notification::{
-    ListNotificationsOutput, Notification, NotificationCount, UpdateSeen,
+    GetUnreadCountOutput, ListNotificationsNotification, ListNotificationsOutput, UpdateSeenInput,
 };
-use crate::lexicon::com::atproto::repo::{CreateRecord, ListRecordsOutput, Record};
-use crate::lexicon::com::atproto::server::{CreateUserSession, RefreshUserSession};
+use crate::lexicon::com::atproto::identity::ResolveHandleOutput;
+use crate::lexicon::com::atproto::repo::{
+    ApplyWritesCreate, ApplyWritesDelete, ApplyWritesInput, ApplyWritesInputWrite,
+    ApplyWritesOutput, ApplyWritesUpdate, CreateRecordInput, DeleteRecordInput, DeleteRecordOutput,
+    GetRecordOutput, ListRecordsOutput, ListRecordsRecord, PutRecordInput, PutRecordOutput,
+};
+use crate::lexicon::com::atproto::server::{CreateSessionOutput, RefreshSessionOutput};
 use crate::storage::Storage;
+use crate::validation::{RecordWrites, Validator};
 use chrono::{DateTime, Utc};
 use derive_builder::Builder;
+use futures::stream::BoxStream;
+use futures::{StreamExt, TryStreamExt};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
-use serde_json::json;
+use serde_json::{json, Value};
 use std::collections::VecDeque;
+use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
+use tokio_util::bytes::Bytes;
+use tokio_util::io::StreamReader;
+
+/// Body of a streamed XRPC response
+pub type ResponseReader = StreamReader<BoxStream<'static, std::io::Result<Bytes>>, Bytes>;
 
 #[derive(Debug, Default, Deserialize, Clone, Serialize)]
 pub struct Jwt {
@@ -31,8 +47,8 @@ pub struct UserSession {
     pub jwt: Jwt,
 }
 
-impl From<CreateUserSession> for UserSession {
-    fn from(create: CreateUserSession) -> Self {
+impl From<CreateSessionOutput> for UserSession {
+    fn from(create: CreateSessionOutput) -> Self {
         Self {
             did: create.did,
             handle: create.handle,
@@ -44,8 +60,8 @@ impl From<CreateUserSession> for UserSession {
     }
 }
 
-impl From<RefreshUserSession> for UserSession {
-    fn from(refresh: RefreshUserSession) -> Self {
+impl From<RefreshSessionOutput> for UserSession {
+    fn from(refresh: RefreshSessionOutput) -> Self {
         Self {
             did: refresh.did,
             handle: refresh.handle,
@@ -66,6 +82,10 @@ pub struct Client {
     storage: Option<Arc<dyn StorableSession>>,
     #[builder(default, setter(custom))]
     pub session: Option<UserSession>,
+    /// Check records against their lexicon before every create, put and applyWrites,
+    /// unless the call passes its own `validate`
+    #[builder(default)]
+    pub(crate) validate_records: bool,
 }
 
 impl ClientBuilder {
@@ -146,14 +166,14 @@ impl Client {
             return Err(BiskyError::ApiError(response.json::<ApiError>().await?));
         };
 
-        let user_session: UserSession = response.json::<CreateUserSession>().await?.into();
+        let user_session: UserSession = response.json::<CreateSessionOutput>().await?.into();
 
         self.update_session(Some(user_session)).await?;
         Ok(())
     }
 
     async fn xrpc_refresh_token(&mut self) -> Result<(), BiskyError> {
-        let Some(session) = &self.session else{
+        let Some(session) = &self.session else {
             return Err(BiskyError::MissingSession);
         };
         let response = reqwest::Client::new()
@@ -166,7 +186,7 @@ impl Client {
             .send()
             .await?
             .error_for_status()?
-            .json::<RefreshUserSession>()
+            .json::<RefreshSessionOutput>()
             .await?;
 
         let session = response.into();
@@ -222,6 +242,43 @@ impl Client {
         Ok(json)
     }
 
+    /// Like `xrpc_get`, but hands back the raw response so large bodies can be streamed
+    pub(crate) async fn xrpc_get_stream(
+        &mut self,
+        path: &str,
+        query: Option<&[(&str, &str)]>,
+    ) -> Result<reqwest::Response, BiskyError> {
+        fn make_request<T: GetService>(
+            self_: &T,
+            path: &str,
+            query: &Option<&[(&str, &str)]>,
+        ) -> Result<reqwest::RequestBuilder, BiskyError> {
+            let mut request = reqwest::Client::new()
+                .get(self_.get_service().join(&format!("xrpc/{path}")).unwrap())
+                .header("authorization", format!("Bearer {}", self_.access_token()?));
+
+            if let Some(query) = query {
+                request = request.query(query);
+            }
+
+            Ok(request)
+        }
+
+        let mut response = make_request(self, path, &query)?.send().await?;
+
+        if response.status() == reqwest::StatusCode::BAD_REQUEST {
+            let error = response.json::<ApiError>().await?;
+            if error.error == "ExpiredToken" {
+                self.xrpc_refresh_token().await?;
+                response = make_request(self, path, &query)?.send().await?;
+            } else {
+                return Err(BiskyError::ApiError(error));
+            }
+        }
+
+        Ok(response.error_for_status()?)
+    }
+
     pub(crate) async fn xrpc_post<D1: Serialize, D2: DeserializeOwned>(
         &mut self,
         path: &str,
@@ -344,7 +401,7 @@ pub struct RecordStream<'a, D: DeserializeOwned> {
     client: &'a mut Client,
     repo: &'a str,
     collection: &'a str,
-    queue: VecDeque<Record<D>>,
+    queue: VecDeque<ListRecordsRecord<D>>,
     cursor: String,
 }
 
@@ -361,7 +418,7 @@ impl From<BiskyError> for StreamError {
 }
 
 impl<'a, D: DeserializeOwned + std::fmt::Debug> RecordStream<'a, D> {
-    pub async fn next(&mut self) -> Result<Record<D>, StreamError> {
+    pub async fn next(&mut self) -> Result<ListRecordsRecord<D>, StreamError> {
         if let Some(record) = self.queue.pop_front() {
             Ok(record)
         } else {
@@ -400,12 +457,12 @@ pub struct NotificationStream<'a, D: DeserializeOwned> {
     limit: usize,
     seen_at: Option<&'a str>,
     // collection: &'a str,
-    queue: VecDeque<Notification<D>>,
+    queue: VecDeque<ListNotificationsNotification<D>>,
     cursor: String,
 }
 
 impl<'a, D: DeserializeOwned + std::fmt::Debug> NotificationStream<'a, D> {
-    pub async fn next(&mut self) -> Result<Notification<D>, StreamError> {
+    pub async fn next(&mut self) -> Result<ListNotificationsNotification<D>, StreamError> {
         if let Some(notification) = self.queue.pop_front() {
             Ok(notification)
         } else {
@@ -432,22 +489,114 @@ impl<'a, D: DeserializeOwned + std::fmt::Debug> NotificationStream<'a, D> {
         }
     }
 }
+/// Batch of writes that land in a single commit, see [`Client::repo_apply_writes`]
+pub struct ApplyWritesBuilder<'a> {
+    client: &'a mut Client,
+    repo: String,
+    writes: Vec<ApplyWritesInputWrite>,
+    validate: Option<bool>,
+    swap_commit: Option<String>,
+    /// First record that failed to serialize, reported by `send`
+    error: Option<serde_json::Error>,
+}
+
+impl<'a> ApplyWritesBuilder<'a> {
+    fn value<S: Serialize>(&mut self, record: &S) -> Value {
+        serde_json::to_value(record).unwrap_or_else(|e| {
+            self.error.get_or_insert(e);
+            Value::Null
+        })
+    }
+
+    /// Create a record, `rkey` of `None` lets the PDS pick one
+    pub fn create<S: Serialize>(
+        mut self,
+        collection: &str,
+        rkey: Option<&str>,
+        record: &S,
+    ) -> Self {
+        let value = self.value(record);
+        self.writes
+            .push(ApplyWritesInputWrite::Create(Box::new(ApplyWritesCreate {
+                collection: collection.to_string(),
+                rkey: rkey.map(str::to_string),
+                value,
+            })));
+        self
+    }
+
+    pub fn update<S: Serialize>(mut self, collection: &str, rkey: &str, record: &S) -> Self {
+        let value = self.value(record);
+        self.writes
+            .push(ApplyWritesInputWrite::Update(Box::new(ApplyWritesUpdate {
+                collection: collection.to_string(),
+                rkey: rkey.to_string(),
+                value,
+            })));
+        self
+    }
+
+    pub fn delete(mut self, collection: &str, rkey: &str) -> Self {
+        self.writes
+            .push(ApplyWritesInputWrite::Delete(Box::new(ApplyWritesDelete {
+                collection: collection.to_string(),
+                rkey: rkey.to_string(),
+            })));
+        self
+    }
+
+    /// Ask the PDS to validate (or not) against its lexicons, this also overrides the
+    /// client's `validate_records` for the batch
+    pub fn validate(mut self, validate: bool) -> Self {
+        self.validate = Some(validate);
+        self
+    }
+
+    /// Fail with `InvalidSwap` unless the repo head is still at `cid`
+    pub fn swap_commit(mut self, cid: &str) -> Self {
+        self.swap_commit = Some(cid.to_string());
+        self
+    }
+
+    pub fn writes(&self) -> &[ApplyWritesInputWrite] {
+        &self.writes
+    }
+
+    ///com.atproto.repo.applyWrites
+    pub async fn send(self) -> Result<ApplyWritesOutput, BiskyError> {
+        if let Some(error) = self.error {
+            return Err(error.into());
+        }
+        self.client
+            .com_atproto_repo_apply_writes(&ApplyWritesInput {
+                repo: self.repo,
+                validate: self.validate,
+                writes: self.writes,
+                swap_commit: self.swap_commit,
+            })
+            .await
+    }
+}
+
 impl Client {
-    // pub async fn repo_get_record<D: DeserializeOwned + std::fmt::Debug>(
-    //     &mut self,
-    //     repo: &str,
-    //     collection: &str,
-    //     rkey: Option<&str>,
-    // ) -> Result<Record<D>, BiskyError> {
-    //     let mut query = vec![("repo", repo), ("collection", collection)];
-
-    //     if let Some(rkey) = rkey {
-    //         query.push(("rkey", rkey));
-    //     }
-
-    //     self.xrpc_get("com.atproto.repo.getRecord", Some(&query))
-    //         .await
-    // }
+    ///com.atproto.repo.getRecord
+    /// Get a single record, optionally at a specific version `cid`
+    pub async fn repo_get_record<D: DeserializeOwned + std::fmt::Debug>(
+        &mut self,
+        repo: &str,
+        collection: &str,
+        rkey: &str,
+        cid: Option<&str>,
+    ) -> Result<GetRecordOutput<D>, BiskyError> {
+        let mut query = Vec::from([("repo", repo), ("collection", collection), ("rkey", rkey)]);
+
+        if let Some(cid) = cid {
+            query.push(("cid", cid));
+        }
+
+        self.xrpc_get("com.atproto.repo.getRecord", Some(&query))
+            .await
+    }
 
     pub async fn repo_list_records<D: DeserializeOwned + std::fmt::Debug>(
         &mut self,
@@ -456,7 +605,7 @@ impl Client {
         mut limit: usize,
         reverse: bool,
         mut cursor: Option<String>,
-    ) -> Result<(Vec<Record<D>>, Option<String>), BiskyError> {
+    ) -> Result<(Vec<ListRecordsRecord<D>>, Option<String>), BiskyError> {
         let reverse = reverse.to_string();
 
         let mut records = Vec::new();
@@ -492,18 +641,170 @@ impl Client {
         Ok((records, cursor))
     }
 
+    /// Check a record against the bundled `collection` lexicon without sending it.
+    /// Runs automatically on writes when the client is built with `validate_records(true)`
+    /// or the write passes `validate: Some(true)`
+    pub fn validate_record<S: Serialize>(
+        &self,
+        collection: &str,
+        record: &S,
+    ) -> Result<(), BiskyError> {
+        Ok(Validator::bundled().validate_record(collection, record)?)
+    }
+
     pub async fn repo_create_record<D: DeserializeOwned, S: Serialize>(
         &mut self,
         repo: &str,
         collection: &str,
         record: S,
+        validate: Option<bool>,
     ) -> Result<D, BiskyError> {
+        let input = CreateRecordInput {
+            repo: repo.to_string(),
+            collection: collection.to_string(),
+            record,
+            rkey: None,
+            validate,
+            swap_commit: None,
+        };
+        input.validate_writes(self.validate_records)?;
+        self.xrpc_post("com.atproto.repo.createRecord", &input)
+            .await
+    }
+
+    ///com.atproto.repo.putRecord
+    /// Create or replace the record at `rkey`. The write fails with `InvalidSwap` if
+    /// `swap_record` or `swap_commit` are given and no longer match
+    #[allow(clippy::too_many_arguments)]
+    pub async fn repo_put_record<S: Serialize>(
+        &mut self,
+        repo: &str,
+        collection: &str,
+        rkey: &str,
+        record: S,
+        validate: Option<bool>,
+        swap_record: Option<&str>,
+        swap_commit: Option<&str>,
+    ) -> Result<PutRecordOutput, BiskyError> {
+        self.com_atproto_repo_put_record(&PutRecordInput {
+            repo: repo.to_string(),
+            collection: collection.to_string(),
+            rkey: rkey.to_string(),
+            validate,
+            record,
+            swap_record: swap_record.map(|cid| Some(cid.to_string())),
+            swap_commit: swap_commit.map(str::to_string),
+        })
+        .await
+    }
+
+    /// Read-modify-write a record: fetch it, apply `update` to the typed value and write it
+    /// back with `swapRecord`. When another writer got there first the PDS answers
+    /// `InvalidSwap`, and the whole cycle is retried up to `retries` more times.
+    /// A missing record is created from `T::default()`, unless another writer creates it first
+    pub async fn update_record<T, F>(
+        &mut self,
+        repo: &str,
+        collection: &str,
+        rkey: &str,
+        retries: usize,
+        update: F,
+    ) -> Result<PutRecordOutput, BiskyError>
+    where
+        T: DeserializeOwned + Serialize + Default + std::fmt::Debug,
+        F: FnMut(&mut T),
+    {
+        self.update_record_or_else(repo, collection, rkey, retries, T::default, update)
+            .await
+    }
+
+    /// [`Client::update_record`], creating a missing record from `init` instead
+    pub async fn update_record_or_else<T, I, F>(
+        &mut self,
+        repo: &str,
+        collection: &str,
+        rkey: &str,
+        retries: usize,
+        mut init: I,
+        mut update: F,
+    ) -> Result<PutRecordOutput, BiskyError>
+    where
+        T: DeserializeOwned + Serialize + std::fmt::Debug,
+        I: FnMut() -> T,
+        F: FnMut(&mut T),
+    {
+        let mut attempt = 0;
+        loop {
+            let (mut value, cid) = match self
+                .repo_get_record::<T>(repo, collection, rkey, None)
+                .await
+            {
+                Ok(record) => match record.cid {
+                    Some(cid) => (record.value, Some(cid)),
+                    None => {
+                        return Err(BiskyError::UnexpectedResponse(format!(
+                            "{} has no cid to swap against",
+                            record.uri
+                        )))
+                    }
+                },
+                Err(BiskyError::ApiError(error)) if error.error == "RecordNotFound" => {
+                    (init(), None)
+                }
+                Err(error) => return Err(error),
+            };
+            update(&mut value);
+
+            // a null swapRecord only creates, so a record made meanwhile is a lost race too
+            let input = PutRecordInput {
+                repo: repo.to_string(),
+                collection: collection.to_string(),
+                rkey: rkey.to_string(),
+                validate: None,
+                record: &value,
+                swap_record: Some(cid),
+                swap_commit: None,
+            };
+            match self.com_atproto_repo_put_record(&input).await {
+                Err(BiskyError::ApiError(error))
+                    if error.error == "InvalidSwap" && attempt < retries =>
+                {
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Start a batch of creates, updates and deletes that are applied atomically
+    pub fn repo_apply_writes(&mut self, repo: &str) -> ApplyWritesBuilder<'_> {
+        ApplyWritesBuilder {
+            client: self,
+            repo: repo.to_string(),
+            writes: Vec::new(),
+            validate: None,
+            swap_commit: None,
+            error: None,
+        }
+    }
+
+    ///com.atproto.repo.deleteRecord
+    pub async fn repo_delete_record(
+        &mut self,
+        repo: &str,
+        collection: &str,
+        rkey: &str,
+        swap_record: Option<&str>,
+        swap_commit: Option<&str>,
+    ) -> Result<DeleteRecordOutput, BiskyError> {
         self.xrpc_post(
-            "com.atproto.repo.createRecord",
-            &CreateRecord {
-                repo,
-                collection,
-                record,
+            "com.atproto.repo.deleteRecord",
+            &DeleteRecordInput {
+                repo: repo.to_string(),
+                collection: collection.to_string(),
+                rkey: rkey.to_string(),
+                swap_record: swap_record.map(str::to_string),
+                swap_commit: swap_commit.map(str::to_string),
             },
         )
         .await
@@ -539,18 +840,90 @@ impl Client {
             Err(StreamError::NoCursor)
         }
     }
+    ///com.atproto.identity.resolveHandle
+    pub async fn identity_resolve_handle(&mut self, handle: &str) -> Result<String, BiskyError> {
+        let query = Vec::from([("handle", handle)]);
+
+        let response = self
+            .xrpc_get::<ResolveHandleOutput>("com.atproto.identity.resolveHandle", Some(&query))
+            .await?;
+
+        Ok(response.did)
+    }
+
+    ///com.atproto.sync.getRepo
+    /// Stream a repo export as a CAR, blocks are read from the network as they are requested
+    pub async fn sync_get_repo(
+        &mut self,
+        did: &str,
+        since: Option<&str>,
+    ) -> Result<CarReader<ResponseReader>, BiskyError> {
+        let mut query = Vec::from([("did", did)]);
+
+        if let Some(since) = since {
+            query.push(("since", since));
+        }
+
+        let response = self
+            .xrpc_get_stream("com.atproto.sync.getRepo", Some(&query))
+            .await?;
+        let stream = response
+            .bytes_stream()
+            .map_err(std::io::Error::other)
+            .boxed();
+
+        Ok(CarReader::new(StreamReader::new(stream)).await?)
+    }
+
+    ///com.atproto.sync.getRepo
+    /// Download a repo export to `path` and index it, for repos too large to hold in memory
+    pub async fn sync_download_repo(
+        &mut self,
+        did: &str,
+        since: Option<&str>,
+        path: &Path,
+    ) -> Result<CarIndex<tokio::fs::File>, BiskyError> {
+        let mut query = Vec::from([("did", did)]);
+
+        if let Some(since) = since {
+            query.push(("since", since));
+        }
+
+        let response = self
+            .xrpc_get_stream("com.atproto.sync.getRepo", Some(&query))
+            .await?;
+        let stream = response.bytes_stream().map_err(std::io::Error::other);
+
+        let mut file = tokio::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)
+            .await?;
+        tokio::io::copy(&mut StreamReader::new(stream), &mut file).await?;
+
+        Ok(CarIndex::build(file).await?)
+    }
+
+    ///com.atproto.sync.subscribeRepos
+    /// Subscribe to the repo event stream of this client's service
+    pub async fn sync_subscribe_repos(&self, cursor: Option<i64>) -> Result<Firehose, BiskyError> {
+        Firehose::connect(&self.service, cursor).await
+    }
+
     /// Get the user's notification count. Can take a date to mark them as seen
     pub async fn bsky_get_notification_count(
         &mut self,
         seen_at: Option<&str>,
-    ) -> Result<NotificationCount, BiskyError> {
+    ) -> Result<GetUnreadCountOutput, BiskyError> {
         let mut query = Vec::new();
 
         if let Some(seen_at) = seen_at {
             query.push(("seen_at", seen_at));
         }
         let res = self
-            .xrpc_get::<NotificationCount>("app.bsky.notification.getUnreadCount", Some(&query))
+            .xrpc_get::<GetUnreadCountOutput>("app.bsky.notification.getUnreadCount", Some(&query))
             .await?;
         Ok(res)
     }
@@ -560,7 +933,7 @@ impl Client {
         mut limit: usize,
         seen_at: Option<&str>,
         cursor: Option<&str>,
-    ) -> Result<(Vec<Notification<D>>, Option<String>), BiskyError> {
+    ) -> Result<(Vec<ListNotificationsNotification<D>>, Option<String>), BiskyError> {
         let mut notifications = Vec::new();
         let mut response_cursor = None;
 
@@ -597,8 +970,13 @@ impl Client {
     }
 
     pub async fn bsky_update_seen(&mut self, seen_at: DateTime<Utc>) -> Result<(), BiskyError> {
-        self.xrpc_post_no_response("app.bsky.notification.updateSeen", &UpdateSeen { seen_at })
-            .await
+        self.xrpc_post_no_response(
+            "app.bsky.notification.updateSeen",
+            &UpdateSeenInput {
+                seen_at: seen_at.into(),
+            },
+        )
+        .await
     }
 
     pub async fn bsky_stream_notifications<'a, D: DeserializeOwned + std::fmt::Debug>(
@@ -733,7 +1111,7 @@ impl Client {
     pub async fn bsky_get_post_thread(
         &mut self,
         uri: &str,
-    ) -> Result<ThreadViewPostEnum, BiskyError> {
+    ) -> Result<GetPostThreadOutputThread, BiskyError> {
         let query = Vec::from([("uri", uri)]);
 
         let response = self
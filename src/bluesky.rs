@@ -1,12 +1,29 @@
 use crate::atproto::{Client, NotificationStream, RecordStream, StreamError};
+use crate::composer::Composer;
 use crate::errors::BiskyError;
 use crate::lexicon::app::bsky::actor::{ProfileView, ProfileViewDetailed};
-use crate::lexicon::app::bsky::feed::{GetLikesLike, Post, ThreadViewPostEnum};
+use crate::lexicon::app::bsky::feed::{
+    GetLikesLike, GetPostThreadOutputThread, Post, PostEmbed, PostReplyRef, Postgate,
+    PostgateEmbeddingRule, Threadgate, ThreadgateAllowItem,
+};
 use crate::lexicon::app::bsky::notification::{
-    Notification, NotificationCount, NotificationRecord,
+    GetUnreadCountOutput, ListNotificationsNotification,
+};
+use crate::lexicon::com::atproto::repo::{
+    ApplyWritesOutputResult, CreateRecordOutput, DeleteRecordOutput, GetRecordOutput,
+    ListRecordsRecord, PutRecordOutput, StrongRef, UploadBlobOutput,
 };
-use crate::lexicon::com::atproto::repo::{BlobOutput, CreateRecordOutput, Record};
+use crate::lexicon::KnownRecord;
+use crate::linkcard::{LinkCard, ReqwestFetcher};
+use crate::repo::{Repo, RepoError};
+use crate::richtext::RichText;
+use crate::tid;
 use chrono::Utc;
+use serde::de::DeserializeOwned;
+
+/// Extra attempts when another writer changes a gate between our read and write
+const GATE_RETRIES: usize = 3;
+
 pub struct Bluesky {
     client: Client,
 }
@@ -16,8 +33,8 @@ impl Bluesky {
         Self { client }
     }
 
-    pub fn user(&mut self, username: &str) -> Result<BlueskyUser, BiskyError> {
-        let Some(_session) = &self.client.session else{
+    pub fn user(&mut self, username: &str) -> Result<BlueskyUser<'_>, BiskyError> {
+        let Some(_session) = &self.client.session else {
             return Err(BiskyError::MissingSession);
         };
         Ok(BlueskyUser {
@@ -26,8 +43,8 @@ impl Bluesky {
         })
     }
 
-    pub fn me(&mut self) -> Result<BlueskyMe, BiskyError> {
-        let Some(session) = &self.client.session else{
+    pub fn me(&mut self) -> Result<BlueskyMe<'_>, BiskyError> {
+        let Some(session) = &self.client.session else {
             return Err(BiskyError::MissingSession);
         };
         Ok(BlueskyMe {
@@ -46,7 +63,225 @@ impl<'a> BlueskyMe<'a> {
     /// Post a new Post to your skyline
     pub async fn post(&mut self, post: Post) -> Result<CreateRecordOutput, BiskyError> {
         self.client
-            .repo_create_record(&self.username, "app.bsky.feed.post", &post)
+            .repo_create_record(&self.username, "app.bsky.feed.post", &post, None)
+            .await
+    }
+    /// Post `text` with its mentions, links and hashtags made clickable
+    pub async fn post_text(&mut self, text: &str) -> Result<CreateRecordOutput, BiskyError> {
+        let post = RichText::new(text)
+            .resolve_mentions(self.client)
+            .await?
+            .into_post();
+        self.post(post).await
+    }
+    /// Publish a composed post, or its posts as a reply chain when it was split into a
+    /// thread. A post with reply or quote controls is written in one commit with its
+    /// threadgate and postgate, so it is never visible ungated.
+    /// Mentions are resolved first. Returns the created records in thread order
+    pub async fn publish(
+        &mut self,
+        composer: Composer,
+    ) -> Result<Vec<CreateRecordOutput>, BiskyError> {
+        let mut allow = composer.threadgate_rules().map(<[_]>::to_vec);
+        let embedding = composer.postgate_rules().map(<[_]>::to_vec);
+        let posts = composer.resolve_mentions(self.client).await?.into_posts()?;
+        // Replies continue the thread they are in, otherwise the first post is the root
+        let reply_root = posts[0].reply.as_ref().map(|reply| reply.root.clone());
+        let mut created = Vec::new();
+        let mut thread: Option<(StrongRef, StrongRef)> = None;
+        for mut post in posts {
+            if let Some((root, parent)) = &thread {
+                post.reply = Some(PostReplyRef {
+                    root: root.clone(),
+                    parent: parent.clone(),
+                });
+            }
+            let allow = allow.take();
+            let output = match allow.is_some() || embedding.is_some() {
+                true => self.post_gated(&post, allow, embedding.as_deref()).await?,
+                false => self.post(post).await?,
+            };
+            let parent = StrongRef {
+                uri: output.uri.clone(),
+                cid: output.cid.clone(),
+            };
+            let root = match thread {
+                Some((root, _)) => root,
+                None => reply_root.clone().unwrap_or_else(|| parent.clone()),
+            };
+            thread = Some((root, parent));
+            created.push(output);
+        }
+        Ok(created)
+    }
+    /// Create `post` and its gates in a single applyWrites, under a client-chosen TID
+    /// since a gate's record key must match its post's
+    async fn post_gated(
+        &mut self,
+        post: &Post,
+        allow: Option<Vec<ThreadgateAllowItem>>,
+        embedding: Option<&[PostgateEmbeddingRule]>,
+    ) -> Result<CreateRecordOutput, BiskyError> {
+        let rkey = tid::next();
+        let uri = format!("at://{}/app.bsky.feed.post/{rkey}", self.username);
+        let mut writes = self.client.repo_apply_writes(&self.username).create(
+            "app.bsky.feed.post",
+            Some(&rkey),
+            post,
+        );
+        if let Some(allow) = allow {
+            let gate = Threadgate {
+                allow: Some(allow),
+                ..Threadgate::new(&uri)
+            };
+            writes = writes.create("app.bsky.feed.threadgate", Some(&rkey), &gate);
+        }
+        if let Some(rules) = embedding {
+            let gate = Postgate {
+                embedding_rules: Some(rules.to_vec()),
+                ..Postgate::new(&uri)
+            };
+            writes = writes.create("app.bsky.feed.postgate", Some(&rkey), &gate);
+        }
+
+        let output = writes.send().await?;
+        match output
+            .results
+            .and_then(|results| results.into_iter().next())
+        {
+            Some(ApplyWritesOutputResult::CreateResult(result)) => Ok(CreateRecordOutput {
+                cid: result.cid,
+                commit: output.commit,
+                uri: result.uri,
+                validation_status: result.validation_status,
+            }),
+            _ => Err(BiskyError::UnexpectedResponse(format!(
+                "applyWrites did not return the created post {uri}"
+            ))),
+        }
+    }
+    /// Link card embed for `url`, with its preview image uploaded as the thumbnail
+    pub async fn link_card(&mut self, url: &str) -> Result<PostEmbed, BiskyError> {
+        let fetcher = ReqwestFetcher::default();
+        LinkCard::fetch(&fetcher, url)
+            .await?
+            .into_embed(self.client, &fetcher)
+            .await
+    }
+    /// Set who may reply to the thread under one of your posts by record key,
+    /// `None` lets everyone reply again
+    pub async fn set_reply_controls(
+        &mut self,
+        rkey: &str,
+        allow: Option<Vec<ThreadgateAllowItem>>,
+    ) -> Result<PutRecordOutput, BiskyError> {
+        self.update_threadgate(rkey, |gate| gate.allow = allow.clone())
+            .await
+    }
+    /// Hide a reply from the thread under one of your posts by record key
+    pub async fn hide_reply(
+        &mut self,
+        rkey: &str,
+        reply_uri: &str,
+    ) -> Result<PutRecordOutput, BiskyError> {
+        self.update_threadgate(rkey, |gate| {
+            let hidden = gate.hidden_replies.get_or_insert_with(Vec::new);
+            if !hidden.iter().any(|uri| uri == reply_uri) {
+                hidden.push(reply_uri.to_string());
+            }
+        })
+        .await
+    }
+    /// Show a reply hidden with [`BlueskyMe::hide_reply`] again
+    pub async fn unhide_reply(
+        &mut self,
+        rkey: &str,
+        reply_uri: &str,
+    ) -> Result<PutRecordOutput, BiskyError> {
+        self.update_threadgate(rkey, |gate| {
+            if let Some(hidden) = &mut gate.hidden_replies {
+                hidden.retain(|uri| uri != reply_uri);
+            }
+        })
+        .await
+    }
+    /// Set who may quote one of your posts by record key, `None` lets everyone quote it again
+    pub async fn set_quote_controls(
+        &mut self,
+        rkey: &str,
+        rules: Option<Vec<PostgateEmbeddingRule>>,
+    ) -> Result<PutRecordOutput, BiskyError> {
+        self.update_postgate(rkey, |gate| gate.embedding_rules = rules.clone())
+            .await
+    }
+    /// Detach a post quoting one of yours, by your post's record key and the quote's URI
+    pub async fn detach_quote(
+        &mut self,
+        rkey: &str,
+        quote_uri: &str,
+    ) -> Result<PutRecordOutput, BiskyError> {
+        self.update_postgate(rkey, |gate| {
+            let detached = gate.detached_embedding_uris.get_or_insert_with(Vec::new);
+            if !detached.iter().any(|uri| uri == quote_uri) {
+                detached.push(quote_uri.to_string());
+            }
+        })
+        .await
+    }
+    /// Attach a quote detached with [`BlueskyMe::detach_quote`] again
+    pub async fn reattach_quote(
+        &mut self,
+        rkey: &str,
+        quote_uri: &str,
+    ) -> Result<PutRecordOutput, BiskyError> {
+        self.update_postgate(rkey, |gate| {
+            if let Some(detached) = &mut gate.detached_embedding_uris {
+                detached.retain(|uri| uri != quote_uri);
+            }
+        })
+        .await
+    }
+    /// Read-modify-write the threadgate of the post at `rkey`, starting a new one if
+    /// there is none. Swaps against the read version so concurrent edits are not lost
+    async fn update_threadgate<F: FnMut(&mut Threadgate)>(
+        &mut self,
+        rkey: &str,
+        update: F,
+    ) -> Result<PutRecordOutput, BiskyError> {
+        let post = format!("at://{}/app.bsky.feed.post/{rkey}", self.username);
+        self.client
+            .update_record_or_else(
+                &self.username,
+                "app.bsky.feed.threadgate",
+                rkey,
+                GATE_RETRIES,
+                || Threadgate::new(&post),
+                update,
+            )
+            .await
+    }
+    /// Read-modify-write the postgate of the post at `rkey`, like `update_threadgate`
+    async fn update_postgate<F: FnMut(&mut Postgate)>(
+        &mut self,
+        rkey: &str,
+        update: F,
+    ) -> Result<PutRecordOutput, BiskyError> {
+        let post = format!("at://{}/app.bsky.feed.post/{rkey}", self.username);
+        self.client
+            .update_record_or_else(
+                &self.username,
+                "app.bsky.feed.postgate",
+                rkey,
+                GATE_RETRIES,
+                || Postgate::new(&post),
+                update,
+            )
+            .await
+    }
+    /// Delete one of your posts by record key
+    pub async fn delete_post(&mut self, rkey: &str) -> Result<DeleteRecordOutput, BiskyError> {
+        self.client
+            .repo_delete_record(&self.username, "app.bsky.feed.post", rkey, None, None)
             .await
     }
     /// Get the notifications for the user
@@ -54,7 +289,7 @@ impl<'a> BlueskyMe<'a> {
     pub async fn get_notification_count(
         &mut self,
         seen_at: Option<&str>,
-    ) -> Result<NotificationCount, BiskyError> {
+    ) -> Result<GetUnreadCountOutput, BiskyError> {
         self.client.bsky_get_notification_count(seen_at).await
     }
     /// Get the notifications for the user
@@ -62,7 +297,7 @@ impl<'a> BlueskyMe<'a> {
     pub async fn list_notifications(
         &mut self,
         limit: usize,
-    ) -> Result<Vec<Notification<NotificationRecord>>, BiskyError> {
+    ) -> Result<Vec<ListNotificationsNotification<KnownRecord>>, BiskyError> {
         self.client
             .bsky_list_notifications(limit, None, None)
             .await
@@ -71,7 +306,7 @@ impl<'a> BlueskyMe<'a> {
 
     pub async fn stream_notifications(
         &mut self,
-    ) -> Result<NotificationStream<Notification<NotificationRecord>>, StreamError> {
+    ) -> Result<NotificationStream<'_, KnownRecord>, StreamError> {
         self.client.bsky_stream_notifications(None).await
     }
     /// Tell Bsky when the notifications were seen, marking them as old
@@ -84,11 +319,14 @@ impl<'a> BlueskyMe<'a> {
         &mut self,
         blob: &[u8],
         mime_type: &str,
-    ) -> Result<BlobOutput, BiskyError> {
+    ) -> Result<UploadBlobOutput, BiskyError> {
         self.client.repo_upload_blob(blob, mime_type).await
     }
 
-    pub async fn get_post_thread(&mut self, uri: &str) -> Result<ThreadViewPostEnum, BiskyError> {
+    pub async fn get_post_thread(
+        &mut self,
+        uri: &str,
+    ) -> Result<GetPostThreadOutputThread, BiskyError> {
         self.client.bsky_get_post_thread(uri).await
     }
 }
@@ -137,29 +375,49 @@ impl BlueskyUser<'_> {
             .await
             .map(|l| l.0)
     }
-    // pub async fn get_record(&mut self, repo: &str, collection: &str, rkey: &str) -> Result<ProfileViewDetailed, BiskyError> {
-    //     self.client
-    //         .xrpc_get(
-    //             "com.atproto.repo.getRecord",
-    //             Some(&[("actor", &self.username)]),
-    //         )
-    //         .await
-    // }
-
-    pub async fn list_posts(&mut self) -> Result<Vec<Record<Post>>, BiskyError> {
+    /// Get one of the user's records, e.g. `("app.bsky.actor.profile", "self")`
+    pub async fn get_record<T: DeserializeOwned + std::fmt::Debug>(
+        &mut self,
+        collection: &str,
+        rkey: &str,
+    ) -> Result<GetRecordOutput<T>, BiskyError> {
         self.client
-            .repo_list_records(
-                &self.username,
-                "app.bsky.feed.post",
-                usize::MAX,
-                false,
-                None,
-            )
+            .repo_get_record(&self.username, collection, rkey, None)
             .await
-            .map(|l| l.0)
     }
 
-    pub async fn stream_posts(&mut self) -> Result<RecordStream<Post>, StreamError> {
+    /// List every post by downloading the whole repo once, newest first. The CAR is
+    /// spooled to a temporary file and the MST walked from its index, so only the posts
+    /// are held in memory. Posts that do not decode are skipped, see [`Repo::try_records`]
+    pub async fn list_posts(&mut self) -> Result<Vec<ListRecordsRecord<Post>>, BiskyError> {
+        let did = match self.username.starts_with("did:") {
+            true => self.username.clone(),
+            false => self.client.identity_resolve_handle(&self.username).await?,
+        };
+
+        // removed when dropped
+        let path = tempfile::Builder::new()
+            .prefix("bisky-")
+            .suffix(".car")
+            .tempfile()?
+            .into_temp_path();
+        let car = self.client.sync_download_repo(&did, None, &path).await?;
+        let Some(root) = car.roots().first().copied() else {
+            return Err(RepoError::MissingRoot.into());
+        };
+        let mut repo = Repo::open(car, &root).await?;
+
+        let mut posts: Vec<_> = repo
+            .try_records::<Post>("app.bsky.feed.post")
+            .await?
+            .into_iter()
+            .filter_map(Result::ok)
+            .collect();
+        posts.reverse();
+        Ok(posts)
+    }
+
+    pub async fn stream_posts(&mut self) -> Result<RecordStream<'_, Post>, StreamError> {
         self.client
             .repo_stream_records(&self.username, "app.bsky.feed.post")
             .await
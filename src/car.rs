@@ -0,0 +1,257 @@
+//! Reader for CAR (Content Addressable aRchive) v1 files, as returned by com.atproto.sync.getRepo
+use async_trait::async_trait;
+use futures::Stream;
+use miette::Diagnostic;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::io::SeekFrom;
+use thiserror::Error;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
+
+pub use ipld_core::cid::Cid;
+
+/// Largest block section we are willing to read, protects against corrupt length prefixes
+const MAX_SECTION_SIZE: u64 = 8 * 1024 * 1024;
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum CarError {
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+    #[error("Invalid CAR Header: {0}")]
+    InvalidHeader(String),
+    #[error("Unsupported CAR Version: {0}")]
+    UnsupportedVersion(u64),
+    #[error("Invalid Varint")]
+    InvalidVarint,
+    #[error("Block Section Too Large: {0} bytes")]
+    SectionTooLarge(u64),
+    #[error("Invalid CID: {0}")]
+    InvalidCid(String),
+    #[error("Block Not Found: {0}")]
+    BlockNotFound(Cid),
+    #[error("DAG-CBOR Decode Error: {0}")]
+    DecodeError(String),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CarHeader {
+    pub version: u64,
+    pub roots: Vec<Cid>,
+}
+
+/// Decode a DAG-CBOR block into `T`
+pub fn decode_block<T: DeserializeOwned>(data: &[u8]) -> Result<T, CarError> {
+    serde_ipld_dagcbor::from_slice(data).map_err(|e| CarError::DecodeError(e.to_string()))
+}
+
+/// Read an unsigned LEB128 varint. Returns `None` on a clean EOF before the first byte
+async fn read_varint<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Option<u64>, CarError> {
+    let mut value: u64 = 0;
+    for i in 0..10 {
+        let byte = match reader.read_u8().await {
+            Ok(byte) => byte,
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof && i == 0 => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Ok(Some(value));
+        }
+    }
+    Err(CarError::InvalidVarint)
+}
+
+fn varint_len(mut value: u64) -> u64 {
+    let mut len = 1;
+    while value >= 0x80 {
+        value >>= 7;
+        len += 1;
+    }
+    len
+}
+
+/// Read one length-prefixed section and split it into its CID and data
+async fn read_section<R: AsyncRead + Unpin>(
+    reader: &mut R,
+) -> Result<Option<(u64, Cid, Vec<u8>)>, CarError> {
+    let Some(length) = read_varint(reader).await? else {
+        return Ok(None);
+    };
+    if length > MAX_SECTION_SIZE {
+        return Err(CarError::SectionTooLarge(length));
+    }
+
+    let mut section = vec![0; length as usize];
+    reader.read_exact(&mut section).await?;
+
+    let mut cursor = std::io::Cursor::new(&section);
+    let cid = Cid::read_bytes(&mut cursor).map_err(|e| CarError::InvalidCid(e.to_string()))?;
+    let cid_len = cursor.position() as usize;
+    section.drain(..cid_len);
+
+    Ok(Some((varint_len(length) + length, cid, section)))
+}
+
+async fn read_header<R: AsyncRead + Unpin>(reader: &mut R) -> Result<(u64, CarHeader), CarError> {
+    let length = read_varint(reader)
+        .await?
+        .ok_or_else(|| CarError::InvalidHeader("empty file".to_string()))?;
+    if length > MAX_SECTION_SIZE {
+        return Err(CarError::SectionTooLarge(length));
+    }
+
+    let mut buf = vec![0; length as usize];
+    reader.read_exact(&mut buf).await?;
+    let header: CarHeader =
+        serde_ipld_dagcbor::from_slice(&buf).map_err(|e| CarError::InvalidHeader(e.to_string()))?;
+
+    if header.version != 1 {
+        return Err(CarError::UnsupportedVersion(header.version));
+    }
+    Ok((varint_len(length) + length, header))
+}
+
+/// Streaming CAR reader, only one block is held in memory at a time
+pub struct CarReader<R> {
+    reader: R,
+    header: CarHeader,
+}
+
+impl<R: AsyncRead + Unpin> CarReader<R> {
+    /// Read the CAR header, leaving the reader positioned at the first block
+    pub async fn new(mut reader: R) -> Result<Self, CarError> {
+        let (_, header) = read_header(&mut reader).await?;
+        Ok(Self { reader, header })
+    }
+
+    pub fn header(&self) -> &CarHeader {
+        &self.header
+    }
+
+    pub fn roots(&self) -> &[Cid] {
+        &self.header.roots
+    }
+
+    /// Read the next block, returns `None` once the CAR is exhausted
+    pub async fn next_block(&mut self) -> Result<Option<(Cid, Vec<u8>)>, CarError> {
+        Ok(read_section(&mut self.reader)
+            .await?
+            .map(|(_, cid, data)| (cid, data)))
+    }
+
+    /// Turn the remaining blocks into a `Stream`
+    pub fn into_stream(self) -> impl Stream<Item = Result<(Cid, Vec<u8>), CarError>> {
+        futures::stream::try_unfold(self, |mut car| async move {
+            Ok(car.next_block().await?.map(|block| (block, car)))
+        })
+    }
+
+    /// Read every remaining block into memory
+    pub async fn into_blocks(mut self) -> Result<Blocks, CarError> {
+        let mut blocks = Blocks {
+            roots: self.header.roots.clone(),
+            blocks: HashMap::new(),
+        };
+        while let Some((cid, data)) = self.next_block().await? {
+            blocks.blocks.insert(cid, data);
+        }
+        Ok(blocks)
+    }
+}
+
+/// Lookup of blocks by CID
+#[async_trait]
+pub trait BlockStore {
+    async fn get_raw(&mut self, cid: &Cid) -> Result<Option<Vec<u8>>, CarError>;
+
+    /// Fetch a block and decode it from DAG-CBOR
+    async fn get<T: DeserializeOwned>(&mut self, cid: &Cid) -> Result<T, CarError> {
+        let data = self
+            .get_raw(cid)
+            .await?
+            .ok_or(CarError::BlockNotFound(*cid))?;
+        decode_block(&data)
+    }
+}
+
+/// A CAR held entirely in memory, fine for small CARs such as firehose commit slices
+#[derive(Debug, Clone, Default)]
+pub struct Blocks {
+    pub roots: Vec<Cid>,
+    pub blocks: HashMap<Cid, Vec<u8>>,
+}
+
+impl Blocks {
+    pub async fn from_bytes(bytes: &[u8]) -> Result<Self, CarError> {
+        CarReader::new(bytes).await?.into_blocks().await
+    }
+}
+
+#[async_trait]
+impl BlockStore for Blocks {
+    async fn get_raw(&mut self, cid: &Cid) -> Result<Option<Vec<u8>>, CarError> {
+        Ok(self.blocks.get(cid).cloned())
+    }
+}
+
+/// A seekable CAR (usually a file on disk) with an index of block offsets.
+/// Blocks are read from the underlying reader on demand
+pub struct CarIndex<R> {
+    reader: R,
+    header: CarHeader,
+    offsets: HashMap<Cid, (u64, usize)>,
+}
+
+impl<R: AsyncRead + AsyncSeek + Unpin + Send> CarIndex<R> {
+    /// Scan the CAR once, recording where each block's data lives
+    pub async fn build(mut reader: R) -> Result<Self, CarError> {
+        reader.seek(SeekFrom::Start(0)).await?;
+        let (mut position, header) = read_header(&mut reader).await?;
+
+        let mut offsets = HashMap::new();
+        while let Some((section_len, cid, data)) = read_section(&mut reader).await? {
+            position += section_len;
+            offsets.insert(cid, (position - data.len() as u64, data.len()));
+        }
+
+        Ok(Self {
+            reader,
+            header,
+            offsets,
+        })
+    }
+
+    pub fn header(&self) -> &CarHeader {
+        &self.header
+    }
+
+    pub fn roots(&self) -> &[Cid] {
+        &self.header.roots
+    }
+
+    pub fn contains(&self, cid: &Cid) -> bool {
+        self.offsets.contains_key(cid)
+    }
+
+    pub fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+}
+
+#[async_trait]
+impl<R: AsyncRead + AsyncSeek + Unpin + Send> BlockStore for CarIndex<R> {
+    async fn get_raw(&mut self, cid: &Cid) -> Result<Option<Vec<u8>>, CarError> {
+        let Some(&(offset, len)) = self.offsets.get(cid) else {
+            return Ok(None);
+        };
+        self.reader.seek(SeekFrom::Start(offset)).await?;
+        let mut data = vec![0; len];
+        self.reader.read_exact(&mut data).await?;
+        Ok(Some(data))
+    }
+}
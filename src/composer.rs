@@ -0,0 +1,377 @@
+//! Post composer: checks the grapheme limit and optionally splits long text into a thread
+use crate::atproto::Client;
+use crate::errors::BiskyError;
+use crate::lexicon::app::bsky::embed::{Record, RecordWithMedia, RecordWithMediaMedia};
+use crate::lexicon::app::bsky::feed::{
+    Post, PostEmbed, PostLabels, PostReplyRef, PostgateEmbeddingRule, ThreadgateAllowItem,
+};
+use crate::lexicon::app::bsky::richtext::{Facet, FacetByteSlice};
+use crate::lexicon::com::atproto::label::SelfLabels;
+use crate::lexicon::com::atproto::repo::StrongRef;
+use crate::richtext::RichText;
+use crate::validation::is_language;
+use miette::Diagnostic;
+use thiserror::Error;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Longest post text the app accepts, in graphemes
+pub const MAX_GRAPHEMES: usize = 300;
+/// Most languages a post can declare
+pub const MAX_LANGS: usize = 3;
+/// Most outline tags a post can carry
+pub const MAX_TAGS: usize = 8;
+/// Longest outline tag, in graphemes
+pub const MAX_TAG_GRAPHEMES: usize = 64;
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum ComposeError {
+    #[error("Post is {graphemes} graphemes, over the {max} grapheme limit")]
+    TooLong { graphemes: usize, max: usize },
+    #[error("Post text is empty")]
+    Empty,
+    #[error("{0:?} is not a BCP-47 language tag")]
+    InvalidLanguage(String),
+    #[error("Post has {count} languages, over the limit of {max}")]
+    TooManyLangs { count: usize, max: usize },
+    #[error("Post has {count} tags, over the limit of {max}")]
+    TooManyTags { count: usize, max: usize },
+    #[error("Tag {0:?} is empty or over {MAX_TAG_GRAPHEMES} graphemes")]
+    InvalidTag(String),
+    #[error("Reply controls only apply to a thread's root post, not to a reply")]
+    GatedReply,
+}
+
+/// Builds one post, or a thread of replies when the text is too long for one and
+/// [`Composer::split_thread`] is on. Publish with [`crate::bluesky::BlueskyMe::publish`]
+pub struct Composer {
+    text: RichText,
+    embed: Option<PostEmbed>,
+    reply: Option<PostReplyRef>,
+    allow: Option<Vec<ThreadgateAllowItem>>,
+    embedding: Option<Vec<PostgateEmbeddingRule>>,
+    langs: Vec<String>,
+    labels: Vec<String>,
+    tags: Vec<String>,
+    #[cfg(feature = "lang-detect")]
+    detect_langs: Option<f64>,
+    split: bool,
+    numbered: bool,
+    max_graphemes: usize,
+}
+
+impl Composer {
+    pub fn new(text: RichText) -> Self {
+        Self {
+            text,
+            embed: None,
+            reply: None,
+            allow: None,
+            embedding: None,
+            langs: Vec::new(),
+            labels: Vec::new(),
+            tags: Vec::new(),
+            #[cfg(feature = "lang-detect")]
+            detect_langs: None,
+            split: false,
+            numbered: true,
+            max_graphemes: MAX_GRAPHEMES,
+        }
+    }
+
+    /// Resolve the text's `@handle` mentions, see [`RichText::resolve_mentions`].
+    /// [`crate::bluesky::BlueskyMe::publish`] does this before splitting
+    pub async fn resolve_mentions(mut self, client: &mut Client) -> Result<Self, BiskyError> {
+        self.text = self.text.resolve_mentions(client).await?;
+        Ok(self)
+    }
+
+    /// Attach an embed, to the first post of a thread. Replaces any quote, call
+    /// [`Composer::quote`] after this to quote alongside it
+    pub fn embed(mut self, embed: PostEmbed) -> Self {
+        self.embed = Some(embed);
+        self
+    }
+
+    /// Quote another post. Images, a video or a link card already attached stay as the
+    /// media of a `recordWithMedia` embed
+    pub fn quote(mut self, post: StrongRef) -> Self {
+        let record = Record { record: post };
+        let media = match self.embed.take() {
+            Some(PostEmbed::Images(images)) => Some(RecordWithMediaMedia::Images(images)),
+            Some(PostEmbed::Video(video)) => Some(RecordWithMediaMedia::Video(video)),
+            Some(PostEmbed::Gallery(gallery)) => Some(RecordWithMediaMedia::Gallery(gallery)),
+            Some(PostEmbed::External(external)) => Some(RecordWithMediaMedia::External(external)),
+            Some(PostEmbed::RecordWithMedia(embed)) => Some(embed.media),
+            _ => None,
+        };
+        self.embed = Some(match media {
+            Some(media) => PostEmbed::RecordWithMedia(Box::new(RecordWithMedia { record, media })),
+            None => PostEmbed::Record(Box::new(record)),
+        });
+        self
+    }
+
+    /// Post as a reply, a split thread continues under it
+    pub fn reply_to(mut self, reply: PostReplyRef) -> Self {
+        self.reply = Some(reply);
+        self
+    }
+
+    /// Only let accounts matching one of the rules reply, no one if there are none.
+    /// Published as a threadgate on the first post
+    pub fn reply_controls(mut self, allow: Vec<ThreadgateAllowItem>) -> Self {
+        self.allow = Some(allow);
+        self
+    }
+
+    pub(crate) fn threadgate_rules(&self) -> Option<&[ThreadgateAllowItem]> {
+        self.allow.as_deref()
+    }
+
+    /// Limit who may quote the posts, e.g. `PostgateEmbeddingRule::DisableRule` to turn
+    /// quoting off. Published as a postgate on every post of a thread
+    pub fn quote_controls(mut self, rules: Vec<PostgateEmbeddingRule>) -> Self {
+        self.embedding = Some(rules);
+        self
+    }
+
+    pub(crate) fn postgate_rules(&self) -> Option<&[PostgateEmbeddingRule]> {
+        self.embedding.as_deref()
+    }
+
+    /// Languages of the text as BCP-47 tags, e.g. `en` or `pt-BR`, set on every post of a thread
+    pub fn langs<I: IntoIterator<Item = S>, S: Into<String>>(mut self, langs: I) -> Self {
+        self.langs = langs.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Detect the language of the text when [`Composer::langs`] is not set, leaving it
+    /// unset if the detector is less than `min_confidence` (0 to 1) sure. See
+    /// [`crate::langdetect::DEFAULT_MIN_CONFIDENCE`]
+    #[cfg(feature = "lang-detect")]
+    pub fn detect_langs(mut self, min_confidence: f64) -> Self {
+        self.detect_langs = Some(min_confidence);
+        self
+    }
+
+    /// Self-labels like `nudity`, `sexual`, `porn` or `graphic-media`, set on every post of a thread
+    pub fn self_labels<I: IntoIterator<Item = S>, S: Into<String>>(mut self, labels: I) -> Self {
+        self.labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Hashtags to add without putting them in the text, on the first post of a thread.
+    /// A leading `#` is dropped
+    pub fn tags<I: IntoIterator<Item = S>, S: Into<String>>(mut self, tags: I) -> Self {
+        self.tags = tags
+            .into_iter()
+            .map(|tag| {
+                let tag: String = tag.into();
+                tag.strip_prefix('#').map(str::to_string).unwrap_or(tag)
+            })
+            .collect();
+        self
+    }
+
+    /// Split text over the limit into a reply chain instead of failing
+    pub fn split_thread(mut self, split: bool) -> Self {
+        self.split = split;
+        self
+    }
+
+    /// Append ` 1/3` style counters to the posts of a split thread, on by default
+    pub fn numbered(mut self, numbered: bool) -> Self {
+        self.numbered = numbered;
+        self
+    }
+
+    /// Lower the per post limit, e.g. to leave room for text added later
+    pub fn max_graphemes(mut self, max_graphemes: usize) -> Self {
+        self.max_graphemes = max_graphemes.min(MAX_GRAPHEMES);
+        self
+    }
+
+    /// The posts to publish in order. Only the first carries the embed and `reply_to`,
+    /// the rest get their reply refs as the thread is published
+    pub fn into_posts(self) -> Result<Vec<Post>, ComposeError> {
+        self.check_metadata()?;
+        let langs = self.post_langs();
+        let (text, facets) = self.text.into_parts();
+        let graphemes = text.graphemes(true).count();
+        if text.trim().is_empty() {
+            return Err(ComposeError::Empty);
+        }
+
+        let chunks = match graphemes <= self.max_graphemes {
+            true => vec![RichText::with_facets(text, facets)],
+            false if self.split => split(&text, &facets, self.max_graphemes, self.numbered),
+            false => {
+                return Err(ComposeError::TooLong {
+                    graphemes,
+                    max: self.max_graphemes,
+                })
+            }
+        };
+
+        let langs = (!langs.is_empty()).then_some(langs);
+        let labels = (!self.labels.is_empty()).then(|| {
+            PostLabels::SelfLabels(Box::new(self.labels.into_iter().collect::<SelfLabels>()))
+        });
+        let mut posts: Vec<Post> = chunks
+            .into_iter()
+            .map(|chunk| Post {
+                langs: langs.clone(),
+                labels: labels.clone(),
+                ..chunk.into_post()
+            })
+            .collect();
+        posts[0].embed = self.embed;
+        posts[0].reply = self.reply;
+        posts[0].tags = (!self.tags.is_empty()).then_some(self.tags);
+        Ok(posts)
+    }
+
+    #[cfg(not(feature = "lang-detect"))]
+    fn post_langs(&self) -> Vec<String> {
+        self.langs.clone()
+    }
+
+    /// The set languages, else the detected one if detection is on and confident
+    #[cfg(feature = "lang-detect")]
+    fn post_langs(&self) -> Vec<String> {
+        let Some(min_confidence) = self.detect_langs else {
+            return self.langs.clone();
+        };
+        if !self.langs.is_empty() {
+            return self.langs.clone();
+        }
+        // Links, mentions and tags say little about the language
+        let mut prose = String::new();
+        let mut from = 0;
+        for facet in self.text.facets() {
+            if let Some(before) = self.text.text().get(from..facet.index.byte_start) {
+                prose.push_str(before);
+                from = facet.index.byte_end;
+            }
+        }
+        prose.push_str(self.text.text().get(from..).unwrap_or_default());
+
+        crate::langdetect::detect_language(&prose, min_confidence)
+            .map(|lang| vec![lang.to_string()])
+            .unwrap_or_default()
+    }
+
+    fn check_metadata(&self) -> Result<(), ComposeError> {
+        if self.allow.is_some() && self.reply.is_some() {
+            return Err(ComposeError::GatedReply);
+        }
+        if let Some(lang) = self.langs.iter().find(|lang| !is_language(lang)) {
+            return Err(ComposeError::InvalidLanguage(lang.clone()));
+        }
+        if self.langs.len() > MAX_LANGS {
+            return Err(ComposeError::TooManyLangs {
+                count: self.langs.len(),
+                max: MAX_LANGS,
+            });
+        }
+        if self.tags.len() > MAX_TAGS {
+            return Err(ComposeError::TooManyTags {
+                count: self.tags.len(),
+                max: MAX_TAGS,
+            });
+        }
+        let invalid_tag = |tag: &&String| {
+            tag.trim().is_empty() || tag.graphemes(true).count() > MAX_TAG_GRAPHEMES
+        };
+        if let Some(tag) = self.tags.iter().find(invalid_tag) {
+            return Err(ComposeError::InvalidTag(tag.clone()));
+        }
+        Ok(())
+    }
+}
+
+impl From<&str> for Composer {
+    fn from(text: &str) -> Self {
+        Self::new(RichText::new(text))
+    }
+}
+
+/// Greedily cut `text` into chunks of at most `max` graphemes, counter included, at the
+/// last sentence boundary that fits, else the last word boundary, else a grapheme. Cuts
+/// never land inside a facet unless the facet alone is too long
+fn split(text: &str, facets: &[Facet], max: usize, numbered: bool) -> Vec<RichText> {
+    let sentences: Vec<usize> = text
+        .split_sentence_bound_indices()
+        .map(|(i, _)| i)
+        .collect();
+    let words: Vec<usize> = text.split_word_bound_indices().map(|(i, _)| i).collect();
+    let inside_facet = |at: usize| {
+        facets
+            .iter()
+            .any(|facet| facet.index.byte_start < at && at < facet.index.byte_end)
+    };
+
+    // The counter width depends on the chunk count, so retry until the guess holds
+    let mut digits = 1;
+    loop {
+        let reserve = match numbered {
+            true => 2 * digits + 2,
+            false => 0,
+        };
+        let limit = max.saturating_sub(reserve).max(1);
+
+        let mut ranges = Vec::new();
+        let mut start = skip_whitespace(text, 0);
+        while start < text.len() {
+            let end = match text[start..].grapheme_indices(true).nth(limit) {
+                Some((offset, _)) => start + offset,
+                None => text.len(),
+            };
+            let cut = if end == text.len() {
+                end
+            } else {
+                let fits = |at: &&usize| **at > start && **at <= end && !inside_facet(**at);
+                let last = |bounds: &[usize]| bounds.iter().rev().find(fits).copied();
+                last(&sentences).or_else(|| last(&words)).unwrap_or(end)
+            };
+            let chunk_end = start + text[start..cut].trim_end().len();
+            if chunk_end > start {
+                ranges.push((start, chunk_end));
+            }
+            start = skip_whitespace(text, cut);
+        }
+
+        if numbered && ranges.len().to_string().len() > digits {
+            digits = ranges.len().to_string().len();
+            continue;
+        }
+
+        let total = ranges.len();
+        return ranges
+            .into_iter()
+            .enumerate()
+            .map(|(i, (start, end))| {
+                let mut chunk = text[start..end].to_string();
+                if numbered {
+                    chunk.push_str(&format!(" {}/{total}", i + 1));
+                }
+                let facets = facets
+                    .iter()
+                    .filter(|facet| facet.index.byte_start >= start && facet.index.byte_end <= end)
+                    .map(|facet| Facet {
+                        index: FacetByteSlice {
+                            byte_start: facet.index.byte_start - start,
+                            byte_end: facet.index.byte_end - start,
+                        },
+                        features: facet.features.clone(),
+                    })
+                    .collect();
+                RichText::with_facets(chunk, facets)
+            })
+            .collect();
+    }
+}
+
+fn skip_whitespace(text: &str, from: usize) -> usize {
+    from + (text[from..].len() - text[from..].trim_start().len())
+}
@@ -0,0 +1,105 @@
+//! atproto signing keys (secp256k1 and P-256) and signature verification
+use k256::ecdsa::signature::Verifier;
+use miette::Diagnostic;
+use thiserror::Error;
+
+/// Multicodec prefix of a compressed secp256k1 public key
+const SECP256K1_PUB: [u8; 2] = [0xe7, 0x01];
+/// Multicodec prefix of a compressed P-256 public key
+const P256_PUB: [u8; 2] = [0x80, 0x24];
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum CryptoError {
+    #[error("Invalid Multibase: {0}")]
+    InvalidMultibase(String),
+    #[error("Unsupported Key Type: {0}")]
+    UnsupportedKeyType(String),
+    #[error("Invalid Public Key: {0}")]
+    InvalidKey(String),
+}
+
+#[derive(Debug, Clone)]
+pub enum PublicKey {
+    Secp256k1(k256::ecdsa::VerifyingKey),
+    P256(p256::ecdsa::VerifyingKey),
+}
+
+pub(crate) fn decode_multibase(value: &str) -> Result<Vec<u8>, CryptoError> {
+    let Some(encoded) = value.strip_prefix('z') else {
+        return Err(CryptoError::InvalidMultibase(
+            "only base58btc ('z') is supported".to_string(),
+        ));
+    };
+    bs58::decode(encoded)
+        .into_vec()
+        .map_err(|e| CryptoError::InvalidMultibase(e.to_string()))
+}
+
+impl PublicKey {
+    /// Parse a `Multikey` `publicKeyMultibase` value: base58btc of a multicodec-prefixed compressed key
+    pub fn from_multikey(multibase: &str) -> Result<Self, CryptoError> {
+        let bytes = decode_multibase(multibase)?;
+        if let Some(key) = bytes.strip_prefix(&SECP256K1_PUB) {
+            Self::from_sec1(KeyType::Secp256k1, key)
+        } else if let Some(key) = bytes.strip_prefix(&P256_PUB) {
+            Self::from_sec1(KeyType::P256, key)
+        } else {
+            Err(CryptoError::UnsupportedKeyType(format!(
+                "unknown multicodec prefix {:02x?}",
+                &bytes[..bytes.len().min(2)]
+            )))
+        }
+    }
+
+    /// Parse a `did:key:z...` string
+    pub fn from_did_key(did_key: &str) -> Result<Self, CryptoError> {
+        let Some(multibase) = did_key.strip_prefix("did:key:") else {
+            return Err(CryptoError::InvalidKey(format!("not a did:key: {did_key}")));
+        };
+        Self::from_multikey(multibase)
+    }
+
+    /// Parse a SEC1 encoded (compressed or uncompressed) key
+    pub fn from_sec1(key_type: KeyType, bytes: &[u8]) -> Result<Self, CryptoError> {
+        match key_type {
+            KeyType::Secp256k1 => k256::ecdsa::VerifyingKey::from_sec1_bytes(bytes)
+                .map(Self::Secp256k1)
+                .map_err(|e| CryptoError::InvalidKey(e.to_string())),
+            KeyType::P256 => p256::ecdsa::VerifyingKey::from_sec1_bytes(bytes)
+                .map(Self::P256)
+                .map_err(|e| CryptoError::InvalidKey(e.to_string())),
+        }
+    }
+
+    pub fn key_type(&self) -> KeyType {
+        match self {
+            Self::Secp256k1(_) => KeyType::Secp256k1,
+            Self::P256(_) => KeyType::P256,
+        }
+    }
+
+    /// Verify a 64 byte compact `r || s` signature over the SHA-256 of `message`.
+    /// High-S signatures are rejected, as the atproto spec requires
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> bool {
+        match self {
+            Self::Secp256k1(key) => {
+                let Ok(signature) = k256::ecdsa::Signature::from_slice(signature) else {
+                    return false;
+                };
+                signature.normalize_s().is_none() && key.verify(message, &signature).is_ok()
+            }
+            Self::P256(key) => {
+                let Ok(signature) = p256::ecdsa::Signature::from_slice(signature) else {
+                    return false;
+                };
+                signature.normalize_s().is_none() && key.verify(message, &signature).is_ok()
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyType {
+    Secp256k1,
+    P256,
+}
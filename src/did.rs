@@ -0,0 +1,110 @@
+//! DID documents for did:plc and did:web identities
+use crate::crypto::{decode_multibase, CryptoError, KeyType, PublicKey};
+use crate::errors::BiskyError;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationMethod {
+    pub id: String,
+    pub r#type: String,
+    pub controller: String,
+    pub public_key_multibase: Option<String>,
+}
+
+impl VerificationMethod {
+    pub fn public_key(&self) -> Result<PublicKey, CryptoError> {
+        let Some(multibase) = &self.public_key_multibase else {
+            return Err(CryptoError::InvalidKey(format!(
+                "{} has no publicKeyMultibase",
+                self.id
+            )));
+        };
+        match self.r#type.as_str() {
+            "Multikey" => PublicKey::from_multikey(multibase),
+            // Legacy key types carry the bare SEC1 key without a multicodec prefix
+            "EcdsaSecp256k1VerificationKey2019" => {
+                PublicKey::from_sec1(KeyType::Secp256k1, &decode_multibase(multibase)?)
+            }
+            "EcdsaSecp256r1VerificationKey2019" => {
+                PublicKey::from_sec1(KeyType::P256, &decode_multibase(multibase)?)
+            }
+            other => Err(CryptoError::UnsupportedKeyType(other.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Service {
+    pub id: String,
+    pub r#type: String,
+    pub service_endpoint: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocument {
+    pub id: String,
+    #[serde(default)]
+    pub also_known_as: Vec<String>,
+    #[serde(default)]
+    pub verification_method: Vec<VerificationMethod>,
+    #[serde(default)]
+    pub service: Vec<Service>,
+}
+
+impl DidDocument {
+    /// Find a verification method by fragment, accepting both `#frag` and `did:...#frag` ids
+    pub fn verification_method(&self, fragment: &str) -> Option<&VerificationMethod> {
+        self.verification_method.iter().find(|method| {
+            method.id == format!("#{fragment}") || method.id == format!("{}#{fragment}", self.id)
+        })
+    }
+
+    /// The repo signing key, `#atproto`
+    pub fn signing_key(&self) -> Option<Result<PublicKey, CryptoError>> {
+        self.verification_method("atproto")
+            .map(VerificationMethod::public_key)
+    }
+
+    /// The label signing key of a labeler, `#atproto_label`
+    pub fn label_key(&self) -> Option<Result<PublicKey, CryptoError>> {
+        self.verification_method("atproto_label")
+            .map(VerificationMethod::public_key)
+    }
+
+    /// The handle claimed in `alsoKnownAs`
+    pub fn handle(&self) -> Option<&str> {
+        self.also_known_as
+            .iter()
+            .find_map(|aka| aka.strip_prefix("at://"))
+    }
+
+    /// The PDS endpoint, `#atproto_pds`
+    pub fn pds_endpoint(&self) -> Option<&str> {
+        self.service
+            .iter()
+            .find(|service| service.id == "#atproto_pds" || service.id.ends_with("#atproto_pds"))
+            .map(|service| service.service_endpoint.as_str())
+    }
+}
+
+/// Resolve a did:plc through plc.directory, or a did:web through its well-known document
+pub async fn resolve_did(did: &str) -> Result<DidDocument, BiskyError> {
+    let url = if did.starts_with("did:plc:") {
+        format!("https://plc.directory/{did}")
+    } else if let Some(host) = did.strip_prefix("did:web:") {
+        format!("https://{}/.well-known/did.json", host.replace("%3A", ":"))
+    } else {
+        return Err(BiskyError::UnexpectedResponse(format!(
+            "Unsupported DID method: {did}"
+        )));
+    };
+
+    Ok(reqwest::get(url)
+        .await?
+        .error_for_status()?
+        .json::<DidDocument>()
+        .await?)
+}
@@ -1,3 +1,10 @@
+use crate::car::CarError;
+use crate::composer::ComposeError;
+use crate::crypto::CryptoError;
+use crate::linkcard::LinkCardError;
+use crate::repo::RepoError;
+use crate::subscription::SubscriptionError;
+use crate::validation::ValidationError;
 use miette::Diagnostic;
 use serde::Deserialize;
 use thiserror::Error;
@@ -20,6 +27,20 @@ pub enum BiskyError {
     JsonError(#[from] serde_json::Error),
     #[error("Storage Error: {0}")]
     StorageError(String),
+    #[error(transparent)]
+    CarError(#[from] CarError),
+    #[error(transparent)]
+    RepoError(#[from] RepoError),
+    #[error(transparent)]
+    CryptoError(#[from] CryptoError),
+    #[error(transparent)]
+    SubscriptionError(#[from] SubscriptionError),
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+    #[error(transparent)]
+    ComposeError(#[from] ComposeError),
+    #[error(transparent)]
+    LinkCardError(#[from] LinkCardError),
 }
 
 #[derive(Debug, Error, Deserialize)]
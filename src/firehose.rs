@@ -0,0 +1,175 @@
+//! Consumer for the com.atproto.sync.subscribeRepos firehose
+use crate::car::{decode_block, BlockStore, Blocks, Cid};
+use crate::errors::BiskyError;
+use crate::lexicon::com::atproto::sync::{
+    SubscribeReposAccount, SubscribeReposCommit, SubscribeReposIdentity, SubscribeReposInfo,
+    SubscribeReposSync,
+};
+use crate::repo::{self, ipld_to_json, RepoRecord};
+use crate::subscription::{self, Frame, Socket, SubscriptionError, SubscriptionEvent};
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+use futures::{Stream, StreamExt};
+use ipld_core::ipld::Ipld;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio_tungstenite::tungstenite::Message;
+
+pub const SUBSCRIBE_REPOS: &str = "com.atproto.sync.subscribeRepos";
+
+#[derive(Debug)]
+pub enum FirehoseEvent {
+    Commit(Box<CommitEvent>),
+    Identity(SubscribeReposIdentity),
+    Account(SubscribeReposAccount),
+    Sync(SubscribeReposSync),
+    Info(SubscribeReposInfo),
+    /// A message type this version of bisky does not know about
+    Unknown(String),
+}
+
+impl FirehoseEvent {
+    /// Sequence number, `Info` and unknown messages have none
+    pub fn seq(&self) -> Option<i64> {
+        match self {
+            Self::Commit(commit) => Some(commit.commit.seq),
+            Self::Identity(identity) => Some(identity.seq),
+            Self::Account(account) => Some(account.seq),
+            Self::Sync(sync) => Some(sync.seq),
+            Self::Info(_) | Self::Unknown(_) => None,
+        }
+    }
+}
+
+/// A `#commit` message with its CAR slice unpacked
+#[derive(Debug)]
+pub struct CommitEvent {
+    pub commit: SubscribeReposCommit,
+    pub ops: Vec<CommitOp>,
+    pub blocks: Blocks,
+}
+
+impl CommitEvent {
+    /// The signed repo commit carried in the CAR slice, for signature verification
+    pub async fn signed_commit(&mut self) -> Result<repo::Commit, BiskyError> {
+        Ok(self.blocks.get(&self.commit.commit.0).await?)
+    }
+}
+
+#[derive(Debug)]
+pub struct CommitOp {
+    /// `create`, `update` or `delete`
+    pub action: String,
+    pub collection: String,
+    pub rkey: String,
+    pub cid: Option<Cid>,
+    /// The new record, `None` for deletes or when the block was not included
+    pub record: Option<RepoRecord>,
+}
+
+async fn decode_commit(commit: SubscribeReposCommit) -> Result<CommitEvent, BiskyError> {
+    // `tooBig` commits may leave the slice out entirely, that means no records, not a bad CAR
+    let mut blocks = match commit.blocks.0.is_empty() {
+        true => Blocks::default(),
+        false => Blocks::from_bytes(&commit.blocks.0).await?,
+    };
+
+    let mut ops = Vec::with_capacity(commit.ops.len());
+    for op in &commit.ops {
+        let (collection, rkey) = op.path.split_once('/').unwrap_or((&op.path, ""));
+        let record = match &op.cid {
+            Some(cid) => blocks
+                .get_raw(&cid.0)
+                .await?
+                .map(|data| decode_block::<Ipld>(&data))
+                .transpose()?
+                .map(|ipld| RepoRecord::from_json(collection, ipld_to_json(ipld))),
+            None => None,
+        };
+        ops.push(CommitOp {
+            action: op.action.clone(),
+            collection: collection.to_string(),
+            rkey: rkey.to_string(),
+            cid: op.cid.map(|cid| cid.0),
+            record,
+        });
+    }
+
+    Ok(CommitEvent {
+        commit,
+        ops,
+        blocks,
+    })
+}
+
+#[async_trait]
+impl SubscriptionEvent for FirehoseEvent {
+    const NSID: &'static str = SUBSCRIBE_REPOS;
+
+    async fn decode(bytes: &[u8]) -> Result<Self, BiskyError> {
+        let frame = Frame::parse(bytes)?;
+        Ok(match frame.message_type() {
+            "#commit" => Self::Commit(Box::new(decode_commit(frame.body()?).await?)),
+            "#identity" => Self::Identity(frame.body()?),
+            "#account" => Self::Account(frame.body()?),
+            "#sync" => Self::Sync(frame.body()?),
+            "#info" => Self::Info(frame.body()?),
+            other => Self::Unknown(other.to_string()),
+        })
+    }
+
+    fn seq(&self) -> Option<i64> {
+        FirehoseEvent::seq(self)
+    }
+
+    fn is_outdated_cursor(&self) -> bool {
+        matches!(self, Self::Info(info) if info.name == "OutdatedCursor")
+    }
+}
+
+/// Read the next event off the socket, skipping pings and other non-binary messages
+async fn next_event(socket: &mut Socket) -> Option<Result<FirehoseEvent, BiskyError>> {
+    loop {
+        let message = match socket.next().await? {
+            Ok(message) => message,
+            Err(e) => return Some(Err(SubscriptionError::from(e).into())),
+        };
+        match message {
+            Message::Binary(bytes) => return Some(FirehoseEvent::decode(&bytes).await),
+            Message::Close(_) => return None,
+            _ => continue,
+        }
+    }
+}
+
+/// Stream of firehose events over a single connection.
+/// Use [`SubscriptionConfig`](crate::subscription::SubscriptionConfig) for cursor persistence and reconnects
+pub struct Firehose {
+    inner: BoxStream<'static, Result<FirehoseEvent, BiskyError>>,
+}
+
+impl Firehose {
+    /// Connect to a relay or PDS, e.g. `https://bsky.network`, optionally resuming after `cursor`
+    pub async fn connect(service: &reqwest::Url, cursor: Option<i64>) -> Result<Self, BiskyError> {
+        let mut query = Vec::new();
+        if let Some(cursor) = cursor {
+            query.push(("cursor", cursor.to_string()));
+        }
+        let url = subscription::subscription_url(service, SUBSCRIBE_REPOS, &query)?;
+        let socket = subscription::connect(&url).await?;
+
+        let inner = futures::stream::unfold(socket, |mut socket| async move {
+            next_event(&mut socket).await.map(|event| (event, socket))
+        })
+        .boxed();
+        Ok(Self { inner })
+    }
+}
+
+impl Stream for Firehose {
+    type Item = Result<FirehoseEvent, BiskyError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx)
+    }
+}
@@ -0,0 +1,168 @@
+//! Consumer for Jetstream, the JSON re-encoding of the firehose without CBOR, CARs or signatures
+use crate::errors::BiskyError;
+use crate::lexicon::com::atproto::sync::{SubscribeReposAccount, SubscribeReposIdentity};
+use crate::repo::RepoRecord;
+use crate::subscription::{self, SubscriptionConfigBuilder, SubscriptionEvent};
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::Value;
+use std::io::Read;
+use std::sync::OnceLock;
+
+pub const SUBSCRIBE: &str = "subscribe";
+pub const DEFAULT_SERVICE: &str = "https://jetstream2.us-east.bsky.network";
+
+/// Dictionary published by Jetstream for `compress=true` messages
+const ZSTD_DICTIONARY: &[u8] = include_bytes!("../assets/jetstream_zstd_dictionary");
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+fn dictionary() -> &'static zstd::dict::DecoderDictionary<'static> {
+    static DICTIONARY: OnceLock<zstd::dict::DecoderDictionary<'static>> = OnceLock::new();
+    DICTIONARY.get_or_init(|| zstd::dict::DecoderDictionary::copy(ZSTD_DICTIONARY))
+}
+
+/// Start a subscription config for Jetstream. Empty filters mean everything.
+/// Finish it with `.build()` and `.subscribe::<JetstreamEvent>()`
+pub fn config(
+    wanted_collections: &[&str],
+    wanted_dids: &[&str],
+    compress: bool,
+) -> SubscriptionConfigBuilder {
+    let mut builder = SubscriptionConfigBuilder::default();
+    builder.service(reqwest::Url::parse(DEFAULT_SERVICE).unwrap());
+    for collection in wanted_collections {
+        builder.param("wantedCollections", collection);
+    }
+    for did in wanted_dids {
+        builder.param("wantedDids", did);
+    }
+    if compress {
+        builder.param("compress", "true");
+    }
+    builder
+}
+
+#[derive(Debug)]
+pub struct JetstreamEvent {
+    pub did: String,
+    /// Microseconds since the epoch, also the resume cursor
+    pub time_us: i64,
+    pub kind: JetstreamKind,
+}
+
+#[derive(Debug)]
+pub enum JetstreamKind {
+    Commit(Box<JetstreamCommit>),
+    Identity(SubscribeReposIdentity),
+    Account(SubscribeReposAccount),
+}
+
+#[derive(Debug)]
+pub struct JetstreamCommit {
+    pub rev: String,
+    pub operation: Operation,
+    pub collection: String,
+    pub rkey: String,
+    pub cid: Option<String>,
+    /// The new record, `None` for deletes
+    pub record: Option<RepoRecord>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Operation {
+    Create,
+    Update,
+    Delete,
+}
+
+#[derive(Deserialize)]
+struct RawEvent {
+    did: String,
+    time_us: i64,
+    #[serde(flatten)]
+    kind: RawKind,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+enum RawKind {
+    Commit { commit: RawCommit },
+    Identity { identity: SubscribeReposIdentity },
+    Account { account: SubscribeReposAccount },
+}
+
+#[derive(Deserialize)]
+struct RawCommit {
+    rev: String,
+    operation: Operation,
+    collection: String,
+    rkey: String,
+    cid: Option<String>,
+    record: Option<Value>,
+}
+
+impl From<RawEvent> for JetstreamEvent {
+    fn from(raw: RawEvent) -> Self {
+        let kind = match raw.kind {
+            RawKind::Commit { commit } => JetstreamKind::Commit(Box::new(JetstreamCommit {
+                record: commit
+                    .record
+                    .map(|record| RepoRecord::from_json(&commit.collection, record)),
+                rev: commit.rev,
+                operation: commit.operation,
+                collection: commit.collection,
+                rkey: commit.rkey,
+                cid: commit.cid,
+            })),
+            RawKind::Identity { identity } => JetstreamKind::Identity(identity),
+            RawKind::Account { account } => JetstreamKind::Account(account),
+        };
+        Self {
+            did: raw.did,
+            time_us: raw.time_us,
+            kind,
+        }
+    }
+}
+
+impl JetstreamEvent {
+    /// Parse a message, decompressing it first if it is zstd compressed
+    pub fn parse(bytes: &[u8]) -> Result<Self, BiskyError> {
+        let raw: RawEvent = if bytes.starts_with(&ZSTD_MAGIC) {
+            let mut json = Vec::new();
+            zstd::stream::Decoder::with_prepared_dictionary(bytes, dictionary())?
+                .read_to_end(&mut json)?;
+            serde_json::from_slice(&json)?
+        } else {
+            serde_json::from_slice(bytes)?
+        };
+        Ok(raw.into())
+    }
+}
+
+#[async_trait]
+impl SubscriptionEvent for JetstreamEvent {
+    /// Jetstream is not XRPC, see `url`
+    const NSID: &'static str = SUBSCRIBE;
+    const SEQUENTIAL: bool = false;
+
+    async fn decode(bytes: &[u8]) -> Result<Self, BiskyError> {
+        Self::parse(bytes)
+    }
+
+    fn seq(&self) -> Option<i64> {
+        Some(self.time_us)
+    }
+
+    fn is_outdated_cursor(&self) -> bool {
+        false
+    }
+
+    fn url(
+        service: &reqwest::Url,
+        query: &[(&str, String)],
+    ) -> Result<reqwest::Url, subscription::SubscriptionError> {
+        subscription::websocket_url(service, SUBSCRIBE, query)
+    }
+}
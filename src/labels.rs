@@ -0,0 +1,201 @@
+//! Consumer for com.atproto.label.subscribeLabels, with optional label signature verification
+use crate::crypto::{CryptoError, PublicKey};
+use crate::did::DidDocument;
+use crate::errors::BiskyError;
+use crate::lexicon::com::atproto::label::{Label, SubscribeLabelsInfo};
+use crate::repo::ipld_to_json;
+use crate::subscription::{
+    Frame, Subscription, SubscriptionConfig, SubscriptionConfigBuilder, SubscriptionError,
+    SubscriptionEvent,
+};
+use async_trait::async_trait;
+use futures::{Stream, StreamExt};
+use ipld_core::ipld::Ipld;
+use serde::Deserialize;
+
+pub const SUBSCRIBE_LABELS: &str = "com.atproto.label.subscribeLabels";
+
+/// Subscription settings for the labeler at `service`, e.g. `https://mod.bsky.app`
+pub fn config(service: reqwest::Url) -> SubscriptionConfigBuilder {
+    let mut builder = SubscriptionConfigBuilder::default();
+    builder.service(service);
+    builder
+}
+
+#[derive(Debug)]
+pub enum LabelEvent {
+    Labels(LabelsEvent),
+    Info(SubscribeLabelsInfo),
+    /// A message other than `#labels` or `#info`, by type
+    Unknown(String),
+}
+
+/// A `#labels` message
+#[derive(Debug)]
+pub struct LabelsEvent {
+    pub seq: i64,
+    pub labels: Vec<Label>,
+    /// Labels dropped by a verifying [`LabelSubscription`], with the reason
+    pub rejected: Vec<(Label, LabelVerification)>,
+    /// Labels as received, signatures are checked against these rather than re-encoded values
+    raw: Vec<Ipld>,
+}
+
+/// Outcome of checking a label signature against the labeler's DID document
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LabelVerification {
+    /// Signed by the `#atproto_label` key of the label's `src`
+    Valid,
+    /// The signature does not match the `#atproto_label` key
+    Invalid,
+    /// The document is for a different DID than `src`, or has no `#atproto_label` key
+    KeyMismatch,
+}
+
+#[derive(Deserialize)]
+struct RawLabels {
+    seq: i64,
+    labels: Vec<Ipld>,
+}
+
+/// Check the signature of a label as received, the signed bytes are the label without `sig`
+fn verify_raw(raw: &Ipld, key: &PublicKey) -> bool {
+    let Ipld::Map(map) = raw else {
+        return false;
+    };
+    let mut unsigned = map.clone();
+    let Some(Ipld::Bytes(sig)) = unsigned.remove("sig") else {
+        return false;
+    };
+    match serde_ipld_dagcbor::to_vec(&Ipld::Map(unsigned)) {
+        Ok(bytes) => key.verify(&bytes, &sig),
+        Err(_) => false,
+    }
+}
+
+impl LabelsEvent {
+    /// Verify every label against the `#atproto_label` key of `labeler`
+    pub fn verify(&self, labeler: &DidDocument) -> Result<Vec<LabelVerification>, CryptoError> {
+        let key = labeler.label_key().transpose()?;
+
+        Ok(self
+            .labels
+            .iter()
+            .zip(&self.raw)
+            .map(|(label, raw)| match &key {
+                Some(key) if label.src == labeler.id => match verify_raw(raw, key) {
+                    true => LabelVerification::Valid,
+                    false => LabelVerification::Invalid,
+                },
+                _ => LabelVerification::KeyMismatch,
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl SubscriptionEvent for LabelEvent {
+    const NSID: &'static str = SUBSCRIBE_LABELS;
+
+    async fn decode(bytes: &[u8]) -> Result<Self, BiskyError> {
+        let frame = Frame::parse(bytes)?;
+        Ok(match frame.message_type() {
+            "#labels" => {
+                let raw: RawLabels = frame.body()?;
+                let labels = raw
+                    .labels
+                    .iter()
+                    .map(|label| serde_json::from_value(ipld_to_json(label.clone())))
+                    .collect::<Result<_, _>>()
+                    .map_err(|e| SubscriptionError::InvalidFrame(e.to_string()))?;
+                Self::Labels(LabelsEvent {
+                    seq: raw.seq,
+                    labels,
+                    rejected: Vec::new(),
+                    raw: raw.labels,
+                })
+            }
+            "#info" => Self::Info(frame.body()?),
+            other => Self::Unknown(other.to_string()),
+        })
+    }
+
+    fn seq(&self) -> Option<i64> {
+        match self {
+            Self::Labels(labels) => Some(labels.seq),
+            Self::Info(_) | Self::Unknown(_) => None,
+        }
+    }
+
+    fn is_outdated_cursor(&self) -> bool {
+        matches!(self, Self::Info(info) if info.name == "OutdatedCursor")
+    }
+}
+
+/// Label subscription that, given the labeler's DID document, drops labels whose
+/// signature does not check out into [`LabelsEvent::rejected`]
+pub struct LabelSubscription {
+    subscription: Subscription<LabelEvent>,
+    labeler: Option<DidDocument>,
+}
+
+impl LabelSubscription {
+    pub async fn subscribe(
+        config: SubscriptionConfig,
+        labeler: Option<DidDocument>,
+    ) -> Result<Self, BiskyError> {
+        Ok(Self {
+            subscription: config.subscribe().await?,
+            labeler,
+        })
+    }
+
+    pub fn cursor(&self) -> Option<i64> {
+        self.subscription.cursor()
+    }
+
+    /// See [`Subscription::checkpoint`]
+    pub async fn checkpoint(&mut self) -> Result<(), BiskyError> {
+        self.subscription.checkpoint().await
+    }
+
+    pub async fn next(&mut self) -> Option<Result<LabelEvent, BiskyError>> {
+        let event = self.subscription.next().await?;
+        Some(event.and_then(|event| reject_unverified(self.labeler.as_ref(), event)))
+    }
+
+    pub fn into_stream(self) -> impl Stream<Item = Result<LabelEvent, BiskyError>> {
+        let labeler = self.labeler;
+        self.subscription
+            .into_stream()
+            .map(move |event| event.and_then(|event| reject_unverified(labeler.as_ref(), event)))
+    }
+}
+
+/// Move labels that fail verification against `labeler` into [`LabelsEvent::rejected`]
+fn reject_unverified(
+    labeler: Option<&DidDocument>,
+    event: LabelEvent,
+) -> Result<LabelEvent, BiskyError> {
+    let Some(labeler) = labeler else {
+        return Ok(event);
+    };
+    let mut labels = match event {
+        LabelEvent::Labels(labels) => labels,
+        event => return Ok(event),
+    };
+
+    let verification = labels.verify(labeler)?;
+    let received = std::mem::take(&mut labels.labels);
+    let raw = std::mem::take(&mut labels.raw);
+    for ((label, raw), verification) in received.into_iter().zip(raw).zip(verification) {
+        match verification {
+            LabelVerification::Valid => {
+                labels.labels.push(label);
+                labels.raw.push(raw);
+            }
+            _ => labels.rejected.push((label, verification)),
+        }
+    }
+    Ok(LabelEvent::Labels(labels))
+}
@@ -1,5 +0,0 @@
-pub mod actor;
-pub mod embed;
-pub mod feed;
-pub mod graph;
-pub mod notification;
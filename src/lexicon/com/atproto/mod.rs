@@ -1,2 +0,0 @@
-pub mod repo;
-pub mod server;
@@ -0,0 +1,106 @@
+//! Data model types the lexicons refer to by kind rather than by def. Each serializes
+//! to the atproto JSON form for human readable formats and to DAG-CBOR otherwise, so
+//! the same types read XRPC responses and firehose frames
+use crate::car::Cid;
+use base64::engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig};
+use base64::Engine;
+use serde::de::Error;
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Standard alphabet, written unpadded and read either way
+const BASE64: GeneralPurpose = GeneralPurpose::new(
+    &base64::alphabet::STANDARD,
+    GeneralPurposeConfig::new()
+        .with_encode_padding(false)
+        .with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// Fields of a record bisky does not model, re-emitted on serialize so round-trips are lossless
+pub type Extra = serde_json::Map<String, serde_json::Value>;
+
+/// Read an optional, nullable field so an explicit `null` (`Some(None)`) stays distinct
+/// from an absent one (`None`)
+pub(crate) fn nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+/// Lexicon type `bytes`, `{"$bytes": base64}` in JSON
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bytes(pub Vec<u8>);
+
+impl Serialize for Bytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() {
+            return serializer.serialize_bytes(&self.0);
+        }
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("$bytes", &BASE64.encode(&self.0))?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Bytes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Json {
+            #[serde(rename = "$bytes")]
+            bytes: String,
+        }
+
+        if !deserializer.is_human_readable() {
+            return serde_bytes::ByteBuf::deserialize(deserializer).map(|b| Self(b.into_vec()));
+        }
+        let json = Json::deserialize(deserializer)?;
+        BASE64
+            .decode(json.bytes)
+            .map(Self)
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Lexicon type `cid-link`, `{"$link": cid}` in JSON
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CidLink(pub Cid);
+
+impl Serialize for CidLink {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() {
+            return self.0.serialize(serializer);
+        }
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("$link", &self.0.to_string())?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for CidLink {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Json {
+            #[serde(rename = "$link")]
+            link: String,
+        }
+
+        if !deserializer.is_human_readable() {
+            return Cid::deserialize(deserializer).map(Self);
+        }
+        let json = Json::deserialize(deserializer)?;
+        Cid::try_from(json.link.as_str())
+            .map(Self)
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Lexicon type `blob`, a file uploaded with `com.atproto.repo.uploadBlob`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "$type", rename = "blob", rename_all = "camelCase")]
+pub struct Blob {
+    pub r#ref: CidLink,
+    pub mime_type: String,
+    pub size: usize,
+}
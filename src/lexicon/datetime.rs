@@ -0,0 +1,105 @@
+//! Lexicon datetimes. Servers differ in how many fractional digits they write, so the
+//! string is kept as read and written back unchanged
+use chrono::{DateTime, SecondsFormat, SubsecRound, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+/// Lexicon string format `datetime`, parsed as UTC but serialized as originally written.
+/// Equality and ordering compare the instant, not the text
+#[derive(Debug, Clone)]
+pub struct Datetime {
+    time: DateTime<Utc>,
+    raw: String,
+}
+
+impl Datetime {
+    /// The current time, in milliseconds like the reference implementation writes
+    pub fn now() -> Self {
+        Utc::now().trunc_subsecs(3).into()
+    }
+
+    /// The string this datetime serializes to
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+}
+
+impl From<DateTime<Utc>> for Datetime {
+    fn from(time: DateTime<Utc>) -> Self {
+        let precision = match time.timestamp_subsec_nanos() % 1_000_000 {
+            0 => SecondsFormat::Millis,
+            _ => SecondsFormat::AutoSi,
+        };
+        Self {
+            raw: time.to_rfc3339_opts(precision, true),
+            time,
+        }
+    }
+}
+
+impl From<Datetime> for DateTime<Utc> {
+    fn from(datetime: Datetime) -> Self {
+        datetime.time
+    }
+}
+
+impl FromStr for Datetime {
+    type Err = chrono::ParseError;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            time: DateTime::parse_from_rfc3339(raw)?.with_timezone(&Utc),
+            raw: raw.to_string(),
+        })
+    }
+}
+
+impl Deref for Datetime {
+    type Target = DateTime<Utc>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.time
+    }
+}
+
+impl fmt::Display for Datetime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+impl PartialEq for Datetime {
+    fn eq(&self, other: &Self) -> bool {
+        self.time == other.time
+    }
+}
+
+impl Eq for Datetime {}
+
+impl PartialOrd for Datetime {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Datetime {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.time.cmp(&other.time)
+    }
+}
+
+impl Serialize for Datetime {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.raw)
+    }
+}
+
+impl<'de> Deserialize<'de> for Datetime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        raw.parse().map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,823 @@
+//! app.bsky.actor
+// @generated by `cargo xtask codegen`, do not edit
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdultContentPref {
+    pub enabled: bool,
+}
+
+/// If set, an active progress guide. Once completed, can be set to undefined. Should have unspecced fields tracking progress.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BskyAppProgressGuide {
+    pub guide: String,
+}
+
+/// A grab bag of state that's specific to the bsky.app program. Third-party apps shouldn't use this.
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BskyAppStatePref {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_progress_guide: Option<BskyAppProgressGuide>,
+    /// Storage for NUXs the user has encountered.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nuxs: Option<Vec<Nux>>,
+    /// An array of tokens which identify nudges (modals, popups, tours, highlight dots) that should be shown to the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub queued_nudges: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentLabelPref {
+    pub label: String,
+    /// Which labeler does this preference apply to? If undefined, applies globally.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labeler_did: Option<String>,
+    pub visibility: String,
+}
+
+/// Read-only preference containing value(s) inferred from the user's declared birthdate. Absence of this preference object in the response indicates that the user has not made a declaration.
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeclaredAgePref {
+    /// Indicates if the user has declared that they are over 13 years of age.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_over_age13: Option<bool>,
+    /// Indicates if the user has declared that they are over 16 years of age.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_over_age16: Option<bool>,
+    /// Indicates if the user has declared that they are over 18 years of age.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_over_age18: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedViewPref {
+    /// The URI of the feed, or an identifier which describes the feed.
+    pub feed: String,
+    /// Hide quote posts in the feed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hide_quote_posts: Option<bool>,
+    /// Hide replies in the feed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hide_replies: Option<bool>,
+    /// Hide replies in the feed if they do not have this number of likes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hide_replies_by_like_count: Option<i64>,
+    /// Hide replies in the feed if they are not by followed users.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hide_replies_by_unfollowed: Option<bool>,
+    /// Hide reposts in the feed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hide_reposts: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HiddenPostsPref {
+    /// A list of URIs of posts the account owner has hidden.
+    pub items: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InterestsPref {
+    /// A list of tags which describe the account owner's interests gathered during onboarding.
+    pub tags: Vec<String>,
+}
+
+/// The subject's followers whom you also follow
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KnownFollowers {
+    pub count: i64,
+    pub followers: Vec<ProfileViewBasic>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelerPrefItem {
+    pub did: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelersPref {
+    pub labelers: Vec<LabelerPrefItem>,
+}
+
+/// Preferences for live events.
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveEventPreferences {
+    /// A list of feed IDs that the user has hidden from live events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hidden_feed_ids: Option<Vec<String>>,
+    /// Whether to hide all feeds from live events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hide_all_feeds: Option<bool>,
+}
+
+/// A word that the account owner has muted.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MutedWord {
+    /// Groups of users to apply the muted word to. If undefined, applies to all users.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_target: Option<String>,
+    /// The date and time at which the muted word will expire and no longer be applied.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<crate::lexicon::Datetime>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The intended targets of the muted word.
+    pub targets: Vec<MutedWordTarget>,
+    /// The muted word itself.
+    pub value: String,
+}
+
+pub type MutedWordTarget = String;
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MutedWordsPref {
+    /// A list of words the account owner has muted.
+    pub items: Vec<MutedWord>,
+}
+
+/// A new user experiences (NUX) storage object
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Nux {
+    pub completed: bool,
+    /// Arbitrary data for the NUX. The structure is defined by the NUX itself. Limited to 300 characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+    /// The date and time at which the NUX will expire and should be considered completed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<crate::lexicon::Datetime>,
+    pub id: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonalDetailsPref {
+    /// The birth date of account owner.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub birth_date: Option<crate::lexicon::Datetime>,
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum PostInteractionSettingsPrefPostgateEmbeddingRule {
+        PostgateDisableRule(Box<crate::lexicon::app::bsky::feed::PostgateDisableRule>) = "app.bsky.feed.postgate#disableRule",
+    }
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum PostInteractionSettingsPrefThreadgateAllowRule {
+        ThreadgateMentionRule(Box<crate::lexicon::app::bsky::feed::ThreadgateMentionRule>) = "app.bsky.feed.threadgate#mentionRule",
+        ThreadgateFollowerRule(Box<crate::lexicon::app::bsky::feed::ThreadgateFollowerRule>) = "app.bsky.feed.threadgate#followerRule",
+        ThreadgateFollowingRule(Box<crate::lexicon::app::bsky::feed::ThreadgateFollowingRule>) = "app.bsky.feed.threadgate#followingRule",
+        ThreadgateListRule(Box<crate::lexicon::app::bsky::feed::ThreadgateListRule>) = "app.bsky.feed.threadgate#listRule",
+    }
+}
+
+/// Default post interaction settings for the account. These values should be applied as default values when creating new posts. These refs should mirror the threadgate and postgate records exactly.
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostInteractionSettingsPref {
+    /// Matches postgate record. List of rules defining who can embed this users posts. If value is an empty array or is undefined, no particular rules apply and anyone can embed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub postgate_embedding_rules: Option<Vec<PostInteractionSettingsPrefPostgateEmbeddingRule>>,
+    /// Matches threadgate record. List of rules defining who can reply to this users posts. If value is an empty array, no one can reply. If value is undefined, anyone can reply.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threadgate_allow_rules: Option<Vec<PostInteractionSettingsPrefThreadgateAllowRule>>,
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum PreferencesItem {
+        AdultContentPref(Box<AdultContentPref>) = "app.bsky.actor.defs#adultContentPref",
+        ContentLabelPref(Box<ContentLabelPref>) = "app.bsky.actor.defs#contentLabelPref",
+        SavedFeedsPref(Box<SavedFeedsPref>) = "app.bsky.actor.defs#savedFeedsPref",
+        SavedFeedsPrefV2(Box<SavedFeedsPrefV2>) = "app.bsky.actor.defs#savedFeedsPrefV2",
+        PersonalDetailsPref(Box<PersonalDetailsPref>) = "app.bsky.actor.defs#personalDetailsPref",
+        DeclaredAgePref(Box<DeclaredAgePref>) = "app.bsky.actor.defs#declaredAgePref",
+        FeedViewPref(Box<FeedViewPref>) = "app.bsky.actor.defs#feedViewPref",
+        ThreadViewPref(Box<ThreadViewPref>) = "app.bsky.actor.defs#threadViewPref",
+        InterestsPref(Box<InterestsPref>) = "app.bsky.actor.defs#interestsPref",
+        MutedWordsPref(Box<MutedWordsPref>) = "app.bsky.actor.defs#mutedWordsPref",
+        HiddenPostsPref(Box<HiddenPostsPref>) = "app.bsky.actor.defs#hiddenPostsPref",
+        BskyAppStatePref(Box<BskyAppStatePref>) = "app.bsky.actor.defs#bskyAppStatePref",
+        LabelersPref(Box<LabelersPref>) = "app.bsky.actor.defs#labelersPref",
+        PostInteractionSettingsPref(Box<PostInteractionSettingsPref>) = "app.bsky.actor.defs#postInteractionSettingsPref",
+        VerificationPrefs(Box<VerificationPrefs>) = "app.bsky.actor.defs#verificationPrefs",
+        LiveEventPreferences(Box<LiveEventPreferences>) = "app.bsky.actor.defs#liveEventPreferences",
+    }
+}
+
+pub type Preferences = Vec<PreferencesItem>;
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileAssociated {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub activity_subscription: Option<ProfileAssociatedActivitySubscription>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chat: Option<ProfileAssociatedChat>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feedgens: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub germ: Option<ProfileAssociatedGerm>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labeler: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lists: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starter_packs: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileAssociatedActivitySubscription {
+    pub allow_subscriptions: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileAssociatedChat {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_group_invites: Option<String>,
+    pub allow_incoming: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileAssociatedGerm {
+    pub message_me_url: String,
+    pub show_button_to: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileView {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub associated: Option<ProfileAssociated>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<crate::lexicon::Datetime>,
+    /// Debug information for internal development
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub debug: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub did: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    pub handle: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indexed_at: Option<crate::lexicon::Datetime>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::lexicon::com::atproto::label::Label>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pronouns: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<StatusView>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification: Option<VerificationState>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<ViewerState>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileViewBasic {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub associated: Option<ProfileAssociated>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<crate::lexicon::Datetime>,
+    /// Debug information for internal development
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub debug: Option<serde_json::Value>,
+    pub did: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    pub handle: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::lexicon::com::atproto::label::Label>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pronouns: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<StatusView>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification: Option<VerificationState>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<ViewerState>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileViewDetailed {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub associated: Option<ProfileAssociated>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub banner: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<crate::lexicon::Datetime>,
+    /// Debug information for internal development
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub debug: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub did: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub followers_count: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub follows_count: Option<i64>,
+    pub handle: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indexed_at: Option<crate::lexicon::Datetime>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub joined_via_starter_pack: Option<crate::lexicon::app::bsky::graph::StarterPackViewBasic>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::lexicon::com::atproto::label::Label>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pinned_post: Option<crate::lexicon::com::atproto::repo::StrongRef>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub posts_count: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pronouns: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<StatusView>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification: Option<VerificationState>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<ViewerState>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub website: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedFeed {
+    pub id: String,
+    pub pinned: bool,
+    pub r#type: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedFeedsPref {
+    pub pinned: Vec<String>,
+    pub saved: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeline_index: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedFeedsPrefV2 {
+    pub items: Vec<SavedFeed>,
+}
+
+open_union! {
+    /// An optional embed associated with the status.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum StatusViewEmbed {
+        ExternalView(Box<crate::lexicon::app::bsky::embed::ExternalView>) = "app.bsky.embed.external#view",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusView {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cid: Option<String>,
+    /// An optional embed associated with the status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embed: Option<StatusViewEmbed>,
+    /// The date when this status will expire. The application might choose to no longer return the status after expiration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<crate::lexicon::Datetime>,
+    /// True if the status is not expired, false if it is expired. Only present if expiration was set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<bool>,
+    /// True if the user's go-live access has been disabled by a moderator, false otherwise.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_disabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::lexicon::com::atproto::label::Label>>,
+    pub record: serde_json::Value,
+    /// The status for the account.
+    pub status: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreadViewPref {
+    /// Sorting mode for threads.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+}
+
+/// Preferences for how verified accounts appear in the app.
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationPrefs {
+    /// Hide the blue check badges for verified accounts and trusted verifiers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hide_badges: Option<bool>,
+}
+
+/// Represents the verification information about the user this object is attached to.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationState {
+    /// The user's status as a trusted verifier.
+    pub trusted_verifier_status: String,
+    /// All verifications issued by trusted verifiers on behalf of this user. Verifications by untrusted verifiers are not included.
+    pub verifications: Vec<VerificationView>,
+    /// The user's status as a verified account.
+    pub verified_status: String,
+}
+
+/// An individual verification for an associated subject.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationView {
+    /// Timestamp when the verification was created.
+    pub created_at: crate::lexicon::Datetime,
+    /// True if the verification passes validation, otherwise false.
+    pub is_valid: bool,
+    /// The user who issued this verification.
+    pub issuer: String,
+    /// The display name of the issuer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuer_display_name: Option<String>,
+    /// The handle of the issuer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuer_handle: Option<String>,
+    /// The AT-URI of the verification record.
+    pub uri: String,
+}
+
+/// Metadata about the requesting account's relationship with the subject account. Only has meaningful content for authed requests.
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewerState {
+    /// This property is present only in selected cases, as an optimization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub activity_subscription:
+        Option<crate::lexicon::app::bsky::notification::ActivitySubscription>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocked_by: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocking: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocking_by_list: Option<crate::lexicon::app::bsky::graph::ListViewBasic>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub followed_by: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub following: Option<String>,
+    /// This property is present only in selected cases, as an optimization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub known_followers: Option<KnownFollowers>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub muted: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub muted_by_list: Option<crate::lexicon::app::bsky::graph::ListViewBasic>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPreferencesOutput {
+    pub preferences: Preferences,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetProfileParams {
+    /// Handle or DID of account to fetch profile of.
+    pub actor: String,
+}
+
+impl GetProfileParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("actor", self.actor.to_string()));
+        query
+    }
+}
+
+pub type GetProfileOutput = ProfileViewDetailed;
+
+#[derive(Debug, Default, Clone)]
+pub struct GetProfilesParams {
+    pub actors: Vec<String>,
+}
+
+impl GetProfilesParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        for value in &self.actors {
+            query.push(("actors", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetProfilesOutput {
+    pub profiles: Vec<ProfileViewDetailed>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetSuggestionsParams {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl GetSuggestionsParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSuggestionsOutput {
+    pub actors: Vec<ProfileView>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// DEPRECATED: use recIdStr instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rec_id: Option<i64>,
+    /// Snowflake for this recommendation, use when submitting recommendation events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rec_id_str: Option<String>,
+}
+
+open_union! {
+    /// Self-label values, specific to the Bluesky application, on the overall account.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum ProfileLabels {
+        SelfLabels(Box<crate::lexicon::com::atproto::label::SelfLabels>) = "com.atproto.label.defs#selfLabels",
+    }
+}
+
+/// A declaration of a Bluesky account profile.
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Profile {
+    /// Small image to be displayed next to posts from account. AKA, 'profile picture'
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<crate::lexicon::Blob>,
+    /// Larger horizontal image to display behind profile view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub banner: Option<crate::lexicon::Blob>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<crate::lexicon::Datetime>,
+    /// Free-form profile description text.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub joined_via_starter_pack: Option<crate::lexicon::com::atproto::repo::StrongRef>,
+    /// Self-label values, specific to the Bluesky application, on the overall account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<ProfileLabels>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pinned_post: Option<crate::lexicon::com::atproto::repo::StrongRef>,
+    /// Free-form pronouns text.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pronouns: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub website: Option<String>,
+    #[serde(flatten)]
+    pub extra: crate::lexicon::Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PutPreferencesInput {
+    pub preferences: Preferences,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct SearchActorsParams {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+    /// Search query string. Syntax, phrase, boolean, and faceting is unspecified, but Lucene query syntax is recommended.
+    pub q: Option<String>,
+    /// DEPRECATED: use 'q' instead.
+    pub term: Option<String>,
+}
+
+impl SearchActorsParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        if let Some(value) = &self.q {
+            query.push(("q", value.to_string()));
+        }
+        if let Some(value) = &self.term {
+            query.push(("term", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchActorsOutput {
+    pub actors: Vec<ProfileView>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct SearchActorsTypeaheadParams {
+    pub limit: Option<i64>,
+    /// Search query prefix; not a full query string.
+    pub q: Option<String>,
+    /// DEPRECATED: use 'q' instead.
+    pub term: Option<String>,
+}
+
+impl SearchActorsTypeaheadParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        if let Some(value) = &self.q {
+            query.push(("q", value.to_string()));
+        }
+        if let Some(value) = &self.term {
+            query.push(("term", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchActorsTypeaheadOutput {
+    pub actors: Vec<ProfileViewBasic>,
+}
+
+/// Advertises an account as currently offering live content.
+pub const LIVE: &str = "app.bsky.actor.status#live";
+
+open_union! {
+    /// An optional embed associated with the status.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum StatusEmbed {
+        External(Box<crate::lexicon::app::bsky::embed::External>) = "app.bsky.embed.external",
+    }
+}
+
+/// A declaration of a Bluesky account status.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Status {
+    pub created_at: crate::lexicon::Datetime,
+    /// The duration of the status in minutes. Applications can choose to impose minimum and maximum limits.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_minutes: Option<usize>,
+    /// An optional embed associated with the status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embed: Option<StatusEmbed>,
+    /// The status for the account.
+    pub status: String,
+    #[serde(flatten)]
+    pub extra: crate::lexicon::Extra,
+}
+
+impl crate::atproto::Client {
+    ///app.bsky.actor.getPreferences
+    /// Get private preferences attached to the current account. Expected use is synchronization between multiple devices, and import/export during account migration. Requires auth.
+    pub async fn app_bsky_actor_get_preferences(
+        &mut self,
+    ) -> Result<GetPreferencesOutput, crate::errors::BiskyError> {
+        self.xrpc_get("app.bsky.actor.getPreferences", None).await
+    }
+
+    ///app.bsky.actor.getProfile
+    /// Get detailed profile view of an actor. Does not require auth, but contains relevant metadata with auth.
+    pub async fn app_bsky_actor_get_profile(
+        &mut self,
+        params: &GetProfileParams,
+    ) -> Result<GetProfileOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.actor.getProfile", Some(&query))
+            .await
+    }
+
+    ///app.bsky.actor.getProfiles
+    /// Get detailed profile views of multiple actors.
+    pub async fn app_bsky_actor_get_profiles(
+        &mut self,
+        params: &GetProfilesParams,
+    ) -> Result<GetProfilesOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.actor.getProfiles", Some(&query))
+            .await
+    }
+
+    ///app.bsky.actor.getSuggestions
+    /// Get a list of suggested actors. Expected use is discovery of accounts to follow during new account onboarding.
+    pub async fn app_bsky_actor_get_suggestions(
+        &mut self,
+        params: &GetSuggestionsParams,
+    ) -> Result<GetSuggestionsOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.actor.getSuggestions", Some(&query))
+            .await
+    }
+
+    ///app.bsky.actor.putPreferences
+    /// Set the private preferences attached to the account.
+    pub async fn app_bsky_actor_put_preferences(
+        &mut self,
+        input: &PutPreferencesInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("app.bsky.actor.putPreferences", input)
+            .await
+    }
+
+    ///app.bsky.actor.searchActors
+    /// Find actors (profiles) matching search criteria. Does not require auth.
+    pub async fn app_bsky_actor_search_actors(
+        &mut self,
+        params: &SearchActorsParams,
+    ) -> Result<SearchActorsOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.actor.searchActors", Some(&query))
+            .await
+    }
+
+    ///app.bsky.actor.searchActorsTypeahead
+    /// Find actor suggestions for a prefix search term. Expected use is for auto-completion during text field entry. Does not require auth.
+    pub async fn app_bsky_actor_search_actors_typeahead(
+        &mut self,
+        params: &SearchActorsTypeaheadParams,
+    ) -> Result<SearchActorsTypeaheadOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.actor.searchActorsTypeahead", Some(&query))
+            .await
+    }
+}
@@ -0,0 +1,235 @@
+//! app.bsky.ageassurance
+// @generated by `cargo xtask codegen`, do not edit
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BeginInput {
+    /// An ISO 3166-1 alpha-2 code of the user's location.
+    pub country_code: String,
+    /// The user's email address to receive Age Assurance instructions.
+    pub email: String,
+    /// The user's preferred language for communication during the Age Assurance process.
+    pub language: String,
+    /// An optional ISO 3166-2 code of the user's region or state within the country.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region_code: Option<String>,
+}
+
+pub type BeginOutput = State;
+
+/// The access level granted based on Age Assurance data we've processed.
+pub type Access = String;
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// The per-region Age Assurance configuration.
+    pub regions: Vec<ConfigRegion>,
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum ConfigRegionRule {
+        ConfigRegionRuleDefault(Box<ConfigRegionRuleDefault>) = "app.bsky.ageassurance.defs#configRegionRuleDefault",
+        ConfigRegionRuleIfDeclaredOverAge(Box<ConfigRegionRuleIfDeclaredOverAge>) = "app.bsky.ageassurance.defs#configRegionRuleIfDeclaredOverAge",
+        ConfigRegionRuleIfDeclaredUnderAge(Box<ConfigRegionRuleIfDeclaredUnderAge>) = "app.bsky.ageassurance.defs#configRegionRuleIfDeclaredUnderAge",
+        ConfigRegionRuleIfAssuredOverAge(Box<ConfigRegionRuleIfAssuredOverAge>) = "app.bsky.ageassurance.defs#configRegionRuleIfAssuredOverAge",
+        ConfigRegionRuleIfAssuredUnderAge(Box<ConfigRegionRuleIfAssuredUnderAge>) = "app.bsky.ageassurance.defs#configRegionRuleIfAssuredUnderAge",
+        ConfigRegionRuleIfAccountNewerThan(Box<ConfigRegionRuleIfAccountNewerThan>) = "app.bsky.ageassurance.defs#configRegionRuleIfAccountNewerThan",
+        ConfigRegionRuleIfAccountOlderThan(Box<ConfigRegionRuleIfAccountOlderThan>) = "app.bsky.ageassurance.defs#configRegionRuleIfAccountOlderThan",
+    }
+}
+
+/// The Age Assurance configuration for a specific region.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigRegion {
+    /// The ISO 3166-1 alpha-2 country code this configuration applies to.
+    pub country_code: String,
+    /// The minimum age (as a whole integer) required to use Bluesky in this region.
+    pub min_access_age: i64,
+    /// The ISO 3166-2 region code this configuration applies to. If omitted, the configuration applies to the entire country.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region_code: Option<String>,
+    /// The ordered list of Age Assurance rules that apply to this region. Rules should be applied in order, and the first matching rule determines the access level granted. The rules array should always include a default rule as the last item.
+    pub rules: Vec<ConfigRegionRule>,
+}
+
+/// Age Assurance rule that applies by default.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigRegionRuleDefault {
+    pub access: Access,
+}
+
+/// Age Assurance rule that applies if the account is equal-to or newer than a certain date.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigRegionRuleIfAccountNewerThan {
+    pub access: Access,
+    /// The date threshold as a datetime string.
+    pub date: crate::lexicon::Datetime,
+}
+
+/// Age Assurance rule that applies if the account is older than a certain date.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigRegionRuleIfAccountOlderThan {
+    pub access: Access,
+    /// The date threshold as a datetime string.
+    pub date: crate::lexicon::Datetime,
+}
+
+/// Age Assurance rule that applies if the user has been assured to be equal-to or over a certain age.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigRegionRuleIfAssuredOverAge {
+    pub access: Access,
+    /// The age threshold as a whole integer.
+    pub age: i64,
+}
+
+/// Age Assurance rule that applies if the user has been assured to be under a certain age.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigRegionRuleIfAssuredUnderAge {
+    pub access: Access,
+    /// The age threshold as a whole integer.
+    pub age: i64,
+}
+
+/// Age Assurance rule that applies if the user has declared themselves equal-to or over a certain age.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigRegionRuleIfDeclaredOverAge {
+    pub access: Access,
+    /// The age threshold as a whole integer.
+    pub age: i64,
+}
+
+/// Age Assurance rule that applies if the user has declared themselves under a certain age.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigRegionRuleIfDeclaredUnderAge {
+    pub access: Access,
+    /// The age threshold as a whole integer.
+    pub age: i64,
+}
+
+/// Object used to store Age Assurance data in stash.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Event {
+    /// The access level granted based on Age Assurance data we've processed.
+    pub access: String,
+    /// The unique identifier for this instance of the Age Assurance flow, in UUID format.
+    pub attempt_id: String,
+    /// The IP address used when completing the Age Assurance flow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub complete_ip: Option<String>,
+    /// The user agent used when completing the Age Assurance flow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub complete_ua: Option<String>,
+    /// The ISO 3166-1 alpha-2 country code provided when beginning the Age Assurance flow.
+    pub country_code: String,
+    /// The date and time of this write operation.
+    pub created_at: crate::lexicon::Datetime,
+    /// The email used for Age Assurance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// The IP address used when initiating the Age Assurance flow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub init_ip: Option<String>,
+    /// The user agent used when initiating the Age Assurance flow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub init_ua: Option<String>,
+    /// The ISO 3166-2 region code provided when beginning the Age Assurance flow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region_code: Option<String>,
+    /// The status of the Age Assurance process.
+    pub status: String,
+}
+
+/// The user's computed Age Assurance state.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct State {
+    pub access: Access,
+    /// The timestamp when this state was last updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_initiated_at: Option<crate::lexicon::Datetime>,
+    pub status: Status,
+}
+
+/// Additional metadata needed to compute Age Assurance state client-side.
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateMetadata {
+    /// The account creation timestamp.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_created_at: Option<crate::lexicon::Datetime>,
+}
+
+/// The status of the Age Assurance process.
+pub type Status = String;
+
+pub type GetConfigOutput = Config;
+
+#[derive(Debug, Default, Clone)]
+pub struct GetStateParams {
+    pub country_code: String,
+    pub region_code: Option<String>,
+}
+
+impl GetStateParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("countryCode", self.country_code.to_string()));
+        if let Some(value) = &self.region_code {
+            query.push(("regionCode", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetStateOutput {
+    pub metadata: StateMetadata,
+    pub state: State,
+}
+
+impl crate::atproto::Client {
+    ///app.bsky.ageassurance.begin
+    /// Initiate Age Assurance for an account.
+    pub async fn app_bsky_ageassurance_begin(
+        &mut self,
+        input: &BeginInput,
+    ) -> Result<BeginOutput, crate::errors::BiskyError> {
+        self.xrpc_post("app.bsky.ageassurance.begin", input).await
+    }
+
+    ///app.bsky.ageassurance.getConfig
+    /// Returns Age Assurance configuration for use on the client.
+    pub async fn app_bsky_ageassurance_get_config(
+        &mut self,
+    ) -> Result<GetConfigOutput, crate::errors::BiskyError> {
+        self.xrpc_get("app.bsky.ageassurance.getConfig", None).await
+    }
+
+    ///app.bsky.ageassurance.getState
+    /// Returns server-computed Age Assurance state, if available, and any additional metadata needed to compute Age Assurance state client-side.
+    pub async fn app_bsky_ageassurance_get_state(
+        &mut self,
+        params: &GetStateParams,
+    ) -> Result<GetStateOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.ageassurance.getState", Some(&query))
+            .await
+    }
+}
@@ -0,0 +1,107 @@
+//! app.bsky.bookmark
+// @generated by `cargo xtask codegen`, do not edit
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateBookmarkInput {
+    pub cid: String,
+    pub uri: String,
+}
+
+/// Object used to store bookmark data in stash.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bookmark {
+    /// A strong ref to the record to be bookmarked. Currently, only `app.bsky.feed.post` records are supported.
+    pub subject: crate::lexicon::com::atproto::repo::StrongRef,
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum BookmarkViewItem {
+        BlockedPost(Box<crate::lexicon::app::bsky::feed::BlockedPost>) = "app.bsky.feed.defs#blockedPost",
+        NotFoundPost(Box<crate::lexicon::app::bsky::feed::NotFoundPost>) = "app.bsky.feed.defs#notFoundPost",
+        PostView(Box<crate::lexicon::app::bsky::feed::PostView>) = "app.bsky.feed.defs#postView",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BookmarkView {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<crate::lexicon::Datetime>,
+    pub item: BookmarkViewItem,
+    /// A strong ref to the bookmarked record.
+    pub subject: crate::lexicon::com::atproto::repo::StrongRef,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteBookmarkInput {
+    pub uri: String,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetBookmarksParams {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl GetBookmarksParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBookmarksOutput {
+    pub bookmarks: Vec<BookmarkView>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+impl crate::atproto::Client {
+    ///app.bsky.bookmark.createBookmark
+    /// Creates a private bookmark for the specified record. Currently, only `app.bsky.feed.post` records are supported. Requires authentication.
+    pub async fn app_bsky_bookmark_create_bookmark(
+        &mut self,
+        input: &CreateBookmarkInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("app.bsky.bookmark.createBookmark", input)
+            .await
+    }
+
+    ///app.bsky.bookmark.deleteBookmark
+    /// Deletes a private bookmark for the specified record. Currently, only `app.bsky.feed.post` records are supported. Requires authentication.
+    pub async fn app_bsky_bookmark_delete_bookmark(
+        &mut self,
+        input: &DeleteBookmarkInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("app.bsky.bookmark.deleteBookmark", input)
+            .await
+    }
+
+    ///app.bsky.bookmark.getBookmarks
+    /// Gets views of records bookmarked by the authenticated user. Requires authentication.
+    pub async fn app_bsky_bookmark_get_bookmarks(
+        &mut self,
+        params: &GetBookmarksParams,
+    ) -> Result<GetBookmarksOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.bookmark.getBookmarks", Some(&query))
+            .await
+    }
+}
@@ -0,0 +1,224 @@
+//! app.bsky.contact
+// @generated by `cargo xtask codegen`, do not edit
+
+/// Associates a profile with the positional index of the contact import input in the call to `app.bsky.contact.importContacts`, so clients can know which phone caused a particular match.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchAndContactIndex {
+    /// The index of this match in the import contact input.
+    pub contact_index: usize,
+    /// Profile of the matched user.
+    pub r#match: crate::lexicon::app::bsky::actor::ProfileView,
+}
+
+/// A stash object to be sent via bsync representing a notification to be created.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Notification {
+    /// The DID of who this notification comes from.
+    pub from: String,
+    /// The DID of who this notification should go to.
+    pub to: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncStatus {
+    /// Number of existing contact matches resulting of the user imports and of their imported contacts having imported the user. Matches stop being counted when the user either follows the matched contact or dismisses the match.
+    pub matches_count: usize,
+    /// Last date when contacts where imported.
+    pub synced_at: crate::lexicon::Datetime,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DismissMatchInput {
+    /// The subject's DID to dismiss the match with.
+    pub subject: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DismissMatchOutput {}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetMatchesParams {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl GetMatchesParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetMatchesOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub matches: Vec<crate::lexicon::app::bsky::actor::ProfileView>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSyncStatusOutput {
+    /// If present, indicates the user has imported their contacts. If not present, indicates the user never used the feature or called `app.bsky.contact.removeData` and didn't import again since.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sync_status: Option<SyncStatus>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportContactsInput {
+    /// List of phone numbers in global E.164 format (e.g., '+12125550123'). Phone numbers that cannot be normalized into a valid phone number will be discarded. Should not repeat the 'phone' input used in `app.bsky.contact.verifyPhone`.
+    pub contacts: Vec<String>,
+    /// JWT to authenticate the call. Use the JWT received as a response to the call to `app.bsky.contact.verifyPhone`.
+    pub token: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportContactsOutput {
+    /// The users that matched during import and their indexes on the input contacts, so the client can correlate with its local list.
+    pub matches_and_contact_indexes: Vec<MatchAndContactIndex>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoveDataInput {}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoveDataOutput {}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendNotificationInput {
+    /// The DID of who this notification comes from.
+    pub from: String,
+    /// The DID of who this notification should go to.
+    pub to: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendNotificationOutput {}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartPhoneVerificationInput {
+    /// The phone number to receive the code via SMS.
+    pub phone: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartPhoneVerificationOutput {}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifyPhoneInput {
+    /// The code received via SMS as a result of the call to `app.bsky.contact.startPhoneVerification`.
+    pub code: String,
+    /// The phone number to verify. Should be the same as the one passed to `app.bsky.contact.startPhoneVerification`.
+    pub phone: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifyPhoneOutput {
+    /// JWT to be used in a call to `app.bsky.contact.importContacts`. It is only valid for a single call.
+    pub token: String,
+}
+
+impl crate::atproto::Client {
+    ///app.bsky.contact.dismissMatch
+    /// Removes a match that was found via contact import. It shouldn't appear again if the same contact is re-imported. Requires authentication.
+    pub async fn app_bsky_contact_dismiss_match(
+        &mut self,
+        input: &DismissMatchInput,
+    ) -> Result<DismissMatchOutput, crate::errors::BiskyError> {
+        self.xrpc_post("app.bsky.contact.dismissMatch", input).await
+    }
+
+    ///app.bsky.contact.getMatches
+    /// Returns the matched contacts (contacts that were mutually imported). Excludes dismissed matches. Requires authentication.
+    pub async fn app_bsky_contact_get_matches(
+        &mut self,
+        params: &GetMatchesParams,
+    ) -> Result<GetMatchesOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.contact.getMatches", Some(&query))
+            .await
+    }
+
+    ///app.bsky.contact.getSyncStatus
+    /// Gets the user's current contact import status. Requires authentication.
+    pub async fn app_bsky_contact_get_sync_status(
+        &mut self,
+    ) -> Result<GetSyncStatusOutput, crate::errors::BiskyError> {
+        self.xrpc_get("app.bsky.contact.getSyncStatus", None).await
+    }
+
+    ///app.bsky.contact.importContacts
+    /// Import contacts for securely matching with other users. This follows the protocol explained in https://docs.bsky.app/blog/contact-import-rfc. Requires authentication.
+    pub async fn app_bsky_contact_import_contacts(
+        &mut self,
+        input: &ImportContactsInput,
+    ) -> Result<ImportContactsOutput, crate::errors::BiskyError> {
+        self.xrpc_post("app.bsky.contact.importContacts", input)
+            .await
+    }
+
+    ///app.bsky.contact.removeData
+    /// Removes all stored hashes used for contact matching, existing matches, and sync status. Requires authentication.
+    pub async fn app_bsky_contact_remove_data(
+        &mut self,
+        input: &RemoveDataInput,
+    ) -> Result<RemoveDataOutput, crate::errors::BiskyError> {
+        self.xrpc_post("app.bsky.contact.removeData", input).await
+    }
+
+    ///app.bsky.contact.sendNotification
+    /// System endpoint to send notifications related to contact imports. Requires role authentication.
+    pub async fn app_bsky_contact_send_notification(
+        &mut self,
+        input: &SendNotificationInput,
+    ) -> Result<SendNotificationOutput, crate::errors::BiskyError> {
+        self.xrpc_post("app.bsky.contact.sendNotification", input)
+            .await
+    }
+
+    ///app.bsky.contact.startPhoneVerification
+    /// Starts a phone verification flow. The phone passed will receive a code via SMS that should be passed to `app.bsky.contact.verifyPhone`. Requires authentication.
+    pub async fn app_bsky_contact_start_phone_verification(
+        &mut self,
+        input: &StartPhoneVerificationInput,
+    ) -> Result<StartPhoneVerificationOutput, crate::errors::BiskyError> {
+        self.xrpc_post("app.bsky.contact.startPhoneVerification", input)
+            .await
+    }
+
+    ///app.bsky.contact.verifyPhone
+    /// Verifies control over a phone number with a code received via SMS and starts a contact import session. Requires authentication.
+    pub async fn app_bsky_contact_verify_phone(
+        &mut self,
+        input: &VerifyPhoneInput,
+    ) -> Result<VerifyPhoneOutput, crate::errors::BiskyError> {
+        self.xrpc_post("app.bsky.contact.verifyPhone", input).await
+    }
+}
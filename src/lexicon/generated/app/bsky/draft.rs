@@ -0,0 +1,252 @@
+//! app.bsky.draft
+// @generated by `cargo xtask codegen`, do not edit
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateDraftInput {
+    pub draft: Draft,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateDraftOutput {
+    /// The ID of the created draft.
+    pub id: String,
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum DraftPostgateEmbeddingRule {
+        PostgateDisableRule(Box<crate::lexicon::app::bsky::feed::PostgateDisableRule>) = "app.bsky.feed.postgate#disableRule",
+    }
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum DraftThreadgateAllowItem {
+        ThreadgateMentionRule(Box<crate::lexicon::app::bsky::feed::ThreadgateMentionRule>) = "app.bsky.feed.threadgate#mentionRule",
+        ThreadgateFollowerRule(Box<crate::lexicon::app::bsky::feed::ThreadgateFollowerRule>) = "app.bsky.feed.threadgate#followerRule",
+        ThreadgateFollowingRule(Box<crate::lexicon::app::bsky::feed::ThreadgateFollowingRule>) = "app.bsky.feed.threadgate#followingRule",
+        ThreadgateListRule(Box<crate::lexicon::app::bsky::feed::ThreadgateListRule>) = "app.bsky.feed.threadgate#listRule",
+    }
+}
+
+/// A draft containing an array of draft posts.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Draft {
+    /// UUIDv4 identifier of the device that created this draft.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device_id: Option<String>,
+    /// The device and/or platform on which the draft was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device_name: Option<String>,
+    /// Indicates human language of posts primary text content.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub langs: Option<Vec<String>>,
+    /// Embedding rules for the postgates to be created when this draft is published.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub postgate_embedding_rules: Option<Vec<DraftPostgateEmbeddingRule>>,
+    /// Array of draft posts that compose this draft.
+    pub posts: Vec<DraftPost>,
+    /// Allow-rules for the threadgate to be created when this draft is published.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threadgate_allow: Option<Vec<DraftThreadgateAllowItem>>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftEmbedCaption {
+    pub content: String,
+    pub lang: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftEmbedExternal {
+    pub uri: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftEmbedGallery {
+    pub items: DraftEmbedGalleryItems,
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum DraftEmbedGalleryItemsItem {
+        DraftEmbedImage(Box<DraftEmbedImage>) = "app.bsky.draft.defs#draftEmbedImage",
+    }
+}
+
+/// The schema-level maxLength of 20 is a future-proof ceiling. Clients should currently enforce a soft limit of 10 items in authoring UIs.
+pub type DraftEmbedGalleryItems = Vec<DraftEmbedGalleryItemsItem>;
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftEmbedImage {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alt: Option<String>,
+    pub local_ref: DraftEmbedLocalRef,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftEmbedLocalRef {
+    /// Local, on-device ref to file to be embedded. Embeds are currently device-bound for drafts.
+    pub path: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftEmbedRecord {
+    pub record: crate::lexicon::com::atproto::repo::StrongRef,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftEmbedVideo {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alt: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub captions: Option<Vec<DraftEmbedCaption>>,
+    pub local_ref: DraftEmbedLocalRef,
+}
+
+open_union! {
+    /// Self-label values for this post. Effectively content warnings.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum DraftPostLabels {
+        SelfLabels(Box<crate::lexicon::com::atproto::label::SelfLabels>) = "com.atproto.label.defs#selfLabels",
+    }
+}
+
+/// One of the posts that compose a draft.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftPost {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embed_externals: Option<Vec<DraftEmbedExternal>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embed_gallery: Option<DraftEmbedGallery>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embed_images: Option<Vec<DraftEmbedImage>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embed_records: Option<Vec<DraftEmbedRecord>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embed_videos: Option<Vec<DraftEmbedVideo>>,
+    /// Self-label values for this post. Effectively content warnings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<DraftPostLabels>,
+    /// The primary post content. It has a higher limit than post contents to allow storing a larger text that can later be refined into smaller posts.
+    pub text: String,
+}
+
+/// View to present drafts data to users.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftView {
+    /// The time the draft was created.
+    pub created_at: crate::lexicon::Datetime,
+    pub draft: Draft,
+    /// A TID to be used as a draft identifier.
+    pub id: String,
+    /// The time the draft was last updated.
+    pub updated_at: crate::lexicon::Datetime,
+}
+
+/// A draft with an identifier, used to store drafts in private storage (stash).
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftWithId {
+    pub draft: Draft,
+    /// A TID to be used as a draft identifier.
+    pub id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteDraftInput {
+    pub id: String,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetDraftsParams {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl GetDraftsParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDraftsOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub drafts: Vec<DraftView>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateDraftInput {
+    pub draft: DraftWithId,
+}
+
+impl crate::atproto::Client {
+    ///app.bsky.draft.createDraft
+    /// Inserts a draft using private storage (stash). An upper limit of drafts might be enforced. Requires authentication.
+    pub async fn app_bsky_draft_create_draft(
+        &mut self,
+        input: &CreateDraftInput,
+    ) -> Result<CreateDraftOutput, crate::errors::BiskyError> {
+        self.xrpc_post("app.bsky.draft.createDraft", input).await
+    }
+
+    ///app.bsky.draft.deleteDraft
+    /// Deletes a draft by ID. Requires authentication.
+    pub async fn app_bsky_draft_delete_draft(
+        &mut self,
+        input: &DeleteDraftInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("app.bsky.draft.deleteDraft", input)
+            .await
+    }
+
+    ///app.bsky.draft.getDrafts
+    /// Gets views of user drafts. Requires authentication.
+    pub async fn app_bsky_draft_get_drafts(
+        &mut self,
+        params: &GetDraftsParams,
+    ) -> Result<GetDraftsOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.draft.getDrafts", Some(&query))
+            .await
+    }
+
+    ///app.bsky.draft.updateDraft
+    /// Updates a draft using private storage (stash). If the draft ID points to a non-existing ID, the update will be silently ignored. This is done because updates don't enforce draft limit, so it accepts all writes, but will ignore invalid ones. Requires authentication.
+    pub async fn app_bsky_draft_update_draft(
+        &mut self,
+        input: &UpdateDraftInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("app.bsky.draft.updateDraft", input)
+            .await
+    }
+}
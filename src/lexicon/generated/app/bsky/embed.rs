@@ -0,0 +1,408 @@
+//! app.bsky.embed
+// @generated by `cargo xtask codegen`, do not edit
+
+/// width:height represents an aspect ratio. It may be approximate, and may not correspond to absolute dimensions in any given unit.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AspectRatio {
+    pub height: usize,
+    pub width: usize,
+}
+
+/// RGB color definition, inspired by site.standard.theme.color#rgb
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalColorRGB {
+    pub b: usize,
+    pub g: usize,
+    pub r: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalExternal {
+    /// StrongRefs (uri+cid) of the Atmosphere records that backed this view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub associated_refs: Option<Vec<crate::lexicon::com::atproto::repo::StrongRef>>,
+    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<crate::lexicon::Blob>,
+    pub title: String,
+    pub uri: String,
+}
+
+/// A representation of some externally linked content (eg, a URL and 'card'), embedded in a Bluesky record (eg, a post).
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct External {
+    pub external: ExternalExternal,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalView {
+    pub external: ExternalViewExternal,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalViewExternal {
+    /// Profiles of the owners of the Atmosphere records that backed this view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub associated_profiles: Option<Vec<crate::lexicon::app::bsky::actor::ProfileViewBasic>>,
+    /// StrongRefs (uri+cid) of the Atmosphere records that backed this view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub associated_refs: Option<Vec<crate::lexicon::com::atproto::repo::StrongRef>>,
+    /// When the external content was created, if available. Example: a publication date, for an article.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<crate::lexicon::Datetime>,
+    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::lexicon::com::atproto::label::Label>>,
+    /// Estimated reading time in minutes, if applicable and available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reading_time: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<ExternalViewExternalSource>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<String>,
+    pub title: String,
+    /// When the external content was updated, if available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<crate::lexicon::Datetime>,
+    pub uri: String,
+}
+
+/// The source of an external embed, such as a standard.site publication.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalViewExternalSource {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Fully-qualified URL where an icon representing the source can be fetched. For example, CDN location provided by the App View.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme: Option<ExternalViewExternalSourceTheme>,
+    pub title: String,
+    /// URI of the source, if available. Example: the https:// URL of a site.standard.publication record.
+    pub uri: String,
+}
+
+/// The theme colors of an external source, such as a site.standard.publication. These colors may be used when rendering an embed from that source.
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalViewExternalSourceTheme {
+    #[serde(
+        rename = "accentForegroundRGB",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub accent_foreground_rgb: Option<ExternalColorRGB>,
+    #[serde(rename = "accentRGB", default, skip_serializing_if = "Option::is_none")]
+    pub accent_rgb: Option<ExternalColorRGB>,
+    #[serde(
+        rename = "backgroundRGB",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub background_rgb: Option<ExternalColorRGB>,
+    #[serde(
+        rename = "foregroundRGB",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub foreground_rgb: Option<ExternalColorRGB>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GalleryImage {
+    /// Alt text description of the image, for accessibility.
+    pub alt: String,
+    pub aspect_ratio: AspectRatio,
+    pub image: crate::lexicon::Blob,
+}
+
+open_union! {
+    /// The media items in the gallery. Each item may be of a different type, but all types must be supported by the client.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum GalleryItem {
+        Image(Box<GalleryImage>) = "app.bsky.embed.gallery#image",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Gallery {
+    /// The schema-level maxLength of 20 is a future-proof ceiling. Clients should currently enforce a soft limit of 10 items in authoring UIs.
+    pub items: Vec<GalleryItem>,
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum GalleryViewItem {
+        ViewImage(Box<GalleryViewImage>) = "app.bsky.embed.gallery#viewImage",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GalleryView {
+    pub items: Vec<GalleryViewItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GalleryViewImage {
+    /// Alt text description of the image, for accessibility.
+    pub alt: String,
+    pub aspect_ratio: AspectRatio,
+    /// Fully-qualified URL where a large version of the image can be fetched. May or may not be the exact original blob. For example, CDN location provided by the App View.
+    pub fullsize: String,
+    /// Fully-qualified URL where a thumbnail of the image can be fetched. For example, CDN location provided by the App View.
+    pub thumbnail: String,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetEmbedExternalViewParams {
+    /// AT-URIs of any Atmosphere records that can be resolved and used to construct #externalView views. Example: a site.standard.document and optionally its associated site.standard.publication.
+    pub uris: Vec<String>,
+    /// The canonical web URL the embed represents (typically the URL the user pasted into the composer). Used as the returned view's `uri`. May be used for validation in the future.
+    pub url: String,
+}
+
+impl GetEmbedExternalViewParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        for value in &self.uris {
+            query.push(("uris", value.to_string()));
+        }
+        query.push(("url", self.url.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetEmbedExternalViewOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub associated_records: Option<Vec<serde_json::Value>>,
+    /// StrongRefs (URI+CID) of the Atmosphere records that backed this view, suitable for embedding into a post's external.associatedRefs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub associated_refs: Option<Vec<crate::lexicon::com::atproto::repo::StrongRef>>,
+    /// Hydrated view of the embed. Present only when the resolved records back the requested URL and supply enough information to populate the required `viewExternal` fields. Omitted alongside the rest of the response when no records resolved or validation failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub view: Option<ExternalView>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImagesImage {
+    /// Alt text description of the image, for accessibility.
+    pub alt: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aspect_ratio: Option<AspectRatio>,
+    /// The raw image file. May be up to 2 MB, formerly limited to 1 MB.
+    pub image: crate::lexicon::Blob,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Images {
+    pub images: Vec<ImagesImage>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImagesView {
+    pub images: Vec<ImagesViewImage>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImagesViewImage {
+    /// Alt text description of the image, for accessibility.
+    pub alt: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aspect_ratio: Option<AspectRatio>,
+    /// Fully-qualified URL where a large version of the image can be fetched. May or may not be the exact original blob. For example, CDN location provided by the App View.
+    pub fullsize: String,
+    /// Fully-qualified URL where a thumbnail of the image can be fetched. For example, CDN location provided by the App View.
+    pub thumb: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Record {
+    pub record: crate::lexicon::com::atproto::repo::StrongRef,
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum RecordViewRecordDef {
+        ViewRecord(Box<RecordViewRecord>) = "app.bsky.embed.record#viewRecord",
+        ViewNotFound(Box<RecordViewNotFound>) = "app.bsky.embed.record#viewNotFound",
+        ViewBlocked(Box<RecordViewBlocked>) = "app.bsky.embed.record#viewBlocked",
+        ViewDetached(Box<RecordViewDetached>) = "app.bsky.embed.record#viewDetached",
+        GeneratorView(Box<crate::lexicon::app::bsky::feed::GeneratorView>) = "app.bsky.feed.defs#generatorView",
+        ListView(Box<crate::lexicon::app::bsky::graph::ListView>) = "app.bsky.graph.defs#listView",
+        LabelerView(Box<crate::lexicon::app::bsky::labeler::LabelerView>) = "app.bsky.labeler.defs#labelerView",
+        StarterPackViewBasic(Box<crate::lexicon::app::bsky::graph::StarterPackViewBasic>) = "app.bsky.graph.defs#starterPackViewBasic",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordView {
+    pub record: RecordViewRecordDef,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordViewBlocked {
+    pub author: crate::lexicon::app::bsky::feed::BlockedAuthor,
+    pub blocked: bool,
+    pub uri: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordViewDetached {
+    pub detached: bool,
+    pub uri: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordViewNotFound {
+    pub not_found: bool,
+    pub uri: String,
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum RecordViewRecordEmbed {
+        ImagesView(Box<ImagesView>) = "app.bsky.embed.images#view",
+        VideoView(Box<VideoView>) = "app.bsky.embed.video#view",
+        GalleryView(Box<GalleryView>) = "app.bsky.embed.gallery#view",
+        ExternalView(Box<ExternalView>) = "app.bsky.embed.external#view",
+        RecordView(Box<RecordView>) = "app.bsky.embed.record#view",
+        RecordWithMediaView(Box<RecordWithMediaView>) = "app.bsky.embed.recordWithMedia#view",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordViewRecord {
+    pub author: crate::lexicon::app::bsky::actor::ProfileViewBasic,
+    pub cid: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embeds: Option<Vec<RecordViewRecordEmbed>>,
+    pub indexed_at: crate::lexicon::Datetime,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::lexicon::com::atproto::label::Label>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub like_count: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quote_count: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply_count: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repost_count: Option<i64>,
+    pub uri: String,
+    /// The record data itself.
+    pub value: serde_json::Value,
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum RecordWithMediaMedia {
+        Images(Box<Images>) = "app.bsky.embed.images",
+        Video(Box<Video>) = "app.bsky.embed.video",
+        Gallery(Box<Gallery>) = "app.bsky.embed.gallery",
+        External(Box<External>) = "app.bsky.embed.external",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordWithMedia {
+    pub media: RecordWithMediaMedia,
+    pub record: Record,
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum RecordWithMediaViewMedia {
+        ImagesView(Box<ImagesView>) = "app.bsky.embed.images#view",
+        VideoView(Box<VideoView>) = "app.bsky.embed.video#view",
+        GalleryView(Box<GalleryView>) = "app.bsky.embed.gallery#view",
+        ExternalView(Box<ExternalView>) = "app.bsky.embed.external#view",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordWithMediaView {
+    pub media: RecordWithMediaViewMedia,
+    pub record: RecordView,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoCaption {
+    pub file: crate::lexicon::Blob,
+    pub lang: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Video {
+    /// Alt text description of the video, for accessibility.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alt: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aspect_ratio: Option<AspectRatio>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub captions: Option<Vec<VideoCaption>>,
+    /// A hint to the client about how to present the video.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub presentation: Option<String>,
+    /// The mp4 video file. May be up to 100mb, formerly limited to 50mb.
+    pub video: crate::lexicon::Blob,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoView {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alt: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aspect_ratio: Option<AspectRatio>,
+    pub cid: String,
+    pub playlist: String,
+    /// A hint to the client about how to present the video.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub presentation: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<String>,
+}
+
+impl crate::atproto::Client {
+    ///app.bsky.embed.getEmbedExternalView
+    /// Resolve one or more AT-URIs into the data needed to render an enhanced external embed. Returns `associatedRefs` (strongRefs to embed into a post's external.associatedRefs), the raw `associatedRecords`, and a hydrated `view`. The response is empty (`{}`) when no records were resolvable, or when validation determined the resolved records don't actually back the requested URL; clients should fall back to their own link-card rendering in that case and skip writing strongRefs to the post.
+    pub async fn app_bsky_embed_get_embed_external_view(
+        &mut self,
+        params: &GetEmbedExternalViewParams,
+    ) -> Result<GetEmbedExternalViewOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.embed.getEmbedExternalView", Some(&query))
+            .await
+    }
+}
@@ -0,0 +1,1590 @@
+//! app.bsky.feed
+// @generated by `cargo xtask codegen`, do not edit
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockedAuthor {
+    pub did: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<crate::lexicon::app::bsky::actor::ViewerState>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockedPost {
+    pub author: BlockedAuthor,
+    pub blocked: bool,
+    pub uri: String,
+}
+
+/// User clicked through to the author of the feed item
+pub const CLICKTHROUGH_AUTHOR: &str = "app.bsky.feed.defs#clickthroughAuthor";
+
+/// User clicked through to the embedded content of the feed item
+pub const CLICKTHROUGH_EMBED: &str = "app.bsky.feed.defs#clickthroughEmbed";
+
+/// User clicked through to the feed item
+pub const CLICKTHROUGH_ITEM: &str = "app.bsky.feed.defs#clickthroughItem";
+
+/// User clicked through to the reposter of the feed item
+pub const CLICKTHROUGH_REPOSTER: &str = "app.bsky.feed.defs#clickthroughReposter";
+
+/// Declares the feed generator returns any types of posts.
+pub const CONTENT_MODE_UNSPECIFIED: &str = "app.bsky.feed.defs#contentModeUnspecified";
+
+/// Declares the feed generator returns posts containing app.bsky.embed.video embeds.
+pub const CONTENT_MODE_VIDEO: &str = "app.bsky.feed.defs#contentModeVideo";
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum FeedViewPostReason {
+        ReasonRepost(Box<ReasonRepost>) = "app.bsky.feed.defs#reasonRepost",
+        ReasonPin(Box<ReasonPin>) = "app.bsky.feed.defs#reasonPin",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedViewPost {
+    /// Context provided by feed generator that may be passed back alongside interactions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feed_context: Option<String>,
+    pub post: PostView,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<FeedViewPostReason>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply: Option<ReplyRef>,
+    /// Unique identifier per request that may be passed back alongside interactions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub req_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratorView {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accepts_interactions: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<String>,
+    pub cid: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_mode: Option<String>,
+    pub creator: crate::lexicon::app::bsky::actor::ProfileView,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_facets: Option<Vec<crate::lexicon::app::bsky::richtext::Facet>>,
+    pub did: String,
+    pub display_name: String,
+    pub indexed_at: crate::lexicon::Datetime,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::lexicon::com::atproto::label::Label>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub like_count: Option<usize>,
+    pub uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<GeneratorViewerState>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratorViewerState {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub like: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Interaction {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event: Option<String>,
+    /// Context on a feed item that was originally supplied by the feed generator on getFeedSkeleton.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feed_context: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub item: Option<String>,
+    /// Unique identifier per request that may be passed back alongside interactions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub req_id: Option<String>,
+}
+
+/// User liked the feed item
+pub const INTERACTION_LIKE: &str = "app.bsky.feed.defs#interactionLike";
+
+/// User quoted the feed item
+pub const INTERACTION_QUOTE: &str = "app.bsky.feed.defs#interactionQuote";
+
+/// User replied to the feed item
+pub const INTERACTION_REPLY: &str = "app.bsky.feed.defs#interactionReply";
+
+/// User reposted the feed item
+pub const INTERACTION_REPOST: &str = "app.bsky.feed.defs#interactionRepost";
+
+/// Feed item was seen by user
+pub const INTERACTION_SEEN: &str = "app.bsky.feed.defs#interactionSeen";
+
+/// User shared the feed item
+pub const INTERACTION_SHARE: &str = "app.bsky.feed.defs#interactionShare";
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotFoundPost {
+    pub not_found: bool,
+    pub uri: String,
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum PostViewEmbed {
+        ImagesView(Box<crate::lexicon::app::bsky::embed::ImagesView>) = "app.bsky.embed.images#view",
+        VideoView(Box<crate::lexicon::app::bsky::embed::VideoView>) = "app.bsky.embed.video#view",
+        GalleryView(Box<crate::lexicon::app::bsky::embed::GalleryView>) = "app.bsky.embed.gallery#view",
+        ExternalView(Box<crate::lexicon::app::bsky::embed::ExternalView>) = "app.bsky.embed.external#view",
+        RecordView(Box<crate::lexicon::app::bsky::embed::RecordView>) = "app.bsky.embed.record#view",
+        RecordWithMediaView(Box<crate::lexicon::app::bsky::embed::RecordWithMediaView>) = "app.bsky.embed.recordWithMedia#view",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostView {
+    pub author: crate::lexicon::app::bsky::actor::ProfileViewBasic,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bookmark_count: Option<i64>,
+    pub cid: String,
+    /// Debug information for internal development
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub debug: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embed: Option<PostViewEmbed>,
+    pub indexed_at: crate::lexicon::Datetime,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::lexicon::com::atproto::label::Label>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub like_count: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quote_count: Option<i64>,
+    pub record: Post,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply_count: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repost_count: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threadgate: Option<ThreadgateView>,
+    pub uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<ViewerState>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReasonPin {}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReasonRepost {
+    pub by: crate::lexicon::app::bsky::actor::ProfileViewBasic,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cid: Option<String>,
+    pub indexed_at: crate::lexicon::Datetime,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum ReplyRefParent {
+        PostView(Box<PostView>) = "app.bsky.feed.defs#postView",
+        NotFoundPost(Box<NotFoundPost>) = "app.bsky.feed.defs#notFoundPost",
+        BlockedPost(Box<BlockedPost>) = "app.bsky.feed.defs#blockedPost",
+    }
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum ReplyRefRoot {
+        PostView(Box<PostView>) = "app.bsky.feed.defs#postView",
+        NotFoundPost(Box<NotFoundPost>) = "app.bsky.feed.defs#notFoundPost",
+        BlockedPost(Box<BlockedPost>) = "app.bsky.feed.defs#blockedPost",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplyRef {
+    /// When parent is a reply to another post, this is the author of that post.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grandparent_author: Option<crate::lexicon::app::bsky::actor::ProfileViewBasic>,
+    pub parent: ReplyRefParent,
+    pub root: ReplyRefRoot,
+}
+
+/// Request that less content like the given feed item be shown in the feed
+pub const REQUEST_LESS: &str = "app.bsky.feed.defs#requestLess";
+
+/// Request that more content like the given feed item be shown in the feed
+pub const REQUEST_MORE: &str = "app.bsky.feed.defs#requestMore";
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum SkeletonFeedPostReason {
+        SkeletonReasonRepost(Box<SkeletonReasonRepost>) = "app.bsky.feed.defs#skeletonReasonRepost",
+        SkeletonReasonPin(Box<SkeletonReasonPin>) = "app.bsky.feed.defs#skeletonReasonPin",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkeletonFeedPost {
+    /// Context that will be passed through to client and may be passed to feed generator back alongside interactions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feed_context: Option<String>,
+    pub post: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<SkeletonFeedPostReason>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkeletonReasonPin {}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkeletonReasonRepost {
+    pub repost: String,
+}
+
+/// Metadata about this post within the context of the thread it is in.
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreadContext {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root_author_like: Option<String>,
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum ThreadViewPostParent {
+        ThreadViewPost(Box<ThreadViewPost>) = "app.bsky.feed.defs#threadViewPost",
+        NotFoundPost(Box<NotFoundPost>) = "app.bsky.feed.defs#notFoundPost",
+        BlockedPost(Box<BlockedPost>) = "app.bsky.feed.defs#blockedPost",
+    }
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum ThreadViewPostReply {
+        ThreadViewPost(Box<ThreadViewPost>) = "app.bsky.feed.defs#threadViewPost",
+        NotFoundPost(Box<NotFoundPost>) = "app.bsky.feed.defs#notFoundPost",
+        BlockedPost(Box<BlockedPost>) = "app.bsky.feed.defs#blockedPost",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreadViewPost {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<ThreadViewPostParent>,
+    pub post: PostView,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replies: Option<Vec<ThreadViewPostReply>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thread_context: Option<ThreadContext>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreadgateView {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lists: Option<Vec<crate::lexicon::app::bsky::graph::ListViewBasic>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record: Option<Threadgate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+}
+
+/// Metadata about the requesting account's relationship with the subject content. Only has meaningful content for authed requests.
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewerState {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bookmarked: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embedding_disabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub like: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pinned: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply_disabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repost: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thread_muted: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DescribeFeedGeneratorFeed {
+    pub uri: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DescribeFeedGeneratorLinks {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub privacy_policy: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terms_of_service: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DescribeFeedGeneratorOutput {
+    pub did: String,
+    pub feeds: Vec<DescribeFeedGeneratorFeed>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub links: Option<DescribeFeedGeneratorLinks>,
+}
+
+open_union! {
+    /// Self-label values
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum GeneratorLabels {
+        SelfLabels(Box<crate::lexicon::com::atproto::label::SelfLabels>) = "com.atproto.label.defs#selfLabels",
+    }
+}
+
+/// Record declaring of the existence of a feed generator, and containing metadata about it. The record can exist in any repository.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Generator {
+    /// Declaration that a feed accepts feedback interactions from a client through app.bsky.feed.sendInteractions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accepts_interactions: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<crate::lexicon::Blob>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_mode: Option<String>,
+    pub created_at: crate::lexicon::Datetime,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_facets: Option<Vec<crate::lexicon::app::bsky::richtext::Facet>>,
+    pub did: String,
+    pub display_name: String,
+    /// Self-label values
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<GeneratorLabels>,
+    #[serde(flatten)]
+    pub extra: crate::lexicon::Extra,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetActorFeedsParams {
+    pub actor: String,
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl GetActorFeedsParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("actor", self.actor.to_string()));
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetActorFeedsOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub feeds: Vec<GeneratorView>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetActorLikesParams {
+    pub actor: String,
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl GetActorLikesParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("actor", self.actor.to_string()));
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetActorLikesOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub feed: Vec<FeedViewPost>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetAuthorFeedParams {
+    pub actor: String,
+    pub cursor: Option<String>,
+    /// Combinations of post/repost types to include in response.
+    pub filter: Option<String>,
+    pub include_pins: Option<bool>,
+    pub limit: Option<i64>,
+}
+
+impl GetAuthorFeedParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("actor", self.actor.to_string()));
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.filter {
+            query.push(("filter", value.to_string()));
+        }
+        if let Some(value) = &self.include_pins {
+            query.push(("includePins", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAuthorFeedOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub feed: Vec<FeedViewPost>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetFeedParams {
+    pub cursor: Option<String>,
+    pub feed: String,
+    pub limit: Option<i64>,
+}
+
+impl GetFeedParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        query.push(("feed", self.feed.to_string()));
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFeedOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub feed: Vec<FeedViewPost>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetFeedGeneratorParams {
+    /// AT-URI of the feed generator record.
+    pub feed: String,
+}
+
+impl GetFeedGeneratorParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("feed", self.feed.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFeedGeneratorOutput {
+    /// Indicates whether the feed generator service has been online recently, or else seems to be inactive.
+    pub is_online: bool,
+    /// Indicates whether the feed generator service is compatible with the record declaration.
+    pub is_valid: bool,
+    pub view: GeneratorView,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetFeedGeneratorsParams {
+    pub feeds: Vec<String>,
+}
+
+impl GetFeedGeneratorsParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        for value in &self.feeds {
+            query.push(("feeds", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFeedGeneratorsOutput {
+    pub feeds: Vec<GeneratorView>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetFeedSkeletonParams {
+    pub cursor: Option<String>,
+    /// Reference to feed generator record describing the specific feed being requested.
+    pub feed: String,
+    pub limit: Option<i64>,
+}
+
+impl GetFeedSkeletonParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        query.push(("feed", self.feed.to_string()));
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFeedSkeletonOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub feed: Vec<SkeletonFeedPost>,
+    /// Unique identifier per request that may be passed back alongside interactions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub req_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetLikesLike {
+    pub actor: crate::lexicon::app::bsky::actor::ProfileView,
+    pub created_at: crate::lexicon::Datetime,
+    pub indexed_at: crate::lexicon::Datetime,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetLikesParams {
+    /// CID of the subject record (aka, specific version of record), to filter likes.
+    pub cid: Option<String>,
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+    /// AT-URI of the subject (eg, a post record).
+    pub uri: String,
+}
+
+impl GetLikesParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cid {
+            query.push(("cid", value.to_string()));
+        }
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query.push(("uri", self.uri.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetLikesOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub likes: Vec<GetLikesLike>,
+    pub uri: String,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetListFeedParams {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+    /// Reference (AT-URI) to the list record.
+    pub list: String,
+}
+
+impl GetListFeedParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query.push(("list", self.list.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetListFeedOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub feed: Vec<FeedViewPost>,
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum GetPostThreadOutputThread {
+        ThreadViewPost(Box<ThreadViewPost>) = "app.bsky.feed.defs#threadViewPost",
+        NotFoundPost(Box<NotFoundPost>) = "app.bsky.feed.defs#notFoundPost",
+        BlockedPost(Box<BlockedPost>) = "app.bsky.feed.defs#blockedPost",
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetPostThreadParams {
+    /// How many levels of reply depth should be included in response.
+    pub depth: Option<i64>,
+    /// How many levels of parent (and grandparent, etc) post to include.
+    pub parent_height: Option<i64>,
+    /// Reference (AT-URI) to post record.
+    pub uri: String,
+}
+
+impl GetPostThreadParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.depth {
+            query.push(("depth", value.to_string()));
+        }
+        if let Some(value) = &self.parent_height {
+            query.push(("parentHeight", value.to_string()));
+        }
+        query.push(("uri", self.uri.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPostThreadOutput {
+    pub thread: GetPostThreadOutputThread,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threadgate: Option<ThreadgateView>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetPostsParams {
+    /// List of post AT-URIs to return hydrated views for.
+    pub uris: Vec<String>,
+}
+
+impl GetPostsParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        for value in &self.uris {
+            query.push(("uris", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPostsOutput {
+    pub posts: Vec<PostView>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetQuotesParams {
+    /// If supplied, filters to quotes of specific version (by CID) of the post record.
+    pub cid: Option<String>,
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+    /// Reference (AT-URI) of post record
+    pub uri: String,
+}
+
+impl GetQuotesParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cid {
+            query.push(("cid", value.to_string()));
+        }
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query.push(("uri", self.uri.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetQuotesOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub posts: Vec<PostView>,
+    pub uri: String,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetRepostedByParams {
+    /// If supplied, filters to reposts of specific version (by CID) of the post record.
+    pub cid: Option<String>,
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+    /// Reference (AT-URI) of post record
+    pub uri: String,
+}
+
+impl GetRepostedByParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cid {
+            query.push(("cid", value.to_string()));
+        }
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query.push(("uri", self.uri.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRepostedByOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub reposted_by: Vec<crate::lexicon::app::bsky::actor::ProfileView>,
+    pub uri: String,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetSuggestedFeedsParams {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl GetSuggestedFeedsParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSuggestedFeedsOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub feeds: Vec<GeneratorView>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetTimelineParams {
+    /// Variant 'algorithm' for timeline. Implementation-specific. NOTE: most feed flexibility has been moved to feed generator mechanism.
+    pub algorithm: Option<String>,
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl GetTimelineParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.algorithm {
+            query.push(("algorithm", value.to_string()));
+        }
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTimelineOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub feed: Vec<FeedViewPost>,
+}
+
+/// Record declaring a 'like' of a piece of subject content.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Like {
+    pub created_at: crate::lexicon::Datetime,
+    pub subject: crate::lexicon::com::atproto::repo::StrongRef,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub via: Option<crate::lexicon::com::atproto::repo::StrongRef>,
+    #[serde(flatten)]
+    pub extra: crate::lexicon::Extra,
+}
+
+/// Deprecated: use facets instead.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostEntity {
+    pub index: PostTextSlice,
+    /// Expected values are 'mention' and 'link'.
+    pub r#type: String,
+    pub value: String,
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum PostEmbed {
+        Images(Box<crate::lexicon::app::bsky::embed::Images>) = "app.bsky.embed.images",
+        Video(Box<crate::lexicon::app::bsky::embed::Video>) = "app.bsky.embed.video",
+        Gallery(Box<crate::lexicon::app::bsky::embed::Gallery>) = "app.bsky.embed.gallery",
+        External(Box<crate::lexicon::app::bsky::embed::External>) = "app.bsky.embed.external",
+        Record(Box<crate::lexicon::app::bsky::embed::Record>) = "app.bsky.embed.record",
+        RecordWithMedia(Box<crate::lexicon::app::bsky::embed::RecordWithMedia>) = "app.bsky.embed.recordWithMedia",
+    }
+}
+
+open_union! {
+    /// Self-label values for this post. Effectively content warnings.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum PostLabels {
+        SelfLabels(Box<crate::lexicon::com::atproto::label::SelfLabels>) = "com.atproto.label.defs#selfLabels",
+    }
+}
+
+/// Record containing a Bluesky post.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Post {
+    /// Client-declared timestamp when this post was originally created.
+    pub created_at: crate::lexicon::Datetime,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embed: Option<PostEmbed>,
+    /// DEPRECATED: replaced by app.bsky.richtext.facet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entities: Option<Vec<PostEntity>>,
+    /// Annotations of text (mentions, URLs, hashtags, etc)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub facets: Option<Vec<crate::lexicon::app::bsky::richtext::Facet>>,
+    /// Self-label values for this post. Effectively content warnings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<PostLabels>,
+    /// Indicates human language of post primary text content.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub langs: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply: Option<PostReplyRef>,
+    /// Additional hashtags, in addition to any included in post text and facets.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// The primary post content. May be an empty string, if there are embeds.
+    pub text: String,
+    #[serde(flatten)]
+    pub extra: crate::lexicon::Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostReplyRef {
+    pub parent: crate::lexicon::com::atproto::repo::StrongRef,
+    pub root: crate::lexicon::com::atproto::repo::StrongRef,
+}
+
+/// Deprecated. Use app.bsky.richtext instead -- A text segment. Start is inclusive, end is exclusive. Indices are for utf16-encoded strings.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostTextSlice {
+    pub end: usize,
+    pub start: usize,
+}
+
+/// Disables embedding of this post.
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostgateDisableRule {}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum PostgateEmbeddingRule {
+        DisableRule(Box<PostgateDisableRule>) = "app.bsky.feed.postgate#disableRule",
+    }
+}
+
+/// Record defining interaction rules for a post. The record key (rkey) of the postgate record must match the record key of the post, and that record must be in the same repository.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Postgate {
+    pub created_at: crate::lexicon::Datetime,
+    /// List of AT-URIs embedding this post that the author has detached from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detached_embedding_uris: Option<Vec<String>>,
+    /// List of rules defining who can embed this post. If value is an empty array or is undefined, no particular rules apply and anyone can embed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embedding_rules: Option<Vec<PostgateEmbeddingRule>>,
+    /// Reference (AT-URI) to the post record.
+    pub post: String,
+    #[serde(flatten)]
+    pub extra: crate::lexicon::Extra,
+}
+
+/// Record representing a 'repost' of an existing Bluesky post.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Repost {
+    pub created_at: crate::lexicon::Datetime,
+    pub subject: crate::lexicon::com::atproto::repo::StrongRef,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub via: Option<crate::lexicon::com::atproto::repo::StrongRef>,
+    #[serde(flatten)]
+    pub extra: crate::lexicon::Extra,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct SearchPostsParams {
+    /// Filter to posts by the given account. Handles are resolved to DID before query-time.
+    pub author: Option<String>,
+    /// Optional pagination mechanism; may not necessarily allow scrolling through entire result set.
+    pub cursor: Option<String>,
+    /// Filter to posts with URLs (facet links or embeds) linking to the given domain (hostname). Server may apply hostname normalization.
+    pub domain: Option<String>,
+    /// Filter to posts in the given language. Expected to be based on post language field, though server may override language detection.
+    pub lang: Option<String>,
+    pub limit: Option<i64>,
+    /// Filter to posts which mention the given account. Handles are resolved to DID before query-time. Only matches rich-text facet mentions.
+    pub mentions: Option<String>,
+    /// Search query string; syntax, phrase, boolean, and faceting is unspecified, but Lucene query syntax is recommended.
+    pub q: String,
+    /// Filter results for posts after the indicated datetime (inclusive). Expected to use 'sortAt' timestamp, which may not match 'createdAt'. Can be a datetime, or just an ISO date (YYYY-MM-DD).
+    pub since: Option<String>,
+    /// Specifies the ranking order of results.
+    pub sort: Option<String>,
+    /// Filter to posts with the given tag (hashtag), based on rich-text facet or tag field. Do not include the hash (#) prefix. Multiple tags can be specified, with 'AND' matching.
+    pub tag: Vec<String>,
+    /// Filter results for posts before the indicated datetime (not inclusive). Expected to use 'sortAt' timestamp, which may not match 'createdAt'. Can be a datetime, or just an ISO date (YYY-MM-DD).
+    pub until: Option<String>,
+    /// Filter to posts with links (facet links or embeds) pointing to this URL. Server may apply URL normalization or fuzzy matching.
+    pub url: Option<String>,
+}
+
+impl SearchPostsParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.author {
+            query.push(("author", value.to_string()));
+        }
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.domain {
+            query.push(("domain", value.to_string()));
+        }
+        if let Some(value) = &self.lang {
+            query.push(("lang", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        if let Some(value) = &self.mentions {
+            query.push(("mentions", value.to_string()));
+        }
+        query.push(("q", self.q.to_string()));
+        if let Some(value) = &self.since {
+            query.push(("since", value.to_string()));
+        }
+        if let Some(value) = &self.sort {
+            query.push(("sort", value.to_string()));
+        }
+        for value in &self.tag {
+            query.push(("tag", value.to_string()));
+        }
+        if let Some(value) = &self.until {
+            query.push(("until", value.to_string()));
+        }
+        if let Some(value) = &self.url {
+            query.push(("url", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchPostsOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// Count of search hits. Optional, may be rounded/truncated, and may not be possible to paginate through all hits.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hits_total: Option<i64>,
+    pub posts: Vec<PostView>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct SearchPostsV2Params {
+    /// Search the full index instead of the recent-post window.
+    pub all_time: Option<bool>,
+    /// Include posts by any of these authors. Handles are resolved to DIDs before searching.
+    pub authors: Vec<String>,
+    /// Optional pagination cursor.
+    pub cursor: Option<String>,
+    /// Include posts that link to any of these domains.
+    pub domains: Vec<String>,
+    /// Include posts that embed any of these AT URIs.
+    pub embedded_at_uris: Vec<String>,
+    /// Exclude posts by any of these authors. Handles are resolved to DIDs before searching.
+    pub exclude_authors: Vec<String>,
+    /// Exclude posts that link to any of these domains.
+    pub exclude_domains: Vec<String>,
+    /// Exclude posts that embed any of these AT URIs.
+    pub exclude_embedded_at_uris: Vec<String>,
+    /// Exclude posts tagged with any of these hashtags. Do not include the hash (#) prefix.
+    pub exclude_hashtags: Vec<String>,
+    /// Exclude posts whose language matches any of these language codes.
+    pub exclude_languages: Vec<String>,
+    /// Exclude posts that mention any of these accounts. Handles are resolved to DIDs before searching.
+    pub exclude_mentions: Vec<String>,
+    /// Exclude replies from results. Mutually exclusive with repliesOnly.
+    pub exclude_replies: Option<bool>,
+    /// Exclude posts that link to any of these URLs.
+    pub exclude_urls: Vec<String>,
+    /// Include only posts from accounts followed by the viewer.
+    pub following: Option<bool>,
+    /// Include only posts with media.
+    pub has_media: Option<bool>,
+    /// Include only posts with video.
+    pub has_video: Option<bool>,
+    /// Include posts tagged with any of these hashtags. Do not include the hash (#) prefix.
+    pub hashtags: Vec<String>,
+    /// Include posts whose language matches any of these language codes.
+    pub languages: Vec<String>,
+    /// Maximum number of results to return.
+    pub limit: Option<i64>,
+    /// Include posts that mention any of these accounts. Handles are resolved to DIDs before searching.
+    pub mentions: Vec<String>,
+    /// Search query string. A query or at least one filter is required.
+    pub query: Option<String>,
+    /// Language analyzer hint for the query text. If unset, the server auto-detects when possible.
+    pub query_language: Option<String>,
+    /// Include only replies. Mutually exclusive with excludeReplies.
+    pub replies_only: Option<bool>,
+    /// Include only direct replies to this parent post URI.
+    pub reply_parent_uri: Option<String>,
+    /// Include posts indexed at or after this timestamp. Can be a datetime, or just an ISO date (YYYY-MM-DD).
+    pub since: Option<String>,
+    /// Ranking order for results. 'recent' sorts by recency; 'top' uses search ranking.
+    pub sort: Option<String>,
+    /// Include only posts in the thread rooted at this post URI.
+    pub thread_root_uri: Option<String>,
+    /// Include posts indexed before this timestamp. Defaults to the current time. Can be a datetime, or just an ISO date (YYYY-MM-DD).
+    pub until: Option<String>,
+    /// Include posts that link to any of these URLs.
+    pub urls: Vec<String>,
+}
+
+impl SearchPostsV2Params {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.all_time {
+            query.push(("allTime", value.to_string()));
+        }
+        for value in &self.authors {
+            query.push(("authors", value.to_string()));
+        }
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        for value in &self.domains {
+            query.push(("domains", value.to_string()));
+        }
+        for value in &self.embedded_at_uris {
+            query.push(("embeddedAtUris", value.to_string()));
+        }
+        for value in &self.exclude_authors {
+            query.push(("excludeAuthors", value.to_string()));
+        }
+        for value in &self.exclude_domains {
+            query.push(("excludeDomains", value.to_string()));
+        }
+        for value in &self.exclude_embedded_at_uris {
+            query.push(("excludeEmbeddedAtUris", value.to_string()));
+        }
+        for value in &self.exclude_hashtags {
+            query.push(("excludeHashtags", value.to_string()));
+        }
+        for value in &self.exclude_languages {
+            query.push(("excludeLanguages", value.to_string()));
+        }
+        for value in &self.exclude_mentions {
+            query.push(("excludeMentions", value.to_string()));
+        }
+        if let Some(value) = &self.exclude_replies {
+            query.push(("excludeReplies", value.to_string()));
+        }
+        for value in &self.exclude_urls {
+            query.push(("excludeUrls", value.to_string()));
+        }
+        if let Some(value) = &self.following {
+            query.push(("following", value.to_string()));
+        }
+        if let Some(value) = &self.has_media {
+            query.push(("hasMedia", value.to_string()));
+        }
+        if let Some(value) = &self.has_video {
+            query.push(("hasVideo", value.to_string()));
+        }
+        for value in &self.hashtags {
+            query.push(("hashtags", value.to_string()));
+        }
+        for value in &self.languages {
+            query.push(("languages", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        for value in &self.mentions {
+            query.push(("mentions", value.to_string()));
+        }
+        if let Some(value) = &self.query {
+            query.push(("query", value.to_string()));
+        }
+        if let Some(value) = &self.query_language {
+            query.push(("queryLanguage", value.to_string()));
+        }
+        if let Some(value) = &self.replies_only {
+            query.push(("repliesOnly", value.to_string()));
+        }
+        if let Some(value) = &self.reply_parent_uri {
+            query.push(("replyParentUri", value.to_string()));
+        }
+        if let Some(value) = &self.since {
+            query.push(("since", value.to_string()));
+        }
+        if let Some(value) = &self.sort {
+            query.push(("sort", value.to_string()));
+        }
+        if let Some(value) = &self.thread_root_uri {
+            query.push(("threadRootUri", value.to_string()));
+        }
+        if let Some(value) = &self.until {
+            query.push(("until", value.to_string()));
+        }
+        for value in &self.urls {
+            query.push(("urls", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchPostsV2Output {
+    /// Cursor for the next page of results.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// Query languages detected for CJK, Thai, or Arabic text. Empty or omitted for other scripts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detected_query_languages: Option<Vec<String>>,
+    /// Estimated total number of matching hits. May be rounded or truncated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hits_total: Option<i64>,
+    /// Hydrated views of matching posts.
+    pub posts: Vec<PostView>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendInteractionsInput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feed: Option<String>,
+    pub interactions: Vec<Interaction>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendInteractionsOutput {}
+
+/// Allow replies from actors who follow you.
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreadgateFollowerRule {}
+
+/// Allow replies from actors you follow.
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreadgateFollowingRule {}
+
+/// Allow replies from actors on a list.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreadgateListRule {
+    pub list: String,
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum ThreadgateAllowItem {
+        MentionRule(Box<ThreadgateMentionRule>) = "app.bsky.feed.threadgate#mentionRule",
+        FollowerRule(Box<ThreadgateFollowerRule>) = "app.bsky.feed.threadgate#followerRule",
+        FollowingRule(Box<ThreadgateFollowingRule>) = "app.bsky.feed.threadgate#followingRule",
+        ListRule(Box<ThreadgateListRule>) = "app.bsky.feed.threadgate#listRule",
+    }
+}
+
+/// Record defining interaction gating rules for a thread (aka, reply controls). The record key (rkey) of the threadgate record must match the record key of the thread's root post, and that record must be in the same repository.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Threadgate {
+    /// List of rules defining who can reply to this post. If value is an empty array, no one can reply. If value is undefined, anyone can reply.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow: Option<Vec<ThreadgateAllowItem>>,
+    pub created_at: crate::lexicon::Datetime,
+    /// List of hidden reply URIs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hidden_replies: Option<Vec<String>>,
+    /// Reference (AT-URI) to the post record.
+    pub post: String,
+    #[serde(flatten)]
+    pub extra: crate::lexicon::Extra,
+}
+
+/// Allow replies from actors mentioned in your post.
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreadgateMentionRule {}
+
+impl crate::atproto::Client {
+    ///app.bsky.feed.describeFeedGenerator
+    /// Get information about a feed generator, including policies and offered feed URIs. Does not require auth; implemented by Feed Generator services (not App View).
+    pub async fn app_bsky_feed_describe_feed_generator(
+        &mut self,
+    ) -> Result<DescribeFeedGeneratorOutput, crate::errors::BiskyError> {
+        self.xrpc_get("app.bsky.feed.describeFeedGenerator", None)
+            .await
+    }
+
+    ///app.bsky.feed.getActorFeeds
+    /// Get a list of feeds (feed generator records) created by the actor (in the actor's repo).
+    pub async fn app_bsky_feed_get_actor_feeds(
+        &mut self,
+        params: &GetActorFeedsParams,
+    ) -> Result<GetActorFeedsOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.feed.getActorFeeds", Some(&query))
+            .await
+    }
+
+    ///app.bsky.feed.getActorLikes
+    /// Get a list of posts liked by an actor. Requires auth, actor must be the requesting account.
+    pub async fn app_bsky_feed_get_actor_likes(
+        &mut self,
+        params: &GetActorLikesParams,
+    ) -> Result<GetActorLikesOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.feed.getActorLikes", Some(&query))
+            .await
+    }
+
+    ///app.bsky.feed.getAuthorFeed
+    /// Get a view of an actor's 'author feed' (post and reposts by the author). Does not require auth.
+    pub async fn app_bsky_feed_get_author_feed(
+        &mut self,
+        params: &GetAuthorFeedParams,
+    ) -> Result<GetAuthorFeedOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.feed.getAuthorFeed", Some(&query))
+            .await
+    }
+
+    ///app.bsky.feed.getFeed
+    /// Get a hydrated feed from an actor's selected feed generator. Implemented by App View.
+    pub async fn app_bsky_feed_get_feed(
+        &mut self,
+        params: &GetFeedParams,
+    ) -> Result<GetFeedOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.feed.getFeed", Some(&query)).await
+    }
+
+    ///app.bsky.feed.getFeedGenerator
+    /// Get information about a feed generator. Implemented by AppView.
+    pub async fn app_bsky_feed_get_feed_generator(
+        &mut self,
+        params: &GetFeedGeneratorParams,
+    ) -> Result<GetFeedGeneratorOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.feed.getFeedGenerator", Some(&query))
+            .await
+    }
+
+    ///app.bsky.feed.getFeedGenerators
+    /// Get information about a list of feed generators.
+    pub async fn app_bsky_feed_get_feed_generators(
+        &mut self,
+        params: &GetFeedGeneratorsParams,
+    ) -> Result<GetFeedGeneratorsOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.feed.getFeedGenerators", Some(&query))
+            .await
+    }
+
+    ///app.bsky.feed.getFeedSkeleton
+    /// Get a skeleton of a feed provided by a feed generator. Auth is optional, depending on provider requirements, and provides the DID of the requester. Implemented by Feed Generator Service.
+    pub async fn app_bsky_feed_get_feed_skeleton(
+        &mut self,
+        params: &GetFeedSkeletonParams,
+    ) -> Result<GetFeedSkeletonOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.feed.getFeedSkeleton", Some(&query))
+            .await
+    }
+
+    ///app.bsky.feed.getLikes
+    /// Get like records which reference a subject (by AT-URI and CID).
+    pub async fn app_bsky_feed_get_likes(
+        &mut self,
+        params: &GetLikesParams,
+    ) -> Result<GetLikesOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.feed.getLikes", Some(&query)).await
+    }
+
+    ///app.bsky.feed.getListFeed
+    /// Get a feed of recent posts from a list (posts and reposts from any actors on the list). Does not require auth.
+    pub async fn app_bsky_feed_get_list_feed(
+        &mut self,
+        params: &GetListFeedParams,
+    ) -> Result<GetListFeedOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.feed.getListFeed", Some(&query))
+            .await
+    }
+
+    ///app.bsky.feed.getPostThread
+    /// Get posts in a thread. Does not require auth, but additional metadata and filtering will be applied for authed requests.
+    pub async fn app_bsky_feed_get_post_thread(
+        &mut self,
+        params: &GetPostThreadParams,
+    ) -> Result<GetPostThreadOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.feed.getPostThread", Some(&query))
+            .await
+    }
+
+    ///app.bsky.feed.getPosts
+    /// Gets post views for a specified list of posts (by AT-URI). This is sometimes referred to as 'hydrating' a 'feed skeleton'.
+    pub async fn app_bsky_feed_get_posts(
+        &mut self,
+        params: &GetPostsParams,
+    ) -> Result<GetPostsOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.feed.getPosts", Some(&query)).await
+    }
+
+    ///app.bsky.feed.getQuotes
+    /// Get a list of quotes for a given post.
+    pub async fn app_bsky_feed_get_quotes(
+        &mut self,
+        params: &GetQuotesParams,
+    ) -> Result<GetQuotesOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.feed.getQuotes", Some(&query)).await
+    }
+
+    ///app.bsky.feed.getRepostedBy
+    /// Get a list of reposts for a given post.
+    pub async fn app_bsky_feed_get_reposted_by(
+        &mut self,
+        params: &GetRepostedByParams,
+    ) -> Result<GetRepostedByOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.feed.getRepostedBy", Some(&query))
+            .await
+    }
+
+    ///app.bsky.feed.getSuggestedFeeds
+    /// Get a list of suggested feeds (feed generators) for the requesting account.
+    pub async fn app_bsky_feed_get_suggested_feeds(
+        &mut self,
+        params: &GetSuggestedFeedsParams,
+    ) -> Result<GetSuggestedFeedsOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.feed.getSuggestedFeeds", Some(&query))
+            .await
+    }
+
+    ///app.bsky.feed.getTimeline
+    /// Get a view of the requesting account's home timeline. This is expected to be some form of reverse-chronological feed.
+    pub async fn app_bsky_feed_get_timeline(
+        &mut self,
+        params: &GetTimelineParams,
+    ) -> Result<GetTimelineOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.feed.getTimeline", Some(&query))
+            .await
+    }
+
+    ///app.bsky.feed.searchPosts
+    /// Find posts matching search criteria, returning views of those posts. Note that this API endpoint may require authentication (eg, not public) for some service providers and implementations.
+    pub async fn app_bsky_feed_search_posts(
+        &mut self,
+        params: &SearchPostsParams,
+    ) -> Result<SearchPostsOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.feed.searchPosts", Some(&query))
+            .await
+    }
+
+    ///app.bsky.feed.searchPostsV2
+    /// Find posts matching a search query or filters, returning search hits for matching post records.
+    pub async fn app_bsky_feed_search_posts_v2(
+        &mut self,
+        params: &SearchPostsV2Params,
+    ) -> Result<SearchPostsV2Output, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.feed.searchPostsV2", Some(&query))
+            .await
+    }
+
+    ///app.bsky.feed.sendInteractions
+    /// Send information about interactions with feed items back to the feed generator that served them.
+    pub async fn app_bsky_feed_send_interactions(
+        &mut self,
+        input: &SendInteractionsInput,
+    ) -> Result<SendInteractionsOutput, crate::errors::BiskyError> {
+        self.xrpc_post("app.bsky.feed.sendInteractions", input)
+            .await
+    }
+}
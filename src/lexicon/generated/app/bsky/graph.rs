@@ -0,0 +1,1137 @@
+//! app.bsky.graph
+// @generated by `cargo xtask codegen`, do not edit
+
+/// Record declaring a 'block' relationship against another account. NOTE: blocks are public in Bluesky; see blog posts for details.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Block {
+    pub created_at: crate::lexicon::Datetime,
+    /// DID of the account to be blocked.
+    pub subject: String,
+    #[serde(flatten)]
+    pub extra: crate::lexicon::Extra,
+}
+
+/// A list of actors used for curation purposes such as list feeds or interaction gating.
+pub const CURATELIST: &str = "app.bsky.graph.defs#curatelist";
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListItemView {
+    pub subject: crate::lexicon::app::bsky::actor::ProfileView,
+    pub uri: String,
+}
+
+pub type ListPurpose = String;
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListView {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<String>,
+    pub cid: String,
+    pub creator: crate::lexicon::app::bsky::actor::ProfileView,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_facets: Option<Vec<crate::lexicon::app::bsky::richtext::Facet>>,
+    pub indexed_at: crate::lexicon::Datetime,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::lexicon::com::atproto::label::Label>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub list_item_count: Option<usize>,
+    pub name: String,
+    pub purpose: ListPurpose,
+    pub uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<ListViewerState>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListViewBasic {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<String>,
+    pub cid: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indexed_at: Option<crate::lexicon::Datetime>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::lexicon::com::atproto::label::Label>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub list_item_count: Option<usize>,
+    pub name: String,
+    pub purpose: ListPurpose,
+    pub uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<ListViewerState>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListViewerState {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocked: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub muted: Option<bool>,
+}
+
+/// A list of actors to apply an aggregate moderation action (mute/block) on.
+pub const MODLIST: &str = "app.bsky.graph.defs#modlist";
+
+/// indicates that a handle or DID could not be resolved
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotFoundActor {
+    pub actor: String,
+    pub not_found: bool,
+}
+
+/// A list of actors used for only for reference purposes such as within a starter pack.
+pub const REFERENCELIST: &str = "app.bsky.graph.defs#referencelist";
+
+/// lists the bi-directional graph relationships between one actor (not indicated in the object), and the target actors (the DID included in the object)
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Relationship {
+    /// if the actor is blocked by this DID, contains the AT-URI of the block record
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocked_by: Option<String>,
+    /// if the actor is blocked by this DID via a block list, contains the AT-URI of the listblock record
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocked_by_list: Option<String>,
+    /// if the actor blocks this DID, this is the AT-URI of the block record
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocking: Option<String>,
+    /// if the actor blocks this DID via a block list, this is the AT-URI of the listblock record
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocking_by_list: Option<String>,
+    pub did: String,
+    /// if the actor is followed by this DID, contains the AT-URI of the follow record
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub followed_by: Option<String>,
+    /// if the actor follows this DID, this is the AT-URI of the follow record
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub following: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StarterPackView {
+    pub cid: String,
+    pub creator: crate::lexicon::app::bsky::actor::ProfileViewBasic,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feeds: Option<Vec<crate::lexicon::app::bsky::feed::GeneratorView>>,
+    pub indexed_at: crate::lexicon::Datetime,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub joined_all_time_count: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub joined_week_count: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::lexicon::com::atproto::label::Label>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub list: Option<ListViewBasic>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub list_items_sample: Option<Vec<ListItemView>>,
+    pub record: serde_json::Value,
+    pub uri: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StarterPackViewBasic {
+    pub cid: String,
+    pub creator: crate::lexicon::app::bsky::actor::ProfileViewBasic,
+    pub indexed_at: crate::lexicon::Datetime,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub joined_all_time_count: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub joined_week_count: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::lexicon::com::atproto::label::Label>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub list_item_count: Option<usize>,
+    pub record: serde_json::Value,
+    pub uri: String,
+}
+
+/// Record declaring a social 'follow' relationship of another account. Duplicate follows will be ignored by the AppView.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Follow {
+    pub created_at: crate::lexicon::Datetime,
+    pub subject: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub via: Option<crate::lexicon::com::atproto::repo::StrongRef>,
+    #[serde(flatten)]
+    pub extra: crate::lexicon::Extra,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetActorStarterPacksParams {
+    pub actor: String,
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl GetActorStarterPacksParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("actor", self.actor.to_string()));
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetActorStarterPacksOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub starter_packs: Vec<StarterPackViewBasic>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetBlocksParams {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl GetBlocksParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBlocksOutput {
+    pub blocks: Vec<crate::lexicon::app::bsky::actor::ProfileView>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetFollowersParams {
+    pub actor: String,
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl GetFollowersParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("actor", self.actor.to_string()));
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFollowersOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub followers: Vec<crate::lexicon::app::bsky::actor::ProfileView>,
+    pub subject: crate::lexicon::app::bsky::actor::ProfileView,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetFollowsParams {
+    pub actor: String,
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl GetFollowsParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("actor", self.actor.to_string()));
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFollowsOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub follows: Vec<crate::lexicon::app::bsky::actor::ProfileView>,
+    pub subject: crate::lexicon::app::bsky::actor::ProfileView,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetKnownFollowersParams {
+    pub actor: String,
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl GetKnownFollowersParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("actor", self.actor.to_string()));
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetKnownFollowersOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub followers: Vec<crate::lexicon::app::bsky::actor::ProfileView>,
+    pub subject: crate::lexicon::app::bsky::actor::ProfileView,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetListParams {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+    /// Reference (AT-URI) of the list record to hydrate.
+    pub list: String,
+}
+
+impl GetListParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query.push(("list", self.list.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetListOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub items: Vec<ListItemView>,
+    pub list: ListView,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetListBlocksParams {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl GetListBlocksParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetListBlocksOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub lists: Vec<ListView>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetListMutesParams {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl GetListMutesParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetListMutesOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub lists: Vec<ListView>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetListsParams {
+    /// The account (actor) to enumerate lists from.
+    pub actor: String,
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+    /// Optional filter by list purpose. If not specified, all supported types are returned.
+    pub purposes: Vec<String>,
+}
+
+impl GetListsParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("actor", self.actor.to_string()));
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        for value in &self.purposes {
+            query.push(("purposes", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetListsOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub lists: Vec<ListView>,
+}
+
+/// A list and an optional list item indicating membership of a target user to that list.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetListsWithMembershipListWithMembership {
+    pub list: ListView,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub list_item: Option<ListItemView>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetListsWithMembershipParams {
+    /// The account (actor) to check for membership.
+    pub actor: String,
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+    /// Optional filter by list purpose. If not specified, all supported types are returned.
+    pub purposes: Vec<String>,
+}
+
+impl GetListsWithMembershipParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("actor", self.actor.to_string()));
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        for value in &self.purposes {
+            query.push(("purposes", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetListsWithMembershipOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub lists_with_membership: Vec<GetListsWithMembershipListWithMembership>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetMutesParams {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl GetMutesParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetMutesOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub mutes: Vec<crate::lexicon::app::bsky::actor::ProfileView>,
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum GetRelationshipsOutputRelationship {
+        Relationship(Box<Relationship>) = "app.bsky.graph.defs#relationship",
+        NotFoundActor(Box<NotFoundActor>) = "app.bsky.graph.defs#notFoundActor",
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetRelationshipsParams {
+    /// Primary account requesting relationships for.
+    pub actor: String,
+    /// List of 'other' accounts to be related back to the primary.
+    pub others: Vec<String>,
+}
+
+impl GetRelationshipsParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("actor", self.actor.to_string()));
+        for value in &self.others {
+            query.push(("others", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRelationshipsOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
+    pub relationships: Vec<GetRelationshipsOutputRelationship>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetStarterPackParams {
+    /// Reference (AT-URI) of the starter pack record.
+    pub starter_pack: String,
+}
+
+impl GetStarterPackParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("starterPack", self.starter_pack.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetStarterPackOutput {
+    pub starter_pack: StarterPackView,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetStarterPacksParams {
+    pub uris: Vec<String>,
+}
+
+impl GetStarterPacksParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        for value in &self.uris {
+            query.push(("uris", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetStarterPacksOutput {
+    pub starter_packs: Vec<StarterPackViewBasic>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetStarterPacksWithMembershipParams {
+    /// The account (actor) to check for membership.
+    pub actor: String,
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl GetStarterPacksWithMembershipParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("actor", self.actor.to_string()));
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetStarterPacksWithMembershipOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub starter_packs_with_membership: Vec<GetStarterPacksWithMembershipStarterPackWithMembership>,
+}
+
+/// A starter pack and an optional list item indicating membership of a target user to that starter pack.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetStarterPacksWithMembershipStarterPackWithMembership {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub list_item: Option<ListItemView>,
+    pub starter_pack: StarterPackView,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetSuggestedFollowsByActorParams {
+    pub actor: String,
+}
+
+impl GetSuggestedFollowsByActorParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("actor", self.actor.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSuggestedFollowsByActorOutput {
+    /// DEPRECATED, unused. Previously: if true, response has fallen-back to generic results, and is not scoped using relativeToDid
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_fallback: Option<bool>,
+    /// DEPRECATED: use recIdStr instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rec_id: Option<i64>,
+    /// Snowflake for this recommendation, use when submitting recommendation events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rec_id_str: Option<String>,
+    pub suggestions: Vec<crate::lexicon::app::bsky::actor::ProfileView>,
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum ListLabels {
+        SelfLabels(Box<crate::lexicon::com::atproto::label::SelfLabels>) = "com.atproto.label.defs#selfLabels",
+    }
+}
+
+/// Record representing a list of accounts (actors). Scope includes both moderation-oriented lists and curration-oriented lists.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct List {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<crate::lexicon::Blob>,
+    pub created_at: crate::lexicon::Datetime,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_facets: Option<Vec<crate::lexicon::app::bsky::richtext::Facet>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<ListLabels>,
+    /// Display name for list; can not be empty.
+    pub name: String,
+    /// Defines the purpose of the list (aka, moderation-oriented or curration-oriented)
+    pub purpose: ListPurpose,
+    #[serde(flatten)]
+    pub extra: crate::lexicon::Extra,
+}
+
+/// Record representing a block relationship against an entire an entire list of accounts (actors).
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Listblock {
+    pub created_at: crate::lexicon::Datetime,
+    /// Reference (AT-URI) to the mod list record.
+    pub subject: String,
+    #[serde(flatten)]
+    pub extra: crate::lexicon::Extra,
+}
+
+/// Record representing an account's inclusion on a specific list. The AppView will ignore duplicate listitem records.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Listitem {
+    pub created_at: crate::lexicon::Datetime,
+    /// Reference (AT-URI) to the list record (app.bsky.graph.list).
+    pub list: String,
+    /// The account which is included on the list.
+    pub subject: String,
+    #[serde(flatten)]
+    pub extra: crate::lexicon::Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MuteActorInput {
+    pub actor: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MuteActorListInput {
+    pub list: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MuteThreadInput {
+    pub root: String,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct SearchStarterPacksParams {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+    /// Search query string. Syntax, phrase, boolean, and faceting is unspecified, but Lucene query syntax is recommended.
+    pub q: String,
+}
+
+impl SearchStarterPacksParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query.push(("q", self.q.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchStarterPacksOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub starter_packs: Vec<StarterPackViewBasic>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StarterpackFeedItem {
+    pub uri: String,
+}
+
+/// Record defining a starter pack of actors and feeds for new users.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Starterpack {
+    pub created_at: crate::lexicon::Datetime,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_facets: Option<Vec<crate::lexicon::app::bsky::richtext::Facet>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feeds: Option<Vec<StarterpackFeedItem>>,
+    /// Reference (AT-URI) to the list record.
+    pub list: String,
+    /// Display name for starter pack; can not be empty.
+    pub name: String,
+    #[serde(flatten)]
+    pub extra: crate::lexicon::Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnmuteActorInput {
+    pub actor: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnmuteActorListInput {
+    pub list: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnmuteThreadInput {
+    pub root: String,
+}
+
+/// Record declaring a verification relationship between two accounts. Verifications are only considered valid by an app if issued by an account the app considers trusted.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Verification {
+    /// Date of when the verification was created.
+    pub created_at: crate::lexicon::Datetime,
+    /// Display name of the subject the verification applies to at the moment of verifying, which might not be the same at the time of viewing. The verification is only valid if the current displayName matches the one at the time of verifying.
+    pub display_name: String,
+    /// Handle of the subject the verification applies to at the moment of verifying, which might not be the same at the time of viewing. The verification is only valid if the current handle matches the one at the time of verifying.
+    pub handle: String,
+    /// DID of the subject the verification applies to.
+    pub subject: String,
+    #[serde(flatten)]
+    pub extra: crate::lexicon::Extra,
+}
+
+impl crate::atproto::Client {
+    ///app.bsky.graph.getActorStarterPacks
+    /// Get a list of starter packs created by the actor.
+    pub async fn app_bsky_graph_get_actor_starter_packs(
+        &mut self,
+        params: &GetActorStarterPacksParams,
+    ) -> Result<GetActorStarterPacksOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.graph.getActorStarterPacks", Some(&query))
+            .await
+    }
+
+    ///app.bsky.graph.getBlocks
+    /// Enumerates which accounts the requesting account is currently blocking. Requires auth.
+    pub async fn app_bsky_graph_get_blocks(
+        &mut self,
+        params: &GetBlocksParams,
+    ) -> Result<GetBlocksOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.graph.getBlocks", Some(&query))
+            .await
+    }
+
+    ///app.bsky.graph.getFollowers
+    /// Enumerates accounts which follow a specified account (actor).
+    pub async fn app_bsky_graph_get_followers(
+        &mut self,
+        params: &GetFollowersParams,
+    ) -> Result<GetFollowersOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.graph.getFollowers", Some(&query))
+            .await
+    }
+
+    ///app.bsky.graph.getFollows
+    /// Enumerates accounts which a specified account (actor) follows.
+    pub async fn app_bsky_graph_get_follows(
+        &mut self,
+        params: &GetFollowsParams,
+    ) -> Result<GetFollowsOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.graph.getFollows", Some(&query))
+            .await
+    }
+
+    ///app.bsky.graph.getKnownFollowers
+    /// Enumerates accounts which follow a specified account (actor) and are followed by the viewer.
+    pub async fn app_bsky_graph_get_known_followers(
+        &mut self,
+        params: &GetKnownFollowersParams,
+    ) -> Result<GetKnownFollowersOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.graph.getKnownFollowers", Some(&query))
+            .await
+    }
+
+    ///app.bsky.graph.getList
+    /// Gets a 'view' (with additional context) of a specified list.
+    pub async fn app_bsky_graph_get_list(
+        &mut self,
+        params: &GetListParams,
+    ) -> Result<GetListOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.graph.getList", Some(&query)).await
+    }
+
+    ///app.bsky.graph.getListBlocks
+    /// Get mod lists that the requesting account (actor) is blocking. Requires auth.
+    pub async fn app_bsky_graph_get_list_blocks(
+        &mut self,
+        params: &GetListBlocksParams,
+    ) -> Result<GetListBlocksOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.graph.getListBlocks", Some(&query))
+            .await
+    }
+
+    ///app.bsky.graph.getListMutes
+    /// Enumerates mod lists that the requesting account (actor) currently has muted. Requires auth.
+    pub async fn app_bsky_graph_get_list_mutes(
+        &mut self,
+        params: &GetListMutesParams,
+    ) -> Result<GetListMutesOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.graph.getListMutes", Some(&query))
+            .await
+    }
+
+    ///app.bsky.graph.getLists
+    /// Enumerates the lists created by a specified account (actor).
+    pub async fn app_bsky_graph_get_lists(
+        &mut self,
+        params: &GetListsParams,
+    ) -> Result<GetListsOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.graph.getLists", Some(&query)).await
+    }
+
+    ///app.bsky.graph.getListsWithMembership
+    /// Enumerates the lists created by the session user, and includes membership information about `actor` in those lists. Only supports curation and moderation lists (no reference lists, used in starter packs). Requires auth.
+    pub async fn app_bsky_graph_get_lists_with_membership(
+        &mut self,
+        params: &GetListsWithMembershipParams,
+    ) -> Result<GetListsWithMembershipOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.graph.getListsWithMembership", Some(&query))
+            .await
+    }
+
+    ///app.bsky.graph.getMutes
+    /// Enumerates accounts that the requesting account (actor) currently has muted. Requires auth.
+    pub async fn app_bsky_graph_get_mutes(
+        &mut self,
+        params: &GetMutesParams,
+    ) -> Result<GetMutesOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.graph.getMutes", Some(&query)).await
+    }
+
+    ///app.bsky.graph.getRelationships
+    /// Enumerates public relationships between one account, and a list of other accounts. Does not require auth.
+    pub async fn app_bsky_graph_get_relationships(
+        &mut self,
+        params: &GetRelationshipsParams,
+    ) -> Result<GetRelationshipsOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.graph.getRelationships", Some(&query))
+            .await
+    }
+
+    ///app.bsky.graph.getStarterPack
+    /// Gets a view of a starter pack.
+    pub async fn app_bsky_graph_get_starter_pack(
+        &mut self,
+        params: &GetStarterPackParams,
+    ) -> Result<GetStarterPackOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.graph.getStarterPack", Some(&query))
+            .await
+    }
+
+    ///app.bsky.graph.getStarterPacks
+    /// Get views for a list of starter packs.
+    pub async fn app_bsky_graph_get_starter_packs(
+        &mut self,
+        params: &GetStarterPacksParams,
+    ) -> Result<GetStarterPacksOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.graph.getStarterPacks", Some(&query))
+            .await
+    }
+
+    ///app.bsky.graph.getStarterPacksWithMembership
+    /// Enumerates the starter packs created by the session user, and includes membership information about `actor` in those starter packs. Requires auth.
+    pub async fn app_bsky_graph_get_starter_packs_with_membership(
+        &mut self,
+        params: &GetStarterPacksWithMembershipParams,
+    ) -> Result<GetStarterPacksWithMembershipOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.graph.getStarterPacksWithMembership", Some(&query))
+            .await
+    }
+
+    ///app.bsky.graph.getSuggestedFollowsByActor
+    /// Enumerates follows similar to a given account (actor). Expected use is to recommend additional accounts immediately after following one account.
+    pub async fn app_bsky_graph_get_suggested_follows_by_actor(
+        &mut self,
+        params: &GetSuggestedFollowsByActorParams,
+    ) -> Result<GetSuggestedFollowsByActorOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.graph.getSuggestedFollowsByActor", Some(&query))
+            .await
+    }
+
+    ///app.bsky.graph.muteActor
+    /// Creates a mute relationship for the specified account. Mutes are private in Bluesky. Requires auth.
+    pub async fn app_bsky_graph_mute_actor(
+        &mut self,
+        input: &MuteActorInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("app.bsky.graph.muteActor", input)
+            .await
+    }
+
+    ///app.bsky.graph.muteActorList
+    /// Creates a mute relationship for the specified list of accounts. Mutes are private in Bluesky. Requires auth.
+    pub async fn app_bsky_graph_mute_actor_list(
+        &mut self,
+        input: &MuteActorListInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("app.bsky.graph.muteActorList", input)
+            .await
+    }
+
+    ///app.bsky.graph.muteThread
+    /// Mutes a thread preventing notifications from the thread and any of its children. Mutes are private in Bluesky. Requires auth.
+    pub async fn app_bsky_graph_mute_thread(
+        &mut self,
+        input: &MuteThreadInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("app.bsky.graph.muteThread", input)
+            .await
+    }
+
+    ///app.bsky.graph.searchStarterPacks
+    /// Find starter packs matching search criteria. Does not require auth.
+    pub async fn app_bsky_graph_search_starter_packs(
+        &mut self,
+        params: &SearchStarterPacksParams,
+    ) -> Result<SearchStarterPacksOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.graph.searchStarterPacks", Some(&query))
+            .await
+    }
+
+    ///app.bsky.graph.unmuteActor
+    /// Unmutes the specified account. Requires auth.
+    pub async fn app_bsky_graph_unmute_actor(
+        &mut self,
+        input: &UnmuteActorInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("app.bsky.graph.unmuteActor", input)
+            .await
+    }
+
+    ///app.bsky.graph.unmuteActorList
+    /// Unmutes the specified list of accounts. Requires auth.
+    pub async fn app_bsky_graph_unmute_actor_list(
+        &mut self,
+        input: &UnmuteActorListInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("app.bsky.graph.unmuteActorList", input)
+            .await
+    }
+
+    ///app.bsky.graph.unmuteThread
+    /// Unmutes the specified thread. Requires auth.
+    pub async fn app_bsky_graph_unmute_thread(
+        &mut self,
+        input: &UnmuteThreadInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("app.bsky.graph.unmuteThread", input)
+            .await
+    }
+}
@@ -0,0 +1,139 @@
+//! app.bsky.labeler
+// @generated by `cargo xtask codegen`, do not edit
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelerPolicies {
+    /// Label values created by this labeler and scoped exclusively to it. Labels defined here will override global label definitions for this labeler.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_value_definitions:
+        Option<Vec<crate::lexicon::com::atproto::label::LabelValueDefinition>>,
+    /// The label values which this labeler publishes. May include global or custom labels.
+    pub label_values: Vec<crate::lexicon::com::atproto::label::LabelValue>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelerView {
+    pub cid: String,
+    pub creator: crate::lexicon::app::bsky::actor::ProfileView,
+    pub indexed_at: crate::lexicon::Datetime,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::lexicon::com::atproto::label::Label>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub like_count: Option<usize>,
+    pub uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<LabelerViewerState>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelerViewDetailed {
+    pub cid: String,
+    pub creator: crate::lexicon::app::bsky::actor::ProfileView,
+    pub indexed_at: crate::lexicon::Datetime,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::lexicon::com::atproto::label::Label>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub like_count: Option<usize>,
+    pub policies: LabelerPolicies,
+    /// The set of report reason 'codes' which are in-scope for this service to review and action. These usually align to policy categories. If not defined (distinct from empty array), all reason types are allowed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason_types: Option<Vec<crate::lexicon::com::atproto::moderation::ReasonType>>,
+    /// Set of record types (collection NSIDs) which can be reported to this service. If not defined (distinct from empty array), default is any record type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject_collections: Option<Vec<String>>,
+    /// The set of subject types (account, record, etc) this service accepts reports on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject_types: Option<Vec<crate::lexicon::com::atproto::moderation::SubjectType>>,
+    pub uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<LabelerViewerState>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelerViewerState {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub like: Option<String>,
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum GetServicesOutputView {
+        LabelerView(Box<LabelerView>) = "app.bsky.labeler.defs#labelerView",
+        LabelerViewDetailed(Box<LabelerViewDetailed>) = "app.bsky.labeler.defs#labelerViewDetailed",
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetServicesParams {
+    pub detailed: Option<bool>,
+    pub dids: Vec<String>,
+}
+
+impl GetServicesParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.detailed {
+            query.push(("detailed", value.to_string()));
+        }
+        for value in &self.dids {
+            query.push(("dids", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetServicesOutput {
+    pub views: Vec<GetServicesOutputView>,
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum ServiceLabels {
+        SelfLabels(Box<crate::lexicon::com::atproto::label::SelfLabels>) = "com.atproto.label.defs#selfLabels",
+    }
+}
+
+/// A declaration of the existence of labeler service.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Service {
+    pub created_at: crate::lexicon::Datetime,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<ServiceLabels>,
+    pub policies: LabelerPolicies,
+    /// The set of report reason 'codes' which are in-scope for this service to review and action. These usually align to policy categories. If not defined (distinct from empty array), all reason types are allowed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason_types: Option<Vec<crate::lexicon::com::atproto::moderation::ReasonType>>,
+    /// Set of record types (collection NSIDs) which can be reported to this service. If not defined (distinct from empty array), default is any record type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject_collections: Option<Vec<String>>,
+    /// The set of subject types (account, record, etc) this service accepts reports on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject_types: Option<Vec<crate::lexicon::com::atproto::moderation::SubjectType>>,
+    #[serde(flatten)]
+    pub extra: crate::lexicon::Extra,
+}
+
+impl crate::atproto::Client {
+    ///app.bsky.labeler.getServices
+    /// Get information about a list of labeler services.
+    pub async fn app_bsky_labeler_get_services(
+        &mut self,
+        params: &GetServicesParams,
+    ) -> Result<GetServicesOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.labeler.getServices", Some(&query))
+            .await
+    }
+}
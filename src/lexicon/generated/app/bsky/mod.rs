@@ -0,0 +1,15 @@
+// @generated by `cargo xtask codegen`, do not edit
+
+pub mod actor;
+pub mod ageassurance;
+pub mod bookmark;
+pub mod contact;
+pub mod draft;
+pub mod embed;
+pub mod feed;
+pub mod graph;
+pub mod labeler;
+pub mod notification;
+pub mod richtext;
+pub mod unspecced;
+pub mod video;
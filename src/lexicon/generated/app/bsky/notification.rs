@@ -0,0 +1,402 @@
+//! app.bsky.notification
+// @generated by `cargo xtask codegen`, do not edit
+
+/// A declaration of the user's choices related to notifications that can be produced by them.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Declaration {
+    /// A declaration of the user's preference for allowing activity subscriptions from other users. Absence of a record implies 'followers'.
+    pub allow_subscriptions: String,
+    #[serde(flatten)]
+    pub extra: crate::lexicon::Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivitySubscription {
+    pub post: bool,
+    pub reply: bool,
+}
+
+/// Deprecated: use chat.bsky.notification preferences instead. This will only return a default value.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatPreference {
+    pub include: String,
+    pub push: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FilterablePreference {
+    pub include: String,
+    pub list: bool,
+    pub push: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Preference {
+    pub list: bool,
+    pub push: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Preferences {
+    /// Deprecated: use chat.bsky.notification preferences instead. This will only return a default value.
+    pub chat: ChatPreference,
+    pub follow: FilterablePreference,
+    pub like: FilterablePreference,
+    pub like_via_repost: FilterablePreference,
+    pub mention: FilterablePreference,
+    pub quote: FilterablePreference,
+    pub reply: FilterablePreference,
+    pub repost: FilterablePreference,
+    pub repost_via_repost: FilterablePreference,
+    pub starterpack_joined: Preference,
+    pub subscribed_post: Preference,
+    pub unverified: Preference,
+    pub verified: Preference,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordDeleted {}
+
+/// Object used to store activity subscription data in stash.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubjectActivitySubscription {
+    pub activity_subscription: ActivitySubscription,
+    pub subject: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPreferencesOutput {
+    pub preferences: Preferences,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetUnreadCountParams {
+    pub priority: Option<bool>,
+    pub seen_at: Option<String>,
+}
+
+impl GetUnreadCountParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.priority {
+            query.push(("priority", value.to_string()));
+        }
+        if let Some(value) = &self.seen_at {
+            query.push(("seenAt", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetUnreadCountOutput {
+    pub count: i64,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ListActivitySubscriptionsParams {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl ListActivitySubscriptionsParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListActivitySubscriptionsOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub subscriptions: Vec<crate::lexicon::app::bsky::actor::ProfileView>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ListNotificationsParams {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+    pub priority: Option<bool>,
+    /// Notification reasons to include in response.
+    pub reasons: Vec<String>,
+    pub seen_at: Option<String>,
+}
+
+impl ListNotificationsParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        if let Some(value) = &self.priority {
+            query.push(("priority", value.to_string()));
+        }
+        for value in &self.reasons {
+            query.push(("reasons", value.to_string()));
+        }
+        if let Some(value) = &self.seen_at {
+            query.push(("seenAt", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListNotificationsOutput<T = serde_json::Value> {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub notifications: Vec<ListNotificationsNotification<T>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seen_at: Option<crate::lexicon::Datetime>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListNotificationsNotification<T = serde_json::Value> {
+    pub author: crate::lexicon::app::bsky::actor::ProfileView,
+    pub cid: String,
+    pub indexed_at: crate::lexicon::Datetime,
+    pub is_read: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::lexicon::com::atproto::label::Label>>,
+    /// The reason why this notification was delivered - e.g. your post was liked, or you received a new follower.
+    pub reason: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason_subject: Option<String>,
+    pub record: T,
+    pub uri: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PutActivitySubscriptionInput {
+    pub activity_subscription: ActivitySubscription,
+    pub subject: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PutActivitySubscriptionOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub activity_subscription: Option<ActivitySubscription>,
+    pub subject: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PutPreferencesInput {
+    pub priority: bool,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PutPreferencesV2Input {
+    /// Deprecated: use chat.bsky.notification preferences instead. Setting this won't stick and the default values will be returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chat: Option<ChatPreference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub follow: Option<FilterablePreference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub like: Option<FilterablePreference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub like_via_repost: Option<FilterablePreference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mention: Option<FilterablePreference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quote: Option<FilterablePreference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply: Option<FilterablePreference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repost: Option<FilterablePreference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repost_via_repost: Option<FilterablePreference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starterpack_joined: Option<Preference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscribed_post: Option<Preference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unverified: Option<Preference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verified: Option<Preference>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PutPreferencesV2Output {
+    pub preferences: Preferences,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisterPushInput {
+    /// Set to true when the actor is age restricted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub age_restricted: Option<bool>,
+    pub app_id: String,
+    pub platform: String,
+    pub service_did: String,
+    pub token: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnregisterPushInput {
+    pub app_id: String,
+    pub platform: String,
+    pub service_did: String,
+    pub token: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateSeenInput {
+    pub seen_at: crate::lexicon::Datetime,
+}
+
+impl crate::atproto::Client {
+    ///app.bsky.notification.getPreferences
+    /// Get notification-related preferences for an account. Requires auth.
+    pub async fn app_bsky_notification_get_preferences(
+        &mut self,
+    ) -> Result<GetPreferencesOutput, crate::errors::BiskyError> {
+        self.xrpc_get("app.bsky.notification.getPreferences", None)
+            .await
+    }
+
+    ///app.bsky.notification.getUnreadCount
+    /// Count the number of unread notifications for the requesting account. Requires auth.
+    pub async fn app_bsky_notification_get_unread_count(
+        &mut self,
+        params: &GetUnreadCountParams,
+    ) -> Result<GetUnreadCountOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.notification.getUnreadCount", Some(&query))
+            .await
+    }
+
+    ///app.bsky.notification.listActivitySubscriptions
+    /// Enumerate all accounts to which the requesting account is subscribed to receive notifications for. Requires auth.
+    pub async fn app_bsky_notification_list_activity_subscriptions(
+        &mut self,
+        params: &ListActivitySubscriptionsParams,
+    ) -> Result<ListActivitySubscriptionsOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get(
+            "app.bsky.notification.listActivitySubscriptions",
+            Some(&query),
+        )
+        .await
+    }
+
+    ///app.bsky.notification.listNotifications
+    /// Enumerate notifications for the requesting account. Requires auth.
+    pub async fn app_bsky_notification_list_notifications<
+        T: serde::de::DeserializeOwned + std::fmt::Debug,
+    >(
+        &mut self,
+        params: &ListNotificationsParams,
+    ) -> Result<ListNotificationsOutput<T>, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.notification.listNotifications", Some(&query))
+            .await
+    }
+
+    ///app.bsky.notification.putActivitySubscription
+    /// Puts an activity subscription entry. The key should be omitted for creation and provided for updates. Requires auth.
+    pub async fn app_bsky_notification_put_activity_subscription(
+        &mut self,
+        input: &PutActivitySubscriptionInput,
+    ) -> Result<PutActivitySubscriptionOutput, crate::errors::BiskyError> {
+        self.xrpc_post("app.bsky.notification.putActivitySubscription", input)
+            .await
+    }
+
+    ///app.bsky.notification.putPreferences
+    /// Set notification-related preferences for an account. Requires auth.
+    pub async fn app_bsky_notification_put_preferences(
+        &mut self,
+        input: &PutPreferencesInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("app.bsky.notification.putPreferences", input)
+            .await
+    }
+
+    ///app.bsky.notification.putPreferencesV2
+    /// Set notification-related preferences for an account. Requires auth.
+    pub async fn app_bsky_notification_put_preferences_v2(
+        &mut self,
+        input: &PutPreferencesV2Input,
+    ) -> Result<PutPreferencesV2Output, crate::errors::BiskyError> {
+        self.xrpc_post("app.bsky.notification.putPreferencesV2", input)
+            .await
+    }
+
+    ///app.bsky.notification.registerPush
+    /// Register to receive push notifications, via a specified service, for the requesting account. Requires auth.
+    pub async fn app_bsky_notification_register_push(
+        &mut self,
+        input: &RegisterPushInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("app.bsky.notification.registerPush", input)
+            .await
+    }
+
+    ///app.bsky.notification.unregisterPush
+    /// The inverse of registerPush - inform a specified service that push notifications should no longer be sent to the given token for the requesting account. Requires auth.
+    pub async fn app_bsky_notification_unregister_push(
+        &mut self,
+        input: &UnregisterPushInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("app.bsky.notification.unregisterPush", input)
+            .await
+    }
+
+    ///app.bsky.notification.updateSeen
+    /// Notify server that the requesting account has seen notifications. Requires auth.
+    pub async fn app_bsky_notification_update_seen(
+        &mut self,
+        input: &UpdateSeenInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("app.bsky.notification.updateSeen", input)
+            .await
+    }
+}
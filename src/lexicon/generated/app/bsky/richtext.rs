@@ -0,0 +1,48 @@
+//! app.bsky.richtext
+// @generated by `cargo xtask codegen`, do not edit
+
+/// Specifies the sub-string range a facet feature applies to. Start index is inclusive, end index is exclusive. Indices are zero-indexed, counting bytes of the UTF-8 encoded text. NOTE: some languages, like Javascript, use UTF-16 or Unicode codepoints for string slice indexing; in these languages, convert to byte arrays before working with facets.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FacetByteSlice {
+    pub byte_end: usize,
+    pub byte_start: usize,
+}
+
+/// Facet feature for a URL. The text URL may have been simplified or truncated, but the facet reference should be a complete URL.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FacetLink {
+    pub uri: String,
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum FacetFeature {
+        Mention(Box<FacetMention>) = "app.bsky.richtext.facet#mention",
+        Link(Box<FacetLink>) = "app.bsky.richtext.facet#link",
+        Tag(Box<FacetTag>) = "app.bsky.richtext.facet#tag",
+    }
+}
+
+/// Annotation of a sub-string within rich text.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Facet {
+    pub features: Vec<FacetFeature>,
+    pub index: FacetByteSlice,
+}
+
+/// Facet feature for mention of another account. The text is usually a handle, including a '@' prefix, but the facet reference is a DID.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FacetMention {
+    pub did: String,
+}
+
+/// Facet feature for a hashtag. The text usually includes a '#' prefix, but the facet reference should not (except in the case of 'double hash tags').
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FacetTag {
+    pub tag: String,
+}
@@ -0,0 +1,1541 @@
+//! app.bsky.unspecced
+// @generated by `cargo xtask codegen`, do not edit
+
+/// Object used to store age assurance data in stash.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgeAssuranceEvent {
+    /// The unique identifier for this instance of the age assurance flow, in UUID format.
+    pub attempt_id: String,
+    /// The IP address used when completing the AA flow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub complete_ip: Option<String>,
+    /// The user agent used when completing the AA flow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub complete_ua: Option<String>,
+    /// The date and time of this write operation.
+    pub created_at: crate::lexicon::Datetime,
+    /// The email used for AA.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// The IP address used when initiating the AA flow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub init_ip: Option<String>,
+    /// The user agent used when initiating the AA flow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub init_ua: Option<String>,
+    /// The status of the age assurance process.
+    pub status: String,
+}
+
+/// The computed state of the age assurance process, returned to the user in question on certain authenticated requests.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgeAssuranceState {
+    /// The timestamp when this state was last updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_initiated_at: Option<crate::lexicon::Datetime>,
+    /// The status of the age assurance process.
+    pub status: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkeletonSearchActor {
+    pub did: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkeletonSearchPost {
+    pub uri: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkeletonSearchStarterPack {
+    pub uri: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkeletonTrend {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    pub dids: Vec<String>,
+    pub display_name: String,
+    pub link: String,
+    pub post_count: i64,
+    pub started_at: crate::lexicon::Datetime,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    pub topic: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreadItemBlocked {
+    pub author: crate::lexicon::app::bsky::feed::BlockedAuthor,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreadItemNoUnauthenticated {}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreadItemNotFound {}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreadItemPost {
+    /// The threadgate created by the author indicates this post as a reply to be hidden for everyone consuming the thread.
+    pub hidden_by_threadgate: bool,
+    /// This post has more parents that were not present in the response. This is just a boolean, without the number of parents.
+    pub more_parents: bool,
+    /// This post has more replies that were not present in the response. This is a numeric value, which is best-effort and might not be accurate.
+    pub more_replies: i64,
+    /// This is by an account muted by the viewer requesting it.
+    pub muted_by_viewer: bool,
+    /// This post is part of a contiguous thread by the OP from the thread root. Many different OP threads can happen in the same thread.
+    pub op_thread: bool,
+    pub post: crate::lexicon::app::bsky::feed::PostView,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrendView {
+    pub actors: Vec<crate::lexicon::app::bsky::actor::ProfileViewBasic>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    pub display_name: String,
+    pub link: String,
+    pub post_count: i64,
+    pub started_at: crate::lexicon::Datetime,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    pub topic: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrendingTopic {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    pub link: String,
+    pub topic: String,
+}
+
+pub type GetAgeAssuranceStateOutput = AgeAssuranceState;
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetConfigLiveNowConfig {
+    pub did: String,
+    pub domains: Vec<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetConfigOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub check_email_confirmed: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub live_now: Option<Vec<GetConfigLiveNowConfig>>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetOnboardingSuggestedStarterPacksParams {
+    pub limit: Option<i64>,
+}
+
+impl GetOnboardingSuggestedStarterPacksParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetOnboardingSuggestedStarterPacksOutput {
+    pub starter_packs: Vec<crate::lexicon::app::bsky::graph::StarterPackView>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetOnboardingSuggestedStarterPacksSkeletonParams {
+    pub limit: Option<i64>,
+    /// DID of the account making the request (not included for public/unauthenticated queries).
+    pub viewer: Option<String>,
+}
+
+impl GetOnboardingSuggestedStarterPacksSkeletonParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        if let Some(value) = &self.viewer {
+            query.push(("viewer", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetOnboardingSuggestedStarterPacksSkeletonOutput {
+    pub starter_packs: Vec<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetOnboardingSuggestedUsersSkeletonParams {
+    /// Category of users to get suggestions for.
+    pub category: Option<String>,
+    pub limit: Option<i64>,
+    /// DID of the account making the request (not included for public/unauthenticated queries).
+    pub viewer: Option<String>,
+}
+
+impl GetOnboardingSuggestedUsersSkeletonParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.category {
+            query.push(("category", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        if let Some(value) = &self.viewer {
+            query.push(("viewer", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetOnboardingSuggestedUsersSkeletonOutput {
+    pub dids: Vec<String>,
+    /// DEPRECATED: use recIdStr instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rec_id: Option<String>,
+    /// Snowflake for this recommendation, use when submitting recommendation events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rec_id_str: Option<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetPopularFeedGeneratorsParams {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+    pub query: Option<String>,
+}
+
+impl GetPopularFeedGeneratorsParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        if let Some(value) = &self.query {
+            query.push(("query", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPopularFeedGeneratorsOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub feeds: Vec<crate::lexicon::app::bsky::feed::GeneratorView>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetPostThreadOtherV2Params {
+    /// Reference (AT-URI) to post record. This is the anchor post.
+    pub anchor: String,
+}
+
+impl GetPostThreadOtherV2Params {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("anchor", self.anchor.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPostThreadOtherV2Output {
+    /// A flat list of other thread items. The depth of each item is indicated by the depth property inside the item.
+    pub thread: Vec<GetPostThreadOtherV2ThreadItem>,
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum GetPostThreadOtherV2ThreadItemValue {
+        ThreadItemPost(Box<ThreadItemPost>) = "app.bsky.unspecced.defs#threadItemPost",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPostThreadOtherV2ThreadItem {
+    /// The nesting level of this item in the thread. Depth 0 means the anchor item. Items above have negative depths, items below have positive depths.
+    pub depth: i64,
+    pub uri: String,
+    pub value: GetPostThreadOtherV2ThreadItemValue,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetPostThreadV2Params {
+    /// Whether to include parents above the anchor.
+    pub above: Option<bool>,
+    /// Reference (AT-URI) to post record. This is the anchor post, and the thread will be built around it. It can be any post in the tree, not necessarily a root post.
+    pub anchor: String,
+    /// How many levels of replies to include below the anchor.
+    pub below: Option<i64>,
+    /// Maximum of replies to include at each level of the thread, except for the direct replies to the anchor, which are (NOTE: currently, during unspecced phase) all returned (NOTE: later they might be paginated).
+    pub branching_factor: Option<i64>,
+    /// Sorting for the thread replies.
+    pub sort: Option<String>,
+}
+
+impl GetPostThreadV2Params {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.above {
+            query.push(("above", value.to_string()));
+        }
+        query.push(("anchor", self.anchor.to_string()));
+        if let Some(value) = &self.below {
+            query.push(("below", value.to_string()));
+        }
+        if let Some(value) = &self.branching_factor {
+            query.push(("branchingFactor", value.to_string()));
+        }
+        if let Some(value) = &self.sort {
+            query.push(("sort", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPostThreadV2Output {
+    /// Whether this thread has additional replies. If true, a call can be made to the `getPostThreadOtherV2` endpoint to retrieve them.
+    pub has_other_replies: bool,
+    /// A flat list of thread items. The depth of each item is indicated by the depth property inside the item.
+    pub thread: Vec<GetPostThreadV2ThreadItem>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threadgate: Option<crate::lexicon::app::bsky::feed::ThreadgateView>,
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum GetPostThreadV2ThreadItemValue {
+        ThreadItemPost(Box<ThreadItemPost>) = "app.bsky.unspecced.defs#threadItemPost",
+        ThreadItemNoUnauthenticated(Box<ThreadItemNoUnauthenticated>) = "app.bsky.unspecced.defs#threadItemNoUnauthenticated",
+        ThreadItemNotFound(Box<ThreadItemNotFound>) = "app.bsky.unspecced.defs#threadItemNotFound",
+        ThreadItemBlocked(Box<ThreadItemBlocked>) = "app.bsky.unspecced.defs#threadItemBlocked",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPostThreadV2ThreadItem {
+    /// The nesting level of this item in the thread. Depth 0 means the anchor item. Items above have negative depths, items below have positive depths.
+    pub depth: i64,
+    pub uri: String,
+    pub value: GetPostThreadV2ThreadItemValue,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetSuggestedFeedsParams {
+    pub limit: Option<i64>,
+}
+
+impl GetSuggestedFeedsParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSuggestedFeedsOutput {
+    pub feeds: Vec<crate::lexicon::app::bsky::feed::GeneratorView>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetSuggestedFeedsSkeletonParams {
+    pub limit: Option<i64>,
+    /// DID of the account making the request (not included for public/unauthenticated queries).
+    pub viewer: Option<String>,
+}
+
+impl GetSuggestedFeedsSkeletonParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        if let Some(value) = &self.viewer {
+            query.push(("viewer", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSuggestedFeedsSkeletonOutput {
+    pub feeds: Vec<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetSuggestedOnboardingUsersParams {
+    /// Category of users to get suggestions for.
+    pub category: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl GetSuggestedOnboardingUsersParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.category {
+            query.push(("category", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSuggestedOnboardingUsersOutput {
+    pub actors: Vec<crate::lexicon::app::bsky::actor::ProfileView>,
+    /// DEPRECATED: use recIdStr instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rec_id: Option<String>,
+    /// Snowflake for this recommendation, use when submitting recommendation events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rec_id_str: Option<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetSuggestedStarterPacksParams {
+    pub limit: Option<i64>,
+}
+
+impl GetSuggestedStarterPacksParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSuggestedStarterPacksOutput {
+    pub starter_packs: Vec<crate::lexicon::app::bsky::graph::StarterPackView>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetSuggestedStarterPacksSkeletonParams {
+    pub limit: Option<i64>,
+    /// DID of the account making the request (not included for public/unauthenticated queries).
+    pub viewer: Option<String>,
+}
+
+impl GetSuggestedStarterPacksSkeletonParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        if let Some(value) = &self.viewer {
+            query.push(("viewer", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSuggestedStarterPacksSkeletonOutput {
+    pub starter_packs: Vec<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetSuggestedUsersParams {
+    /// Category of users to get suggestions for.
+    pub category: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl GetSuggestedUsersParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.category {
+            query.push(("category", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSuggestedUsersOutput {
+    pub actors: Vec<crate::lexicon::app::bsky::actor::ProfileView>,
+    /// DEPRECATED: use recIdStr instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rec_id: Option<String>,
+    /// Snowflake for this recommendation, use when submitting recommendation events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rec_id_str: Option<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetSuggestedUsersForDiscoverParams {
+    pub limit: Option<i64>,
+}
+
+impl GetSuggestedUsersForDiscoverParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSuggestedUsersForDiscoverOutput {
+    pub actors: Vec<crate::lexicon::app::bsky::actor::ProfileView>,
+    /// Snowflake for this recommendation, use when submitting recommendation events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rec_id_str: Option<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetSuggestedUsersForDiscoverSkeletonParams {
+    pub limit: Option<i64>,
+    /// DID of the account making the request (not included for public/unauthenticated queries).
+    pub viewer: Option<String>,
+}
+
+impl GetSuggestedUsersForDiscoverSkeletonParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        if let Some(value) = &self.viewer {
+            query.push(("viewer", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSuggestedUsersForDiscoverSkeletonOutput {
+    pub dids: Vec<String>,
+    /// Snowflake for this recommendation, use when submitting recommendation events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rec_id_str: Option<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetSuggestedUsersForExploreParams {
+    /// Category of users to get suggestions for.
+    pub category: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl GetSuggestedUsersForExploreParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.category {
+            query.push(("category", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSuggestedUsersForExploreOutput {
+    pub actors: Vec<crate::lexicon::app::bsky::actor::ProfileView>,
+    /// Snowflake for this recommendation, use when submitting recommendation events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rec_id_str: Option<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetSuggestedUsersForExploreSkeletonParams {
+    /// Category of users to get suggestions for.
+    pub category: Option<String>,
+    pub limit: Option<i64>,
+    /// DID of the account making the request (not included for public/unauthenticated queries).
+    pub viewer: Option<String>,
+}
+
+impl GetSuggestedUsersForExploreSkeletonParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.category {
+            query.push(("category", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        if let Some(value) = &self.viewer {
+            query.push(("viewer", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSuggestedUsersForExploreSkeletonOutput {
+    pub dids: Vec<String>,
+    /// Snowflake for this recommendation, use when submitting recommendation events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rec_id_str: Option<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetSuggestedUsersForSeeMoreParams {
+    /// Category of users to get suggestions for.
+    pub category: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl GetSuggestedUsersForSeeMoreParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.category {
+            query.push(("category", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSuggestedUsersForSeeMoreOutput {
+    pub actors: Vec<crate::lexicon::app::bsky::actor::ProfileView>,
+    /// Snowflake for this recommendation, use when submitting recommendation events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rec_id_str: Option<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetSuggestedUsersForSeeMoreSkeletonParams {
+    /// Category of users to get suggestions for.
+    pub category: Option<String>,
+    pub limit: Option<i64>,
+    /// DID of the account making the request (not included for public/unauthenticated queries).
+    pub viewer: Option<String>,
+}
+
+impl GetSuggestedUsersForSeeMoreSkeletonParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.category {
+            query.push(("category", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        if let Some(value) = &self.viewer {
+            query.push(("viewer", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSuggestedUsersForSeeMoreSkeletonOutput {
+    pub dids: Vec<String>,
+    /// Snowflake for this recommendation, use when submitting recommendation events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rec_id_str: Option<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetSuggestedUsersSkeletonParams {
+    /// Category of users to get suggestions for.
+    pub category: Option<String>,
+    pub limit: Option<i64>,
+    /// DID of the account making the request (not included for public/unauthenticated queries).
+    pub viewer: Option<String>,
+}
+
+impl GetSuggestedUsersSkeletonParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.category {
+            query.push(("category", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        if let Some(value) = &self.viewer {
+            query.push(("viewer", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSuggestedUsersSkeletonOutput {
+    pub dids: Vec<String>,
+    /// DEPRECATED: use recIdStr instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rec_id: Option<String>,
+    /// Snowflake for this recommendation, use when submitting recommendation events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rec_id_str: Option<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetSuggestionsSkeletonParams {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+    /// DID of the account to get suggestions relative to. If not provided, suggestions will be based on the viewer.
+    pub relative_to_did: Option<String>,
+    /// DID of the account making the request (not included for public/unauthenticated queries). Used to boost followed accounts in ranking.
+    pub viewer: Option<String>,
+}
+
+impl GetSuggestionsSkeletonParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        if let Some(value) = &self.relative_to_did {
+            query.push(("relativeToDid", value.to_string()));
+        }
+        if let Some(value) = &self.viewer {
+            query.push(("viewer", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSuggestionsSkeletonOutput {
+    pub actors: Vec<SkeletonSearchActor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// DEPRECATED: use recIdStr instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rec_id: Option<i64>,
+    /// Snowflake for this recommendation, use when submitting recommendation events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rec_id_str: Option<String>,
+    /// DID of the account these suggestions are relative to. If this is returned undefined, suggestions are based on the viewer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relative_to_did: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTaggedSuggestionsOutput {
+    pub suggestions: Vec<GetTaggedSuggestionsSuggestion>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTaggedSuggestionsSuggestion {
+    pub subject: String,
+    pub subject_type: String,
+    pub tag: String,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetTrendingTopicsParams {
+    pub limit: Option<i64>,
+    /// DID of the account making the request (not included for public/unauthenticated queries). Used to boost followed accounts in ranking.
+    pub viewer: Option<String>,
+}
+
+impl GetTrendingTopicsParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        if let Some(value) = &self.viewer {
+            query.push(("viewer", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTrendingTopicsOutput {
+    pub suggested: Vec<TrendingTopic>,
+    pub topics: Vec<TrendingTopic>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetTrendsParams {
+    pub limit: Option<i64>,
+}
+
+impl GetTrendsParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTrendsOutput {
+    pub trends: Vec<TrendView>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetTrendsSkeletonParams {
+    pub limit: Option<i64>,
+    /// DID of the account making the request (not included for public/unauthenticated queries).
+    pub viewer: Option<String>,
+}
+
+impl GetTrendsSkeletonParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        if let Some(value) = &self.viewer {
+            query.push(("viewer", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTrendsSkeletonOutput {
+    pub trends: Vec<SkeletonTrend>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitAgeAssuranceInput {
+    /// An ISO 3166-1 alpha-2 code of the user's location.
+    pub country_code: String,
+    /// The user's email address to receive assurance instructions.
+    pub email: String,
+    /// The user's preferred language for communication during the assurance process.
+    pub language: String,
+}
+
+pub type InitAgeAssuranceOutput = AgeAssuranceState;
+
+#[derive(Debug, Default, Clone)]
+pub struct SearchActorsSkeletonParams {
+    /// Optional pagination mechanism; may not necessarily allow scrolling through entire result set.
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+    /// Search query string; syntax, phrase, boolean, and faceting is unspecified, but Lucene query syntax is recommended. For typeahead search, only simple term match is supported, not full syntax.
+    pub q: String,
+    /// If true, acts as fast/simple 'typeahead' query.
+    pub typeahead: Option<bool>,
+    /// DID of the account making the request (not included for public/unauthenticated queries). Used to boost followed accounts in ranking.
+    pub viewer: Option<String>,
+}
+
+impl SearchActorsSkeletonParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query.push(("q", self.q.to_string()));
+        if let Some(value) = &self.typeahead {
+            query.push(("typeahead", value.to_string()));
+        }
+        if let Some(value) = &self.viewer {
+            query.push(("viewer", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchActorsSkeletonOutput {
+    pub actors: Vec<SkeletonSearchActor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// Count of search hits. Optional, may be rounded/truncated, and may not be possible to paginate through all hits.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hits_total: Option<i64>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct SearchPostsSkeletonParams {
+    /// Filter to posts by the given account. Handles are resolved to DID before query-time.
+    pub author: Option<String>,
+    /// Optional pagination mechanism; may not necessarily allow scrolling through entire result set.
+    pub cursor: Option<String>,
+    /// Filter to posts with URLs (facet links or embeds) linking to the given domain (hostname). Server may apply hostname normalization.
+    pub domain: Option<String>,
+    /// Filter to posts in the given language. Expected to be based on post language field, though server may override language detection.
+    pub lang: Option<String>,
+    pub limit: Option<i64>,
+    /// Filter to posts which mention the given account. Handles are resolved to DID before query-time. Only matches rich-text facet mentions.
+    pub mentions: Option<String>,
+    /// Search query string; syntax, phrase, boolean, and faceting is unspecified, but Lucene query syntax is recommended.
+    pub q: String,
+    /// Filter results for posts after the indicated datetime (inclusive). Expected to use 'sortAt' timestamp, which may not match 'createdAt'. Can be a datetime, or just an ISO date (YYYY-MM-DD).
+    pub since: Option<String>,
+    /// Specifies the ranking order of results.
+    pub sort: Option<String>,
+    /// Filter to posts with the given tag (hashtag), based on rich-text facet or tag field. Do not include the hash (#) prefix. Multiple tags can be specified, with 'AND' matching.
+    pub tag: Vec<String>,
+    /// Filter results for posts before the indicated datetime (not inclusive). Expected to use 'sortAt' timestamp, which may not match 'createdAt'. Can be a datetime, or just an ISO date (YYY-MM-DD).
+    pub until: Option<String>,
+    /// Filter to posts with links (facet links or embeds) pointing to this URL. Server may apply URL normalization or fuzzy matching.
+    pub url: Option<String>,
+    /// DID of the account making the request (not included for public/unauthenticated queries). Used for 'from:me' queries.
+    pub viewer: Option<String>,
+}
+
+impl SearchPostsSkeletonParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.author {
+            query.push(("author", value.to_string()));
+        }
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.domain {
+            query.push(("domain", value.to_string()));
+        }
+        if let Some(value) = &self.lang {
+            query.push(("lang", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        if let Some(value) = &self.mentions {
+            query.push(("mentions", value.to_string()));
+        }
+        query.push(("q", self.q.to_string()));
+        if let Some(value) = &self.since {
+            query.push(("since", value.to_string()));
+        }
+        if let Some(value) = &self.sort {
+            query.push(("sort", value.to_string()));
+        }
+        for value in &self.tag {
+            query.push(("tag", value.to_string()));
+        }
+        if let Some(value) = &self.until {
+            query.push(("until", value.to_string()));
+        }
+        if let Some(value) = &self.url {
+            query.push(("url", value.to_string()));
+        }
+        if let Some(value) = &self.viewer {
+            query.push(("viewer", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchPostsSkeletonOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// Count of search hits. Optional, may be rounded/truncated, and may not be possible to paginate through all hits.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hits_total: Option<i64>,
+    pub posts: Vec<SkeletonSearchPost>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct SearchStarterPacksSkeletonParams {
+    /// Optional pagination mechanism; may not necessarily allow scrolling through entire result set.
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+    /// Search query string; syntax, phrase, boolean, and faceting is unspecified, but Lucene query syntax is recommended.
+    pub q: String,
+    /// DID of the account making the request (not included for public/unauthenticated queries).
+    pub viewer: Option<String>,
+}
+
+impl SearchStarterPacksSkeletonParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query.push(("q", self.q.to_string()));
+        if let Some(value) = &self.viewer {
+            query.push(("viewer", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchStarterPacksSkeletonOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// Count of search hits. Optional, may be rounded/truncated, and may not be possible to paginate through all hits.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hits_total: Option<i64>,
+    pub starter_packs: Vec<SkeletonSearchStarterPack>,
+}
+
+impl crate::atproto::Client {
+    ///app.bsky.unspecced.getAgeAssuranceState
+    /// Returns the current state of the age assurance process for an account. This is used to check if the user has completed age assurance or if further action is required.
+    pub async fn app_bsky_unspecced_get_age_assurance_state(
+        &mut self,
+    ) -> Result<GetAgeAssuranceStateOutput, crate::errors::BiskyError> {
+        self.xrpc_get("app.bsky.unspecced.getAgeAssuranceState", None)
+            .await
+    }
+
+    ///app.bsky.unspecced.getConfig
+    /// Get miscellaneous runtime configuration.
+    pub async fn app_bsky_unspecced_get_config(
+        &mut self,
+    ) -> Result<GetConfigOutput, crate::errors::BiskyError> {
+        self.xrpc_get("app.bsky.unspecced.getConfig", None).await
+    }
+
+    ///app.bsky.unspecced.getOnboardingSuggestedStarterPacks
+    /// Get a list of suggested starterpacks for onboarding
+    pub async fn app_bsky_unspecced_get_onboarding_suggested_starter_packs(
+        &mut self,
+        params: &GetOnboardingSuggestedStarterPacksParams,
+    ) -> Result<GetOnboardingSuggestedStarterPacksOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get(
+            "app.bsky.unspecced.getOnboardingSuggestedStarterPacks",
+            Some(&query),
+        )
+        .await
+    }
+
+    ///app.bsky.unspecced.getOnboardingSuggestedStarterPacksSkeleton
+    /// Get a skeleton of suggested starterpacks for onboarding. Intended to be called and hydrated by app.bsky.unspecced.getOnboardingSuggestedStarterPacks
+    pub async fn app_bsky_unspecced_get_onboarding_suggested_starter_packs_skeleton(
+        &mut self,
+        params: &GetOnboardingSuggestedStarterPacksSkeletonParams,
+    ) -> Result<GetOnboardingSuggestedStarterPacksSkeletonOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get(
+            "app.bsky.unspecced.getOnboardingSuggestedStarterPacksSkeleton",
+            Some(&query),
+        )
+        .await
+    }
+
+    ///app.bsky.unspecced.getOnboardingSuggestedUsersSkeleton
+    /// Get a skeleton of suggested users for onboarding. Intended to be called and hydrated by app.bsky.unspecced.getSuggestedOnboardingUsers
+    pub async fn app_bsky_unspecced_get_onboarding_suggested_users_skeleton(
+        &mut self,
+        params: &GetOnboardingSuggestedUsersSkeletonParams,
+    ) -> Result<GetOnboardingSuggestedUsersSkeletonOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get(
+            "app.bsky.unspecced.getOnboardingSuggestedUsersSkeleton",
+            Some(&query),
+        )
+        .await
+    }
+
+    ///app.bsky.unspecced.getPopularFeedGenerators
+    /// An unspecced view of globally popular feed generators.
+    pub async fn app_bsky_unspecced_get_popular_feed_generators(
+        &mut self,
+        params: &GetPopularFeedGeneratorsParams,
+    ) -> Result<GetPopularFeedGeneratorsOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.unspecced.getPopularFeedGenerators", Some(&query))
+            .await
+    }
+
+    ///app.bsky.unspecced.getPostThreadOtherV2
+    /// (NOTE: this endpoint is under development and WILL change without notice. Don't use it until it is moved out of `unspecced` or your application WILL break) Get additional posts under a thread e.g. replies hidden by threadgate. Based on an anchor post at any depth of the tree, returns top-level replies below that anchor. It does not include ancestors nor the anchor itself. This should be called after exhausting `app.bsky.unspecced.getPostThreadV2`. Does not require auth, but additional metadata and filtering will be applied for authed requests.
+    pub async fn app_bsky_unspecced_get_post_thread_other_v2(
+        &mut self,
+        params: &GetPostThreadOtherV2Params,
+    ) -> Result<GetPostThreadOtherV2Output, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.unspecced.getPostThreadOtherV2", Some(&query))
+            .await
+    }
+
+    ///app.bsky.unspecced.getPostThreadV2
+    /// (NOTE: this endpoint is under development and WILL change without notice. Don't use it until it is moved out of `unspecced` or your application WILL break) Get posts in a thread. It is based in an anchor post at any depth of the tree, and returns posts above it (recursively resolving the parent, without further branching to their replies) and below it (recursive replies, with branching to their replies). Does not require auth, but additional metadata and filtering will be applied for authed requests.
+    pub async fn app_bsky_unspecced_get_post_thread_v2(
+        &mut self,
+        params: &GetPostThreadV2Params,
+    ) -> Result<GetPostThreadV2Output, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.unspecced.getPostThreadV2", Some(&query))
+            .await
+    }
+
+    ///app.bsky.unspecced.getSuggestedFeeds
+    /// Get a list of suggested feeds
+    pub async fn app_bsky_unspecced_get_suggested_feeds(
+        &mut self,
+        params: &GetSuggestedFeedsParams,
+    ) -> Result<GetSuggestedFeedsOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.unspecced.getSuggestedFeeds", Some(&query))
+            .await
+    }
+
+    ///app.bsky.unspecced.getSuggestedFeedsSkeleton
+    /// Get a skeleton of suggested feeds. Intended to be called and hydrated by app.bsky.unspecced.getSuggestedFeeds
+    pub async fn app_bsky_unspecced_get_suggested_feeds_skeleton(
+        &mut self,
+        params: &GetSuggestedFeedsSkeletonParams,
+    ) -> Result<GetSuggestedFeedsSkeletonOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.unspecced.getSuggestedFeedsSkeleton", Some(&query))
+            .await
+    }
+
+    ///app.bsky.unspecced.getSuggestedOnboardingUsers
+    /// Get a list of suggested users for onboarding
+    pub async fn app_bsky_unspecced_get_suggested_onboarding_users(
+        &mut self,
+        params: &GetSuggestedOnboardingUsersParams,
+    ) -> Result<GetSuggestedOnboardingUsersOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get(
+            "app.bsky.unspecced.getSuggestedOnboardingUsers",
+            Some(&query),
+        )
+        .await
+    }
+
+    ///app.bsky.unspecced.getSuggestedStarterPacks
+    /// Get a list of suggested starterpacks
+    pub async fn app_bsky_unspecced_get_suggested_starter_packs(
+        &mut self,
+        params: &GetSuggestedStarterPacksParams,
+    ) -> Result<GetSuggestedStarterPacksOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.unspecced.getSuggestedStarterPacks", Some(&query))
+            .await
+    }
+
+    ///app.bsky.unspecced.getSuggestedStarterPacksSkeleton
+    /// Get a skeleton of suggested starterpacks. Intended to be called and hydrated by app.bsky.unspecced.getSuggestedStarterpacks
+    pub async fn app_bsky_unspecced_get_suggested_starter_packs_skeleton(
+        &mut self,
+        params: &GetSuggestedStarterPacksSkeletonParams,
+    ) -> Result<GetSuggestedStarterPacksSkeletonOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get(
+            "app.bsky.unspecced.getSuggestedStarterPacksSkeleton",
+            Some(&query),
+        )
+        .await
+    }
+
+    ///app.bsky.unspecced.getSuggestedUsers
+    /// Get a list of suggested users
+    pub async fn app_bsky_unspecced_get_suggested_users(
+        &mut self,
+        params: &GetSuggestedUsersParams,
+    ) -> Result<GetSuggestedUsersOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.unspecced.getSuggestedUsers", Some(&query))
+            .await
+    }
+
+    ///app.bsky.unspecced.getSuggestedUsersForDiscover
+    /// Get a list of suggested users for the Discover page
+    pub async fn app_bsky_unspecced_get_suggested_users_for_discover(
+        &mut self,
+        params: &GetSuggestedUsersForDiscoverParams,
+    ) -> Result<GetSuggestedUsersForDiscoverOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get(
+            "app.bsky.unspecced.getSuggestedUsersForDiscover",
+            Some(&query),
+        )
+        .await
+    }
+
+    ///app.bsky.unspecced.getSuggestedUsersForDiscoverSkeleton
+    /// Get a skeleton of suggested users for the Discover page. Intended to be called and hydrated by app.bsky.unspecced.getSuggestedUsersForDiscover
+    pub async fn app_bsky_unspecced_get_suggested_users_for_discover_skeleton(
+        &mut self,
+        params: &GetSuggestedUsersForDiscoverSkeletonParams,
+    ) -> Result<GetSuggestedUsersForDiscoverSkeletonOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get(
+            "app.bsky.unspecced.getSuggestedUsersForDiscoverSkeleton",
+            Some(&query),
+        )
+        .await
+    }
+
+    ///app.bsky.unspecced.getSuggestedUsersForExplore
+    /// Get a list of suggested users for the Explore page
+    pub async fn app_bsky_unspecced_get_suggested_users_for_explore(
+        &mut self,
+        params: &GetSuggestedUsersForExploreParams,
+    ) -> Result<GetSuggestedUsersForExploreOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get(
+            "app.bsky.unspecced.getSuggestedUsersForExplore",
+            Some(&query),
+        )
+        .await
+    }
+
+    ///app.bsky.unspecced.getSuggestedUsersForExploreSkeleton
+    /// Get a skeleton of suggested users for the Explore page. Intended to be called and hydrated by app.bsky.unspecced.getSuggestedUsersForExplore
+    pub async fn app_bsky_unspecced_get_suggested_users_for_explore_skeleton(
+        &mut self,
+        params: &GetSuggestedUsersForExploreSkeletonParams,
+    ) -> Result<GetSuggestedUsersForExploreSkeletonOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get(
+            "app.bsky.unspecced.getSuggestedUsersForExploreSkeleton",
+            Some(&query),
+        )
+        .await
+    }
+
+    ///app.bsky.unspecced.getSuggestedUsersForSeeMore
+    /// Get a list of suggested users for the See More page
+    pub async fn app_bsky_unspecced_get_suggested_users_for_see_more(
+        &mut self,
+        params: &GetSuggestedUsersForSeeMoreParams,
+    ) -> Result<GetSuggestedUsersForSeeMoreOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get(
+            "app.bsky.unspecced.getSuggestedUsersForSeeMore",
+            Some(&query),
+        )
+        .await
+    }
+
+    ///app.bsky.unspecced.getSuggestedUsersForSeeMoreSkeleton
+    /// Get a skeleton of suggested users for the See More page. Intended to be called and hydrated by app.bsky.unspecced.getSuggestedUsersForSeeMore
+    pub async fn app_bsky_unspecced_get_suggested_users_for_see_more_skeleton(
+        &mut self,
+        params: &GetSuggestedUsersForSeeMoreSkeletonParams,
+    ) -> Result<GetSuggestedUsersForSeeMoreSkeletonOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get(
+            "app.bsky.unspecced.getSuggestedUsersForSeeMoreSkeleton",
+            Some(&query),
+        )
+        .await
+    }
+
+    ///app.bsky.unspecced.getSuggestedUsersSkeleton
+    /// Get a skeleton of suggested users. Intended to be called and hydrated by app.bsky.unspecced.getSuggestedUsers
+    pub async fn app_bsky_unspecced_get_suggested_users_skeleton(
+        &mut self,
+        params: &GetSuggestedUsersSkeletonParams,
+    ) -> Result<GetSuggestedUsersSkeletonOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.unspecced.getSuggestedUsersSkeleton", Some(&query))
+            .await
+    }
+
+    ///app.bsky.unspecced.getSuggestionsSkeleton
+    /// Get a skeleton of suggested actors. Intended to be called and then hydrated through app.bsky.actor.getSuggestions
+    pub async fn app_bsky_unspecced_get_suggestions_skeleton(
+        &mut self,
+        params: &GetSuggestionsSkeletonParams,
+    ) -> Result<GetSuggestionsSkeletonOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.unspecced.getSuggestionsSkeleton", Some(&query))
+            .await
+    }
+
+    ///app.bsky.unspecced.getTaggedSuggestions
+    /// Get a list of suggestions (feeds and users) tagged with categories
+    pub async fn app_bsky_unspecced_get_tagged_suggestions(
+        &mut self,
+    ) -> Result<GetTaggedSuggestionsOutput, crate::errors::BiskyError> {
+        self.xrpc_get("app.bsky.unspecced.getTaggedSuggestions", None)
+            .await
+    }
+
+    ///app.bsky.unspecced.getTrendingTopics
+    /// Get a list of trending topics
+    pub async fn app_bsky_unspecced_get_trending_topics(
+        &mut self,
+        params: &GetTrendingTopicsParams,
+    ) -> Result<GetTrendingTopicsOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.unspecced.getTrendingTopics", Some(&query))
+            .await
+    }
+
+    ///app.bsky.unspecced.getTrends
+    /// Get the current trends on the network
+    pub async fn app_bsky_unspecced_get_trends(
+        &mut self,
+        params: &GetTrendsParams,
+    ) -> Result<GetTrendsOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.unspecced.getTrends", Some(&query))
+            .await
+    }
+
+    ///app.bsky.unspecced.getTrendsSkeleton
+    /// Get the skeleton of trends on the network. Intended to be called and then hydrated through app.bsky.unspecced.getTrends
+    pub async fn app_bsky_unspecced_get_trends_skeleton(
+        &mut self,
+        params: &GetTrendsSkeletonParams,
+    ) -> Result<GetTrendsSkeletonOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.unspecced.getTrendsSkeleton", Some(&query))
+            .await
+    }
+
+    ///app.bsky.unspecced.initAgeAssurance
+    /// Initiate age assurance for an account. This is a one-time action that will start the process of verifying the user's age.
+    pub async fn app_bsky_unspecced_init_age_assurance(
+        &mut self,
+        input: &InitAgeAssuranceInput,
+    ) -> Result<InitAgeAssuranceOutput, crate::errors::BiskyError> {
+        self.xrpc_post("app.bsky.unspecced.initAgeAssurance", input)
+            .await
+    }
+
+    ///app.bsky.unspecced.searchActorsSkeleton
+    /// Backend Actors (profile) search, returns only skeleton.
+    pub async fn app_bsky_unspecced_search_actors_skeleton(
+        &mut self,
+        params: &SearchActorsSkeletonParams,
+    ) -> Result<SearchActorsSkeletonOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.unspecced.searchActorsSkeleton", Some(&query))
+            .await
+    }
+
+    ///app.bsky.unspecced.searchPostsSkeleton
+    /// Backend Posts search, returns only skeleton
+    pub async fn app_bsky_unspecced_search_posts_skeleton(
+        &mut self,
+        params: &SearchPostsSkeletonParams,
+    ) -> Result<SearchPostsSkeletonOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.unspecced.searchPostsSkeleton", Some(&query))
+            .await
+    }
+
+    ///app.bsky.unspecced.searchStarterPacksSkeleton
+    /// Backend Starter Pack search, returns only skeleton.
+    pub async fn app_bsky_unspecced_search_starter_packs_skeleton(
+        &mut self,
+        params: &SearchStarterPacksSkeletonParams,
+    ) -> Result<SearchStarterPacksSkeletonOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get(
+            "app.bsky.unspecced.searchStarterPacksSkeleton",
+            Some(&query),
+        )
+        .await
+    }
+}
@@ -0,0 +1,96 @@
+//! app.bsky.video
+// @generated by `cargo xtask codegen`, do not edit
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobStatus {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blob: Option<crate::lexicon::Blob>,
+    pub did: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub job_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Progress within the current processing state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub progress: Option<usize>,
+    /// The state of the video processing job. All values not listed as a known value indicate that the job is in process.
+    pub state: String,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetJobStatusParams {
+    pub job_id: String,
+}
+
+impl GetJobStatusParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("jobId", self.job_id.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetJobStatusOutput {
+    pub job_status: JobStatus,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetUploadLimitsOutput {
+    pub can_upload: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remaining_daily_bytes: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remaining_daily_videos: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadVideoOutput {
+    pub job_status: JobStatus,
+}
+
+impl crate::atproto::Client {
+    ///app.bsky.video.getJobStatus
+    /// Get status details for a video processing job.
+    pub async fn app_bsky_video_get_job_status(
+        &mut self,
+        params: &GetJobStatusParams,
+    ) -> Result<GetJobStatusOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("app.bsky.video.getJobStatus", Some(&query))
+            .await
+    }
+
+    ///app.bsky.video.getUploadLimits
+    /// Get video upload limits for the authenticated user.
+    pub async fn app_bsky_video_get_upload_limits(
+        &mut self,
+    ) -> Result<GetUploadLimitsOutput, crate::errors::BiskyError> {
+        self.xrpc_get("app.bsky.video.getUploadLimits", None).await
+    }
+
+    ///app.bsky.video.uploadVideo
+    /// Upload a video to be processed then stored on the PDS.
+    pub async fn app_bsky_video_upload_video(
+        &mut self,
+        body: &[u8],
+        mime_type: &str,
+    ) -> Result<UploadVideoOutput, crate::errors::BiskyError> {
+        self.xrpc_post_binary("app.bsky.video.uploadVideo", body, mime_type)
+            .await
+    }
+}
@@ -0,0 +1,3 @@
+// @generated by `cargo xtask codegen`, do not edit
+
+pub mod bsky;
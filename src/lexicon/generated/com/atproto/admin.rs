@@ -0,0 +1,497 @@
+//! com.atproto.admin
+// @generated by `cargo xtask codegen`, do not edit
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountView {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deactivated_at: Option<crate::lexicon::Datetime>,
+    pub did: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_confirmed_at: Option<crate::lexicon::Datetime>,
+    pub handle: String,
+    pub indexed_at: crate::lexicon::Datetime,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invite_note: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invited_by: Option<crate::lexicon::com::atproto::server::InviteCode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invites: Option<Vec<crate::lexicon::com::atproto::server::InviteCode>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invites_disabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub related_records: Option<Vec<serde_json::Value>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threat_signatures: Option<Vec<ThreatSignature>>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepoBlobRef {
+    pub cid: String,
+    pub did: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record_uri: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepoRef {
+    pub did: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusAttr {
+    pub applied: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#ref: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreatSignature {
+    pub property: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteAccountInput {
+    pub did: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisableAccountInvitesInput {
+    pub account: String,
+    /// Optional reason for disabled invites.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisableInviteCodesInput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accounts: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub codes: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnableAccountInvitesInput {
+    pub account: String,
+    /// Optional reason for enabled invites.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetAccountInfoParams {
+    pub did: String,
+}
+
+impl GetAccountInfoParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("did", self.did.to_string()));
+        query
+    }
+}
+
+pub type GetAccountInfoOutput = AccountView;
+
+#[derive(Debug, Default, Clone)]
+pub struct GetAccountInfosParams {
+    pub dids: Vec<String>,
+}
+
+impl GetAccountInfosParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        for value in &self.dids {
+            query.push(("dids", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAccountInfosOutput {
+    pub infos: Vec<AccountView>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetInviteCodesParams {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+    pub sort: Option<String>,
+}
+
+impl GetInviteCodesParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        if let Some(value) = &self.sort {
+            query.push(("sort", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetInviteCodesOutput {
+    pub codes: Vec<crate::lexicon::com::atproto::server::InviteCode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum GetSubjectStatusOutputSubject {
+        RepoRef(Box<RepoRef>) = "com.atproto.admin.defs#repoRef",
+        StrongRef(Box<crate::lexicon::com::atproto::repo::StrongRef>) = "com.atproto.repo.strongRef",
+        RepoBlobRef(Box<RepoBlobRef>) = "com.atproto.admin.defs#repoBlobRef",
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetSubjectStatusParams {
+    pub blob: Option<String>,
+    pub did: Option<String>,
+    pub uri: Option<String>,
+}
+
+impl GetSubjectStatusParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.blob {
+            query.push(("blob", value.to_string()));
+        }
+        if let Some(value) = &self.did {
+            query.push(("did", value.to_string()));
+        }
+        if let Some(value) = &self.uri {
+            query.push(("uri", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSubjectStatusOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deactivated: Option<StatusAttr>,
+    pub subject: GetSubjectStatusOutputSubject,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub takedown: Option<StatusAttr>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct SearchAccountsParams {
+    pub cursor: Option<String>,
+    pub email: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl SearchAccountsParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.email {
+            query.push(("email", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchAccountsOutput {
+    pub accounts: Vec<AccountView>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendEmailInput {
+    /// Additional comment by the sender that won't be used in the email itself but helpful to provide more context for moderators/reviewers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    pub content: String,
+    pub recipient_did: String,
+    pub sender_did: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendEmailOutput {
+    pub sent: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateAccountEmailInput {
+    /// The handle or DID of the repo.
+    pub account: String,
+    pub email: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateAccountHandleInput {
+    pub did: String,
+    pub handle: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateAccountPasswordInput {
+    pub did: String,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateAccountSigningKeyInput {
+    pub did: String,
+    /// Did-key formatted public key
+    pub signing_key: String,
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum UpdateSubjectStatusInputSubject {
+        RepoRef(Box<RepoRef>) = "com.atproto.admin.defs#repoRef",
+        StrongRef(Box<crate::lexicon::com::atproto::repo::StrongRef>) = "com.atproto.repo.strongRef",
+        RepoBlobRef(Box<RepoBlobRef>) = "com.atproto.admin.defs#repoBlobRef",
+    }
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum UpdateSubjectStatusOutputSubject {
+        RepoRef(Box<RepoRef>) = "com.atproto.admin.defs#repoRef",
+        StrongRef(Box<crate::lexicon::com::atproto::repo::StrongRef>) = "com.atproto.repo.strongRef",
+        RepoBlobRef(Box<RepoBlobRef>) = "com.atproto.admin.defs#repoBlobRef",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateSubjectStatusInput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deactivated: Option<StatusAttr>,
+    pub subject: UpdateSubjectStatusInputSubject,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub takedown: Option<StatusAttr>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateSubjectStatusOutput {
+    pub subject: UpdateSubjectStatusOutputSubject,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub takedown: Option<StatusAttr>,
+}
+
+impl crate::atproto::Client {
+    ///com.atproto.admin.deleteAccount
+    /// Delete a user account as an administrator.
+    pub async fn com_atproto_admin_delete_account(
+        &mut self,
+        input: &DeleteAccountInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("com.atproto.admin.deleteAccount", input)
+            .await
+    }
+
+    ///com.atproto.admin.disableAccountInvites
+    /// Disable an account from receiving new invite codes, but does not invalidate existing codes.
+    pub async fn com_atproto_admin_disable_account_invites(
+        &mut self,
+        input: &DisableAccountInvitesInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("com.atproto.admin.disableAccountInvites", input)
+            .await
+    }
+
+    ///com.atproto.admin.disableInviteCodes
+    /// Disable some set of codes and/or all codes associated with a set of users.
+    pub async fn com_atproto_admin_disable_invite_codes(
+        &mut self,
+        input: &DisableInviteCodesInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("com.atproto.admin.disableInviteCodes", input)
+            .await
+    }
+
+    ///com.atproto.admin.enableAccountInvites
+    /// Re-enable an account's ability to receive invite codes.
+    pub async fn com_atproto_admin_enable_account_invites(
+        &mut self,
+        input: &EnableAccountInvitesInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("com.atproto.admin.enableAccountInvites", input)
+            .await
+    }
+
+    ///com.atproto.admin.getAccountInfo
+    /// Get details about an account.
+    pub async fn com_atproto_admin_get_account_info(
+        &mut self,
+        params: &GetAccountInfoParams,
+    ) -> Result<GetAccountInfoOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.admin.getAccountInfo", Some(&query))
+            .await
+    }
+
+    ///com.atproto.admin.getAccountInfos
+    /// Get details about some accounts.
+    pub async fn com_atproto_admin_get_account_infos(
+        &mut self,
+        params: &GetAccountInfosParams,
+    ) -> Result<GetAccountInfosOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.admin.getAccountInfos", Some(&query))
+            .await
+    }
+
+    ///com.atproto.admin.getInviteCodes
+    /// Get an admin view of invite codes.
+    pub async fn com_atproto_admin_get_invite_codes(
+        &mut self,
+        params: &GetInviteCodesParams,
+    ) -> Result<GetInviteCodesOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.admin.getInviteCodes", Some(&query))
+            .await
+    }
+
+    ///com.atproto.admin.getSubjectStatus
+    /// Get the service-specific admin status of a subject (account, record, or blob).
+    pub async fn com_atproto_admin_get_subject_status(
+        &mut self,
+        params: &GetSubjectStatusParams,
+    ) -> Result<GetSubjectStatusOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.admin.getSubjectStatus", Some(&query))
+            .await
+    }
+
+    ///com.atproto.admin.searchAccounts
+    /// Get list of accounts that matches your search query.
+    pub async fn com_atproto_admin_search_accounts(
+        &mut self,
+        params: &SearchAccountsParams,
+    ) -> Result<SearchAccountsOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.admin.searchAccounts", Some(&query))
+            .await
+    }
+
+    ///com.atproto.admin.sendEmail
+    /// Send email to a user's account email address.
+    pub async fn com_atproto_admin_send_email(
+        &mut self,
+        input: &SendEmailInput,
+    ) -> Result<SendEmailOutput, crate::errors::BiskyError> {
+        self.xrpc_post("com.atproto.admin.sendEmail", input).await
+    }
+
+    ///com.atproto.admin.updateAccountEmail
+    /// Administrative action to update an account's email.
+    pub async fn com_atproto_admin_update_account_email(
+        &mut self,
+        input: &UpdateAccountEmailInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("com.atproto.admin.updateAccountEmail", input)
+            .await
+    }
+
+    ///com.atproto.admin.updateAccountHandle
+    /// Administrative action to update an account's handle.
+    pub async fn com_atproto_admin_update_account_handle(
+        &mut self,
+        input: &UpdateAccountHandleInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("com.atproto.admin.updateAccountHandle", input)
+            .await
+    }
+
+    ///com.atproto.admin.updateAccountPassword
+    /// Update the password for a user account as an administrator.
+    pub async fn com_atproto_admin_update_account_password(
+        &mut self,
+        input: &UpdateAccountPasswordInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("com.atproto.admin.updateAccountPassword", input)
+            .await
+    }
+
+    ///com.atproto.admin.updateAccountSigningKey
+    /// Administrative action to update an account's signing key in their Did document.
+    pub async fn com_atproto_admin_update_account_signing_key(
+        &mut self,
+        input: &UpdateAccountSigningKeyInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("com.atproto.admin.updateAccountSigningKey", input)
+            .await
+    }
+
+    ///com.atproto.admin.updateSubjectStatus
+    /// Update the service-specific admin status of a subject (account, record, or blob).
+    pub async fn com_atproto_admin_update_subject_status(
+        &mut self,
+        input: &UpdateSubjectStatusInput,
+    ) -> Result<UpdateSubjectStatusOutput, crate::errors::BiskyError> {
+        self.xrpc_post("com.atproto.admin.updateSubjectStatus", input)
+            .await
+    }
+}
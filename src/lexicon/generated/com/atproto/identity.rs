@@ -0,0 +1,238 @@
+//! com.atproto.identity
+// @generated by `cargo xtask codegen`, do not edit
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentityInfo {
+    pub did: String,
+    /// The complete DID document for the identity.
+    pub did_doc: serde_json::Value,
+    /// The validated handle of the account; or 'handle.invalid' if the handle did not bi-directionally match the DID document.
+    pub handle: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRecommendedDidCredentialsOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub also_known_as: Option<Vec<String>>,
+    /// Recommended rotation keys for PLC dids. Should be undefined (or ignored) for did:webs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rotation_keys: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub services: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification_methods: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefreshIdentityInput {
+    pub identifier: String,
+}
+
+pub type RefreshIdentityOutput = IdentityInfo;
+
+#[derive(Debug, Default, Clone)]
+pub struct ResolveDidParams {
+    /// DID to resolve.
+    pub did: String,
+}
+
+impl ResolveDidParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("did", self.did.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolveDidOutput {
+    /// The complete DID document for the identity.
+    pub did_doc: serde_json::Value,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ResolveHandleParams {
+    /// The handle to resolve.
+    pub handle: String,
+}
+
+impl ResolveHandleParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("handle", self.handle.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolveHandleOutput {
+    pub did: String,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ResolveIdentityParams {
+    /// Handle or DID to resolve.
+    pub identifier: String,
+}
+
+impl ResolveIdentityParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("identifier", self.identifier.to_string()));
+        query
+    }
+}
+
+pub type ResolveIdentityOutput = IdentityInfo;
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignPlcOperationInput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub also_known_as: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rotation_keys: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub services: Option<serde_json::Value>,
+    /// A token received through com.atproto.identity.requestPlcOperationSignature
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification_methods: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignPlcOperationOutput {
+    /// A signed DID PLC operation.
+    pub operation: serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitPlcOperationInput {
+    pub operation: serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateHandleInput {
+    /// The new handle.
+    pub handle: String,
+}
+
+impl crate::atproto::Client {
+    ///com.atproto.identity.getRecommendedDidCredentials
+    /// Describe the credentials that should be included in the DID doc of an account that is migrating to this service.
+    pub async fn com_atproto_identity_get_recommended_did_credentials(
+        &mut self,
+    ) -> Result<GetRecommendedDidCredentialsOutput, crate::errors::BiskyError> {
+        self.xrpc_get("com.atproto.identity.getRecommendedDidCredentials", None)
+            .await
+    }
+
+    ///com.atproto.identity.refreshIdentity
+    /// Request that the server re-resolve an identity (DID and handle). The server may ignore this request, or require authentication, depending on the role, implementation, and policy of the server.
+    pub async fn com_atproto_identity_refresh_identity(
+        &mut self,
+        input: &RefreshIdentityInput,
+    ) -> Result<RefreshIdentityOutput, crate::errors::BiskyError> {
+        self.xrpc_post("com.atproto.identity.refreshIdentity", input)
+            .await
+    }
+
+    ///com.atproto.identity.requestPlcOperationSignature
+    /// Request an email with a code to in order to request a signed PLC operation. Requires Auth.
+    pub async fn com_atproto_identity_request_plc_operation_signature(
+        &mut self,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response(
+            "com.atproto.identity.requestPlcOperationSignature",
+            &serde_json::json!({}),
+        )
+        .await
+    }
+
+    ///com.atproto.identity.resolveDid
+    /// Resolves DID to DID document. Does not bi-directionally verify handle.
+    pub async fn com_atproto_identity_resolve_did(
+        &mut self,
+        params: &ResolveDidParams,
+    ) -> Result<ResolveDidOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.identity.resolveDid", Some(&query))
+            .await
+    }
+
+    ///com.atproto.identity.resolveHandle
+    /// Resolves an atproto handle (hostname) to a DID. Does not necessarily bi-directionally verify against the the DID document.
+    pub async fn com_atproto_identity_resolve_handle(
+        &mut self,
+        params: &ResolveHandleParams,
+    ) -> Result<ResolveHandleOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.identity.resolveHandle", Some(&query))
+            .await
+    }
+
+    ///com.atproto.identity.resolveIdentity
+    /// Resolves an identity (DID or Handle) to a full identity (DID document and verified handle).
+    pub async fn com_atproto_identity_resolve_identity(
+        &mut self,
+        params: &ResolveIdentityParams,
+    ) -> Result<ResolveIdentityOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.identity.resolveIdentity", Some(&query))
+            .await
+    }
+
+    ///com.atproto.identity.signPlcOperation
+    /// Signs a PLC operation to update some value(s) in the requesting DID's document.
+    pub async fn com_atproto_identity_sign_plc_operation(
+        &mut self,
+        input: &SignPlcOperationInput,
+    ) -> Result<SignPlcOperationOutput, crate::errors::BiskyError> {
+        self.xrpc_post("com.atproto.identity.signPlcOperation", input)
+            .await
+    }
+
+    ///com.atproto.identity.submitPlcOperation
+    /// Validates a PLC operation to ensure that it doesn't violate a service's constraints or get the identity into a bad state, then submits it to the PLC registry
+    pub async fn com_atproto_identity_submit_plc_operation(
+        &mut self,
+        input: &SubmitPlcOperationInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("com.atproto.identity.submitPlcOperation", input)
+            .await
+    }
+
+    ///com.atproto.identity.updateHandle
+    /// Updates the current account's handle. Verifies handle validity, and updates did:plc document if necessary. Implemented by PDS, and requires auth.
+    pub async fn com_atproto_identity_update_handle(
+        &mut self,
+        input: &UpdateHandleInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("com.atproto.identity.updateHandle", input)
+            .await
+    }
+}
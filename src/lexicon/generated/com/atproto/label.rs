@@ -0,0 +1,174 @@
+//! com.atproto.label
+// @generated by `cargo xtask codegen`, do not edit
+
+/// Metadata tag on an atproto resource (eg, repo or record).
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Label {
+    /// Optionally, CID specifying the specific version of 'uri' resource this label applies to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cid: Option<String>,
+    /// Timestamp when this label was created.
+    pub cts: crate::lexicon::Datetime,
+    /// Timestamp at which this label expires (no longer applies).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exp: Option<crate::lexicon::Datetime>,
+    /// If true, this is a negation label, overwriting a previous label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neg: Option<bool>,
+    /// Signature of dag-cbor encoded label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sig: Option<crate::lexicon::Bytes>,
+    /// DID of the actor who created this label.
+    pub src: String,
+    /// AT URI of the record, repository (account), or other resource that this label applies to.
+    pub uri: String,
+    /// The short string name of the value or type of this label.
+    pub val: String,
+    /// The AT Protocol version of the label object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ver: Option<i64>,
+}
+
+pub type LabelValue = String;
+
+/// Declares a label value and its expected interpretations and behaviors.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelValueDefinition {
+    /// Does the user need to have adult content enabled in order to configure this label?
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adult_only: Option<bool>,
+    /// What should this label hide in the UI, if applied? 'content' hides all of the target; 'media' hides the images/video/audio; 'none' hides nothing.
+    pub blurs: String,
+    /// The default setting for this label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_setting: Option<String>,
+    /// The value of the label being defined. Must only include lowercase ascii and the '-' character ([a-z-]+).
+    pub identifier: String,
+    pub locales: Vec<LabelValueDefinitionStrings>,
+    /// How should a client visually convey this label? 'inform' means neutral and informational; 'alert' means negative and warning; 'none' means show nothing.
+    pub severity: String,
+}
+
+/// Strings which describe the label in the UI, localized into a specific language.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelValueDefinitionStrings {
+    /// A longer description of what the label means and why it might be applied.
+    pub description: String,
+    /// The code of the language these strings are written in.
+    pub lang: String,
+    /// A short human-readable name for the label.
+    pub name: String,
+}
+
+/// Metadata tag on an atproto record, published by the author within the record. Note that schemas should use #selfLabels, not #selfLabel.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelfLabel {
+    /// The short string name of the value or type of this label.
+    pub val: String,
+}
+
+/// Metadata tags on an atproto record, published by the author within the record.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelfLabels {
+    pub values: Vec<SelfLabel>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct QueryLabelsParams {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+    /// Optional list of label sources (DIDs) to filter on.
+    pub sources: Vec<String>,
+    /// List of AT URI patterns to match (boolean 'OR'). Each may be a prefix (ending with '*'; will match inclusive of the string leading to '*'), or a full URI.
+    pub uri_patterns: Vec<String>,
+}
+
+impl QueryLabelsParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        for value in &self.sources {
+            query.push(("sources", value.to_string()));
+        }
+        for value in &self.uri_patterns {
+            query.push(("uriPatterns", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryLabelsOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub labels: Vec<Label>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscribeLabelsInfo {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscribeLabelsLabels {
+    pub labels: Vec<Label>,
+    pub seq: i64,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct SubscribeLabelsParams {
+    /// The last known event seq number to backfill from.
+    pub cursor: Option<i64>,
+}
+
+impl SubscribeLabelsParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        query
+    }
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum SubscribeLabelsMessage {
+        Labels(Box<SubscribeLabelsLabels>) = "com.atproto.label.subscribeLabels#labels",
+        Info(Box<SubscribeLabelsInfo>) = "com.atproto.label.subscribeLabels#info",
+    }
+}
+
+impl crate::atproto::Client {
+    ///com.atproto.label.queryLabels
+    /// Find labels relevant to the provided AT-URI patterns. Public endpoint for moderation services, though may return different or additional results with auth.
+    pub async fn com_atproto_label_query_labels(
+        &mut self,
+        params: &QueryLabelsParams,
+    ) -> Result<QueryLabelsOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.label.queryLabels", Some(&query))
+            .await
+    }
+}
@@ -0,0 +1,55 @@
+//! com.atproto.lexicon
+// @generated by `cargo xtask codegen`, do not edit
+
+#[derive(Debug, Default, Clone)]
+pub struct ResolveLexiconParams {
+    /// The lexicon NSID to resolve.
+    pub nsid: String,
+}
+
+impl ResolveLexiconParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("nsid", self.nsid.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolveLexiconOutput {
+    /// The CID of the lexicon schema record.
+    pub cid: String,
+    /// The resolved lexicon schema record.
+    pub schema: Schema,
+    /// The AT-URI of the lexicon schema record.
+    pub uri: String,
+}
+
+/// Representation of Lexicon schemas themselves, when published as atproto records. Note that the schema language is not defined in Lexicon; this meta schema currently only includes a single version field ('lexicon'). See the atproto specifications for description of the other expected top-level fields ('id', 'defs', etc).
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Schema {
+    /// Indicates the 'version' of the Lexicon language. Must be '1' for the current atproto/Lexicon schema system.
+    pub lexicon: i64,
+    #[serde(flatten)]
+    pub extra: crate::lexicon::Extra,
+}
+
+impl crate::atproto::Client {
+    ///com.atproto.lexicon.resolveLexicon
+    /// Resolves an atproto lexicon (NSID) to a schema.
+    pub async fn com_atproto_lexicon_resolve_lexicon(
+        &mut self,
+        params: &ResolveLexiconParams,
+    ) -> Result<ResolveLexiconOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.lexicon.resolveLexicon", Some(&query))
+            .await
+    }
+}
@@ -0,0 +1,11 @@
+// @generated by `cargo xtask codegen`, do not edit
+
+pub mod admin;
+pub mod identity;
+pub mod label;
+pub mod lexicon;
+pub mod moderation;
+pub mod repo;
+pub mod server;
+pub mod sync;
+pub mod temp;
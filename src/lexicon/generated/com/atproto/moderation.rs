@@ -0,0 +1,92 @@
+//! com.atproto.moderation
+// @generated by `cargo xtask codegen`, do not edit
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum CreateReportInputSubject {
+        RepoRef(Box<crate::lexicon::com::atproto::admin::RepoRef>) = "com.atproto.admin.defs#repoRef",
+        StrongRef(Box<crate::lexicon::com::atproto::repo::StrongRef>) = "com.atproto.repo.strongRef",
+    }
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum CreateReportOutputSubject {
+        RepoRef(Box<crate::lexicon::com::atproto::admin::RepoRef>) = "com.atproto.admin.defs#repoRef",
+        StrongRef(Box<crate::lexicon::com::atproto::repo::StrongRef>) = "com.atproto.repo.strongRef",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateReportInput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mod_tool: Option<CreateReportModTool>,
+    /// Additional context about the content and violation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Indicates the broad category of violation the report is for.
+    pub reason_type: ReasonType,
+    pub subject: CreateReportInputSubject,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateReportOutput {
+    pub created_at: crate::lexicon::Datetime,
+    pub id: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    pub reason_type: ReasonType,
+    pub reported_by: String,
+    pub subject: CreateReportOutputSubject,
+}
+
+/// Moderation tool information for tracing the source of the action
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateReportModTool {
+    /// Additional arbitrary metadata about the source
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<serde_json::Value>,
+    /// Name/identifier of the source (e.g., 'bsky-app/android', 'bsky-web/chrome')
+    pub name: String,
+}
+
+/// Appeal a previously taken moderation action
+pub const REASON_APPEAL: &str = "com.atproto.moderation.defs#reasonAppeal";
+
+/// Misleading identity, affiliation, or content. Prefer new lexicon definition `tools.ozone.report.defs#reasonMisleadingOther`.
+pub const REASON_MISLEADING: &str = "com.atproto.moderation.defs#reasonMisleading";
+
+/// Reports not falling under another report category. Prefer new lexicon definition `tools.ozone.report.defs#reasonOther`.
+pub const REASON_OTHER: &str = "com.atproto.moderation.defs#reasonOther";
+
+/// Rude, harassing, explicit, or otherwise unwelcoming behavior. Prefer new lexicon definition `tools.ozone.report.defs#reasonHarassmentOther`.
+pub const REASON_RUDE: &str = "com.atproto.moderation.defs#reasonRude";
+
+/// Unwanted or mislabeled sexual content. Prefer new lexicon definition `tools.ozone.report.defs#reasonSexualUnlabeled`.
+pub const REASON_SEXUAL: &str = "com.atproto.moderation.defs#reasonSexual";
+
+/// Spam: frequent unwanted promotion, replies, mentions. Prefer new lexicon definition `tools.ozone.report.defs#reasonMisleadingSpam`.
+pub const REASON_SPAM: &str = "com.atproto.moderation.defs#reasonSpam";
+
+pub type ReasonType = String;
+
+/// Direct violation of server rules, laws, terms of service. Prefer new lexicon definition `tools.ozone.report.defs#reasonRuleOther`.
+pub const REASON_VIOLATION: &str = "com.atproto.moderation.defs#reasonViolation";
+
+/// Tag describing a type of subject that might be reported.
+pub type SubjectType = String;
+
+impl crate::atproto::Client {
+    ///com.atproto.moderation.createReport
+    /// Submit a moderation report regarding an atproto account or record. Implemented by moderation services (with PDS proxying), and requires auth.
+    pub async fn com_atproto_moderation_create_report(
+        &mut self,
+        input: &CreateReportInput,
+    ) -> Result<CreateReportOutput, crate::errors::BiskyError> {
+        self.xrpc_post("com.atproto.moderation.createReport", input)
+            .await
+    }
+}
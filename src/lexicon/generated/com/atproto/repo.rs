@@ -0,0 +1,463 @@
+//! com.atproto.repo
+// @generated by `cargo xtask codegen`, do not edit
+
+/// Operation which creates a new record.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyWritesCreate {
+    pub collection: String,
+    /// NOTE: maxLength is redundant with record-key format. Keeping it temporarily to ensure backwards compatibility.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rkey: Option<String>,
+    pub value: serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyWritesCreateResult {
+    pub cid: String,
+    pub uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation_status: Option<String>,
+}
+
+/// Operation which deletes an existing record.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyWritesDelete {
+    pub collection: String,
+    pub rkey: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyWritesDeleteResult {}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum ApplyWritesInputWrite {
+        Create(Box<ApplyWritesCreate>) = "com.atproto.repo.applyWrites#create",
+        Update(Box<ApplyWritesUpdate>) = "com.atproto.repo.applyWrites#update",
+        Delete(Box<ApplyWritesDelete>) = "com.atproto.repo.applyWrites#delete",
+    }
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum ApplyWritesOutputResult {
+        CreateResult(Box<ApplyWritesCreateResult>) = "com.atproto.repo.applyWrites#createResult",
+        UpdateResult(Box<ApplyWritesUpdateResult>) = "com.atproto.repo.applyWrites#updateResult",
+        DeleteResult(Box<ApplyWritesDeleteResult>) = "com.atproto.repo.applyWrites#deleteResult",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyWritesInput {
+    /// The handle or DID of the repo (aka, current account).
+    pub repo: String,
+    /// If provided, the entire operation will fail if the current repo commit CID does not match this value. Used to prevent conflicting repo mutations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub swap_commit: Option<String>,
+    /// Can be set to 'false' to skip Lexicon schema validation of record data across all operations, 'true' to require it, or leave unset to validate only for known Lexicons.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validate: Option<bool>,
+    pub writes: Vec<ApplyWritesInputWrite>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyWritesOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<CommitMeta>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<ApplyWritesOutputResult>>,
+}
+
+/// Operation which updates an existing record.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyWritesUpdate {
+    pub collection: String,
+    pub rkey: String,
+    pub value: serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyWritesUpdateResult {
+    pub cid: String,
+    pub uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation_status: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRecordInput<T = serde_json::Value> {
+    /// The NSID of the record collection.
+    pub collection: String,
+    /// The record itself. Must contain a $type field.
+    pub record: T,
+    /// The handle or DID of the repo (aka, current account).
+    pub repo: String,
+    /// The Record Key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rkey: Option<String>,
+    /// Compare and swap with the previous commit by CID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub swap_commit: Option<String>,
+    /// Can be set to 'false' to skip Lexicon schema validation of record data, 'true' to require it, or leave unset to validate only for known Lexicons.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validate: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRecordOutput {
+    pub cid: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<CommitMeta>,
+    pub uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation_status: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitMeta {
+    pub cid: String,
+    pub rev: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteRecordInput {
+    /// The NSID of the record collection.
+    pub collection: String,
+    /// The handle or DID of the repo (aka, current account).
+    pub repo: String,
+    /// The Record Key.
+    pub rkey: String,
+    /// Compare and swap with the previous commit by CID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub swap_commit: Option<String>,
+    /// Compare and swap with the previous record by CID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub swap_record: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteRecordOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<CommitMeta>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DescribeRepoParams {
+    /// The handle or DID of the repo.
+    pub repo: String,
+}
+
+impl DescribeRepoParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("repo", self.repo.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DescribeRepoOutput {
+    /// List of all the collections (NSIDs) for which this repo contains at least one record.
+    pub collections: Vec<String>,
+    pub did: String,
+    /// The complete DID document for this account.
+    pub did_doc: serde_json::Value,
+    pub handle: String,
+    /// Indicates if handle is currently valid (resolves bi-directionally)
+    pub handle_is_correct: bool,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetRecordParams {
+    /// The CID of the version of the record. If not specified, then return the most recent version.
+    pub cid: Option<String>,
+    /// The NSID of the record collection.
+    pub collection: String,
+    /// The handle or DID of the repo.
+    pub repo: String,
+    /// The Record Key.
+    pub rkey: String,
+}
+
+impl GetRecordParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cid {
+            query.push(("cid", value.to_string()));
+        }
+        query.push(("collection", self.collection.to_string()));
+        query.push(("repo", self.repo.to_string()));
+        query.push(("rkey", self.rkey.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRecordOutput<T = serde_json::Value> {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cid: Option<String>,
+    pub uri: String,
+    pub value: T,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ListMissingBlobsParams {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl ListMissingBlobsParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListMissingBlobsOutput {
+    pub blobs: Vec<ListMissingBlobsRecordBlob>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListMissingBlobsRecordBlob {
+    pub cid: String,
+    pub record_uri: String,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ListRecordsParams {
+    /// The NSID of the record type.
+    pub collection: String,
+    pub cursor: Option<String>,
+    /// The number of records to return.
+    pub limit: Option<i64>,
+    /// The handle or DID of the repo.
+    pub repo: String,
+    /// Flag to reverse the order of the returned records.
+    pub reverse: Option<bool>,
+}
+
+impl ListRecordsParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("collection", self.collection.to_string()));
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query.push(("repo", self.repo.to_string()));
+        if let Some(value) = &self.reverse {
+            query.push(("reverse", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListRecordsOutput<T = serde_json::Value> {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub records: Vec<ListRecordsRecord<T>>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListRecordsRecord<T = serde_json::Value> {
+    pub cid: String,
+    pub uri: String,
+    pub value: T,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PutRecordInput<T = serde_json::Value> {
+    /// The NSID of the record collection.
+    pub collection: String,
+    /// The record to write.
+    pub record: T,
+    /// The handle or DID of the repo (aka, current account).
+    pub repo: String,
+    /// The Record Key.
+    pub rkey: String,
+    /// Compare and swap with the previous commit by CID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub swap_commit: Option<String>,
+    /// Compare and swap with the previous record by CID. WARNING: nullable and optional field; may cause problems with golang implementation
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::lexicon::data::nullable"
+    )]
+    pub swap_record: Option<Option<String>>,
+    /// Can be set to 'false' to skip Lexicon schema validation of record data, 'true' to require it, or leave unset to validate only for known Lexicons.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validate: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PutRecordOutput {
+    pub cid: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<CommitMeta>,
+    pub uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation_status: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StrongRef {
+    pub cid: String,
+    pub uri: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadBlobOutput {
+    pub blob: crate::lexicon::Blob,
+}
+
+impl crate::atproto::Client {
+    ///com.atproto.repo.applyWrites
+    /// Apply a batch transaction of repository creates, updates, and deletes. Requires auth, implemented by PDS.
+    pub async fn com_atproto_repo_apply_writes(
+        &mut self,
+        input: &ApplyWritesInput,
+    ) -> Result<ApplyWritesOutput, crate::errors::BiskyError> {
+        crate::validation::RecordWrites::validate_writes(input, self.validate_records)?;
+        self.xrpc_post("com.atproto.repo.applyWrites", input).await
+    }
+
+    ///com.atproto.repo.createRecord
+    /// Create a single new repository record. Requires auth, implemented by PDS.
+    pub async fn com_atproto_repo_create_record<T: serde::Serialize>(
+        &mut self,
+        input: &CreateRecordInput<T>,
+    ) -> Result<CreateRecordOutput, crate::errors::BiskyError> {
+        crate::validation::RecordWrites::validate_writes(input, self.validate_records)?;
+        self.xrpc_post("com.atproto.repo.createRecord", input).await
+    }
+
+    ///com.atproto.repo.deleteRecord
+    /// Delete a repository record, or ensure it doesn't exist. Requires auth, implemented by PDS.
+    pub async fn com_atproto_repo_delete_record(
+        &mut self,
+        input: &DeleteRecordInput,
+    ) -> Result<DeleteRecordOutput, crate::errors::BiskyError> {
+        self.xrpc_post("com.atproto.repo.deleteRecord", input).await
+    }
+
+    ///com.atproto.repo.describeRepo
+    /// Get information about an account and repository, including the list of collections. Does not require auth.
+    pub async fn com_atproto_repo_describe_repo(
+        &mut self,
+        params: &DescribeRepoParams,
+    ) -> Result<DescribeRepoOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.repo.describeRepo", Some(&query))
+            .await
+    }
+
+    ///com.atproto.repo.getRecord
+    /// Get a single record from a repository. Does not require auth.
+    pub async fn com_atproto_repo_get_record<T: serde::de::DeserializeOwned + std::fmt::Debug>(
+        &mut self,
+        params: &GetRecordParams,
+    ) -> Result<GetRecordOutput<T>, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.repo.getRecord", Some(&query))
+            .await
+    }
+
+    ///com.atproto.repo.listMissingBlobs
+    /// Returns a list of missing blobs for the requesting account. Intended to be used in the account migration flow.
+    pub async fn com_atproto_repo_list_missing_blobs(
+        &mut self,
+        params: &ListMissingBlobsParams,
+    ) -> Result<ListMissingBlobsOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.repo.listMissingBlobs", Some(&query))
+            .await
+    }
+
+    ///com.atproto.repo.listRecords
+    /// List a range of records in a repository, matching a specific collection. Does not require auth.
+    pub async fn com_atproto_repo_list_records<T: serde::de::DeserializeOwned + std::fmt::Debug>(
+        &mut self,
+        params: &ListRecordsParams,
+    ) -> Result<ListRecordsOutput<T>, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.repo.listRecords", Some(&query))
+            .await
+    }
+
+    ///com.atproto.repo.putRecord
+    /// Write a repository record, creating or updating it as needed. Requires auth, implemented by PDS.
+    pub async fn com_atproto_repo_put_record<T: serde::Serialize>(
+        &mut self,
+        input: &PutRecordInput<T>,
+    ) -> Result<PutRecordOutput, crate::errors::BiskyError> {
+        crate::validation::RecordWrites::validate_writes(input, self.validate_records)?;
+        self.xrpc_post("com.atproto.repo.putRecord", input).await
+    }
+
+    ///com.atproto.repo.uploadBlob
+    /// Upload a new blob, to be referenced from a repository record. The blob will be deleted if it is not referenced within a time window (eg, minutes). Blob restrictions (mimetype, size, etc) are enforced when the reference is created. Requires auth, implemented by PDS.
+    pub async fn com_atproto_repo_upload_blob(
+        &mut self,
+        body: &[u8],
+        mime_type: &str,
+    ) -> Result<UploadBlobOutput, crate::errors::BiskyError> {
+        self.xrpc_post_binary("com.atproto.repo.uploadBlob", body, mime_type)
+            .await
+    }
+}
@@ -0,0 +1,653 @@
+//! com.atproto.server
+// @generated by `cargo xtask codegen`, do not edit
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckAccountStatusOutput {
+    pub activated: bool,
+    pub expected_blobs: i64,
+    pub imported_blobs: i64,
+    pub indexed_records: i64,
+    pub private_state_values: i64,
+    pub repo_blocks: i64,
+    pub repo_commit: String,
+    pub repo_rev: String,
+    pub valid_did: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfirmEmailInput {
+    pub email: String,
+    pub token: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateAccountInput {
+    /// Pre-existing atproto DID, being imported to a new account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub did: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Requested handle for the account.
+    pub handle: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invite_code: Option<String>,
+    /// Initial account password. May need to meet instance-specific password strength requirements.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    /// A signed DID PLC operation to be submitted as part of importing an existing account to this instance. NOTE: this optional field may be updated when full account migration is implemented.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plc_op: Option<serde_json::Value>,
+    /// DID PLC rotation key (aka, recovery key) to be included in PLC creation operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recovery_key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification_phone: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateAccountOutput {
+    pub access_jwt: String,
+    /// The DID of the new account.
+    pub did: String,
+    /// Complete DID document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub did_doc: Option<serde_json::Value>,
+    pub handle: String,
+    pub refresh_jwt: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateAppPasswordAppPassword {
+    pub created_at: crate::lexicon::Datetime,
+    pub name: String,
+    pub password: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub privileged: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateAppPasswordInput {
+    /// A short name for the App Password, to help distinguish them.
+    pub name: String,
+    /// If an app password has 'privileged' access to possibly sensitive account state. Meant for use with trusted clients.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub privileged: Option<bool>,
+}
+
+pub type CreateAppPasswordOutput = CreateAppPasswordAppPassword;
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateInviteCodeInput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub for_account: Option<String>,
+    pub use_count: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateInviteCodeOutput {
+    pub code: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateInviteCodesAccountCodes {
+    pub account: String,
+    pub codes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateInviteCodesInput {
+    pub code_count: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub for_accounts: Option<Vec<String>>,
+    pub use_count: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateInviteCodesOutput {
+    pub codes: Vec<CreateInviteCodesAccountCodes>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSessionInput {
+    /// When true, instead of throwing error for takendown accounts, a valid response with a narrow scoped token will be returned
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_takendown: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_factor_token: Option<String>,
+    /// Handle or other identifier supported by the server for the authenticating user.
+    pub identifier: String,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSessionOutput {
+    pub access_jwt: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    pub did: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub did_doc: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_auth_factor: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_confirmed: Option<bool>,
+    pub handle: String,
+    pub refresh_jwt: String,
+    /// If active=false, this optional field indicates a possible reason for why the account is not active. If active=false and no status is supplied, then the host makes no claim for why the repository is no longer being hosted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeactivateAccountInput {
+    /// A recommendation to server as to how long they should hold onto the deactivated account before deleting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delete_after: Option<crate::lexicon::Datetime>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InviteCode {
+    pub available: i64,
+    pub code: String,
+    pub created_at: crate::lexicon::Datetime,
+    pub created_by: String,
+    pub disabled: bool,
+    pub for_account: String,
+    pub uses: Vec<InviteCodeUse>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InviteCodeUse {
+    pub used_at: crate::lexicon::Datetime,
+    pub used_by: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteAccountInput {
+    pub did: String,
+    pub password: String,
+    pub token: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DescribeServerContact {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DescribeServerLinks {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub privacy_policy: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terms_of_service: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DescribeServerOutput {
+    /// List of domain suffixes that can be used in account handles.
+    pub available_user_domains: Vec<String>,
+    /// Contact information
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contact: Option<DescribeServerContact>,
+    pub did: String,
+    /// If true, an invite code must be supplied to create an account on this instance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invite_code_required: Option<bool>,
+    /// URLs of service policy documents.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub links: Option<DescribeServerLinks>,
+    /// If true, a phone verification token must be supplied to create an account on this instance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone_verification_required: Option<bool>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetAccountInviteCodesParams {
+    /// Controls whether any new 'earned' but not 'created' invites should be created.
+    pub create_available: Option<bool>,
+    pub include_used: Option<bool>,
+}
+
+impl GetAccountInviteCodesParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.create_available {
+            query.push(("createAvailable", value.to_string()));
+        }
+        if let Some(value) = &self.include_used {
+            query.push(("includeUsed", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAccountInviteCodesOutput {
+    pub codes: Vec<InviteCode>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetServiceAuthParams {
+    /// The DID or `did#serviceId` reference of the service that the token will be used to authenticate with.
+    pub aud: String,
+    /// The time in Unix Epoch seconds that the JWT expires. Defaults to 60 seconds in the future. The service may enforce certain time bounds on tokens depending on the requested scope.
+    pub exp: Option<i64>,
+    /// Lexicon (XRPC) method to bind the requested token to
+    pub lxm: Option<String>,
+}
+
+impl GetServiceAuthParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("aud", self.aud.to_string()));
+        if let Some(value) = &self.exp {
+            query.push(("exp", value.to_string()));
+        }
+        if let Some(value) = &self.lxm {
+            query.push(("lxm", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetServiceAuthOutput {
+    pub token: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSessionOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    pub did: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub did_doc: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_auth_factor: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_confirmed: Option<bool>,
+    pub handle: String,
+    /// If active=false, this optional field indicates a possible reason for why the account is not active. If active=false and no status is supplied, then the host makes no claim for why the repository is no longer being hosted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListAppPasswordsAppPassword {
+    pub created_at: crate::lexicon::Datetime,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub privileged: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListAppPasswordsOutput {
+    pub passwords: Vec<ListAppPasswordsAppPassword>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefreshSessionOutput {
+    pub access_jwt: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    pub did: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub did_doc: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_auth_factor: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_confirmed: Option<bool>,
+    pub handle: String,
+    pub refresh_jwt: String,
+    /// Hosting status of the account. If not specified, then assume 'active'.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestEmailUpdateOutput {
+    pub token_required: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestPasswordResetInput {
+    pub email: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReserveSigningKeyInput {
+    /// The DID to reserve a key for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub did: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReserveSigningKeyOutput {
+    /// The public key for the reserved signing key, in did:key serialization.
+    pub signing_key: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResetPasswordInput {
+    pub password: String,
+    pub token: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RevokeAppPasswordInput {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateEmailInput {
+    pub email: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_auth_factor: Option<bool>,
+    /// Requires a token from com.atproto.sever.requestEmailUpdate if the account's email has been confirmed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+}
+
+impl crate::atproto::Client {
+    ///com.atproto.server.activateAccount
+    /// Activates a currently deactivated account. Used to finalize account migration after the account's repo is imported and identity is setup.
+    pub async fn com_atproto_server_activate_account(
+        &mut self,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("com.atproto.server.activateAccount", &serde_json::json!({}))
+            .await
+    }
+
+    ///com.atproto.server.checkAccountStatus
+    /// Returns the status of an account, especially as pertaining to import or recovery. Can be called many times over the course of an account migration. Requires auth and can only be called pertaining to oneself.
+    pub async fn com_atproto_server_check_account_status(
+        &mut self,
+    ) -> Result<CheckAccountStatusOutput, crate::errors::BiskyError> {
+        self.xrpc_get("com.atproto.server.checkAccountStatus", None)
+            .await
+    }
+
+    ///com.atproto.server.confirmEmail
+    /// Confirm an email using a token from com.atproto.server.requestEmailConfirmation.
+    pub async fn com_atproto_server_confirm_email(
+        &mut self,
+        input: &ConfirmEmailInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("com.atproto.server.confirmEmail", input)
+            .await
+    }
+
+    ///com.atproto.server.createAccount
+    /// Create an account. Implemented by PDS.
+    pub async fn com_atproto_server_create_account(
+        &mut self,
+        input: &CreateAccountInput,
+    ) -> Result<CreateAccountOutput, crate::errors::BiskyError> {
+        self.xrpc_post("com.atproto.server.createAccount", input)
+            .await
+    }
+
+    ///com.atproto.server.createAppPassword
+    /// Create an App Password.
+    pub async fn com_atproto_server_create_app_password(
+        &mut self,
+        input: &CreateAppPasswordInput,
+    ) -> Result<CreateAppPasswordOutput, crate::errors::BiskyError> {
+        self.xrpc_post("com.atproto.server.createAppPassword", input)
+            .await
+    }
+
+    ///com.atproto.server.createInviteCode
+    /// Create an invite code.
+    pub async fn com_atproto_server_create_invite_code(
+        &mut self,
+        input: &CreateInviteCodeInput,
+    ) -> Result<CreateInviteCodeOutput, crate::errors::BiskyError> {
+        self.xrpc_post("com.atproto.server.createInviteCode", input)
+            .await
+    }
+
+    ///com.atproto.server.createInviteCodes
+    /// Create invite codes.
+    pub async fn com_atproto_server_create_invite_codes(
+        &mut self,
+        input: &CreateInviteCodesInput,
+    ) -> Result<CreateInviteCodesOutput, crate::errors::BiskyError> {
+        self.xrpc_post("com.atproto.server.createInviteCodes", input)
+            .await
+    }
+
+    ///com.atproto.server.createSession
+    /// Create an authentication session.
+    pub async fn com_atproto_server_create_session(
+        &mut self,
+        input: &CreateSessionInput,
+    ) -> Result<CreateSessionOutput, crate::errors::BiskyError> {
+        self.xrpc_post("com.atproto.server.createSession", input)
+            .await
+    }
+
+    ///com.atproto.server.deactivateAccount
+    /// Deactivates a currently active account. Stops serving of repo, and future writes to repo until reactivated. Used to finalize account migration with the old host after the account has been activated on the new host.
+    pub async fn com_atproto_server_deactivate_account(
+        &mut self,
+        input: &DeactivateAccountInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("com.atproto.server.deactivateAccount", input)
+            .await
+    }
+
+    ///com.atproto.server.deleteAccount
+    /// Delete an actor's account with a token and password. Can only be called after requesting a deletion token. Requires auth.
+    pub async fn com_atproto_server_delete_account(
+        &mut self,
+        input: &DeleteAccountInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("com.atproto.server.deleteAccount", input)
+            .await
+    }
+
+    ///com.atproto.server.deleteSession
+    /// Delete the current session. Requires auth using the 'refreshJwt' (not the 'accessJwt').
+    pub async fn com_atproto_server_delete_session(
+        &mut self,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("com.atproto.server.deleteSession", &serde_json::json!({}))
+            .await
+    }
+
+    ///com.atproto.server.describeServer
+    /// Describes the server's account creation requirements and capabilities. Implemented by PDS.
+    pub async fn com_atproto_server_describe_server(
+        &mut self,
+    ) -> Result<DescribeServerOutput, crate::errors::BiskyError> {
+        self.xrpc_get("com.atproto.server.describeServer", None)
+            .await
+    }
+
+    ///com.atproto.server.getAccountInviteCodes
+    /// Get all invite codes for the current account. Requires auth.
+    pub async fn com_atproto_server_get_account_invite_codes(
+        &mut self,
+        params: &GetAccountInviteCodesParams,
+    ) -> Result<GetAccountInviteCodesOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.server.getAccountInviteCodes", Some(&query))
+            .await
+    }
+
+    ///com.atproto.server.getServiceAuth
+    /// Get a signed token on behalf of the requesting DID for the requested service.
+    pub async fn com_atproto_server_get_service_auth(
+        &mut self,
+        params: &GetServiceAuthParams,
+    ) -> Result<GetServiceAuthOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.server.getServiceAuth", Some(&query))
+            .await
+    }
+
+    ///com.atproto.server.getSession
+    /// Get information about the current auth session. Requires auth.
+    pub async fn com_atproto_server_get_session(
+        &mut self,
+    ) -> Result<GetSessionOutput, crate::errors::BiskyError> {
+        self.xrpc_get("com.atproto.server.getSession", None).await
+    }
+
+    ///com.atproto.server.listAppPasswords
+    /// List all App Passwords.
+    pub async fn com_atproto_server_list_app_passwords(
+        &mut self,
+    ) -> Result<ListAppPasswordsOutput, crate::errors::BiskyError> {
+        self.xrpc_get("com.atproto.server.listAppPasswords", None)
+            .await
+    }
+
+    ///com.atproto.server.refreshSession
+    /// Refresh an authentication session. Requires auth using the 'refreshJwt' (not the 'accessJwt').
+    pub async fn com_atproto_server_refresh_session(
+        &mut self,
+    ) -> Result<RefreshSessionOutput, crate::errors::BiskyError> {
+        self.xrpc_post("com.atproto.server.refreshSession", &serde_json::json!({}))
+            .await
+    }
+
+    ///com.atproto.server.requestAccountDelete
+    /// Initiate a user account deletion via email.
+    pub async fn com_atproto_server_request_account_delete(
+        &mut self,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response(
+            "com.atproto.server.requestAccountDelete",
+            &serde_json::json!({}),
+        )
+        .await
+    }
+
+    ///com.atproto.server.requestEmailConfirmation
+    /// Request an email with a code to confirm ownership of email.
+    pub async fn com_atproto_server_request_email_confirmation(
+        &mut self,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response(
+            "com.atproto.server.requestEmailConfirmation",
+            &serde_json::json!({}),
+        )
+        .await
+    }
+
+    ///com.atproto.server.requestEmailUpdate
+    /// Request a token in order to update email.
+    pub async fn com_atproto_server_request_email_update(
+        &mut self,
+    ) -> Result<RequestEmailUpdateOutput, crate::errors::BiskyError> {
+        self.xrpc_post(
+            "com.atproto.server.requestEmailUpdate",
+            &serde_json::json!({}),
+        )
+        .await
+    }
+
+    ///com.atproto.server.requestPasswordReset
+    /// Initiate a user account password reset via email.
+    pub async fn com_atproto_server_request_password_reset(
+        &mut self,
+        input: &RequestPasswordResetInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("com.atproto.server.requestPasswordReset", input)
+            .await
+    }
+
+    ///com.atproto.server.reserveSigningKey
+    /// Reserve a repo signing key, for use with account creation. Necessary so that a DID PLC update operation can be constructed during an account migraiton. Public and does not require auth; implemented by PDS. NOTE: this endpoint may change when full account migration is implemented.
+    pub async fn com_atproto_server_reserve_signing_key(
+        &mut self,
+        input: &ReserveSigningKeyInput,
+    ) -> Result<ReserveSigningKeyOutput, crate::errors::BiskyError> {
+        self.xrpc_post("com.atproto.server.reserveSigningKey", input)
+            .await
+    }
+
+    ///com.atproto.server.resetPassword
+    /// Reset a user account password using a token.
+    pub async fn com_atproto_server_reset_password(
+        &mut self,
+        input: &ResetPasswordInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("com.atproto.server.resetPassword", input)
+            .await
+    }
+
+    ///com.atproto.server.revokeAppPassword
+    /// Revoke an App Password by name.
+    pub async fn com_atproto_server_revoke_app_password(
+        &mut self,
+        input: &RevokeAppPasswordInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("com.atproto.server.revokeAppPassword", input)
+            .await
+    }
+
+    ///com.atproto.server.updateEmail
+    /// Update an account's email.
+    pub async fn com_atproto_server_update_email(
+        &mut self,
+        input: &UpdateEmailInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("com.atproto.server.updateEmail", input)
+            .await
+    }
+}
@@ -0,0 +1,632 @@
+//! com.atproto.sync
+// @generated by `cargo xtask codegen`, do not edit
+
+pub type HostStatus = String;
+
+#[derive(Debug, Default, Clone)]
+pub struct GetBlobParams {
+    /// The CID of the blob to fetch
+    pub cid: String,
+    /// The DID of the account.
+    pub did: String,
+}
+
+impl GetBlobParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("cid", self.cid.to_string()));
+        query.push(("did", self.did.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetBlocksParams {
+    pub cids: Vec<String>,
+    /// The DID of the repo.
+    pub did: String,
+}
+
+impl GetBlocksParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        for value in &self.cids {
+            query.push(("cids", value.to_string()));
+        }
+        query.push(("did", self.did.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetCheckoutParams {
+    /// The DID of the repo.
+    pub did: String,
+}
+
+impl GetCheckoutParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("did", self.did.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetHeadParams {
+    /// The DID of the repo.
+    pub did: String,
+}
+
+impl GetHeadParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("did", self.did.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetHeadOutput {
+    pub root: String,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetHostStatusParams {
+    /// Hostname of the host (eg, PDS or relay) being queried.
+    pub hostname: String,
+}
+
+impl GetHostStatusParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("hostname", self.hostname.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetHostStatusOutput {
+    /// Number of accounts on the server which are associated with the upstream host. Note that the upstream may actually have more accounts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_count: Option<i64>,
+    pub hostname: String,
+    /// Recent repo stream event sequence number. May be delayed from actual stream processing (eg, persisted cursor not in-memory cursor).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seq: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<HostStatus>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetLatestCommitParams {
+    /// The DID of the repo.
+    pub did: String,
+}
+
+impl GetLatestCommitParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("did", self.did.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetLatestCommitOutput {
+    pub cid: String,
+    pub rev: String,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetRecordParams {
+    pub collection: String,
+    /// The DID of the repo.
+    pub did: String,
+    /// Record Key
+    pub rkey: String,
+}
+
+impl GetRecordParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("collection", self.collection.to_string()));
+        query.push(("did", self.did.to_string()));
+        query.push(("rkey", self.rkey.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetRepoParams {
+    /// The DID of the repo.
+    pub did: String,
+    /// The revision ('rev') of the repo to create a diff from.
+    pub since: Option<String>,
+}
+
+impl GetRepoParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("did", self.did.to_string()));
+        if let Some(value) = &self.since {
+            query.push(("since", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetRepoStatusParams {
+    /// The DID of the repo.
+    pub did: String,
+}
+
+impl GetRepoStatusParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("did", self.did.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRepoStatusOutput {
+    pub active: bool,
+    pub did: String,
+    /// Optional field, the current rev of the repo, if active=true
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rev: Option<String>,
+    /// If active=false, this optional field indicates a possible reason for why the account is not active. If active=false and no status is supplied, then the host makes no claim for why the repository is no longer being hosted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ListBlobsParams {
+    pub cursor: Option<String>,
+    /// The DID of the repo.
+    pub did: String,
+    pub limit: Option<i64>,
+    /// Optional revision of the repo to list blobs since.
+    pub since: Option<String>,
+}
+
+impl ListBlobsParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        query.push(("did", self.did.to_string()));
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        if let Some(value) = &self.since {
+            query.push(("since", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListBlobsOutput {
+    pub cids: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListHostsHost {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_count: Option<i64>,
+    /// hostname of server; not a URL (no scheme)
+    pub hostname: String,
+    /// Recent repo stream event sequence number. May be delayed from actual stream processing (eg, persisted cursor not in-memory cursor).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seq: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<HostStatus>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ListHostsParams {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl ListHostsParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListHostsOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// Sort order is not formally specified. Recommended order is by time host was first seen by the server, with oldest first.
+    pub hosts: Vec<ListHostsHost>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ListReposParams {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl ListReposParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListReposOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub repos: Vec<ListReposRepo>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListReposRepo {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    pub did: String,
+    /// Current repo commit CID
+    pub head: String,
+    pub rev: String,
+    /// If active=false, this optional field indicates a possible reason for why the account is not active. If active=false and no status is supplied, then the host makes no claim for why the repository is no longer being hosted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ListReposByCollectionParams {
+    pub collection: String,
+    pub cursor: Option<String>,
+    /// Maximum size of response set. Recommend setting a large maximum (1000+) when enumerating large DID lists.
+    pub limit: Option<i64>,
+}
+
+impl ListReposByCollectionParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("collection", self.collection.to_string()));
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListReposByCollectionOutput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub repos: Vec<ListReposByCollectionRepo>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListReposByCollectionRepo {
+    pub did: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyOfUpdateInput {
+    /// Hostname of the current service (usually a PDS) that is notifying of update.
+    pub hostname: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestCrawlInput {
+    /// Hostname of the current service (eg, PDS) that is requesting to be crawled.
+    pub hostname: String,
+}
+
+/// Represents a change to an account's status on a host (eg, PDS or Relay). The semantics of this event are that the status is at the host which emitted the event, not necessarily that at the currently active PDS. Eg, a Relay takedown would emit a takedown with active=false, even if the PDS is still active.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscribeReposAccount {
+    /// Indicates that the account has a repository which can be fetched from the host that emitted this event.
+    pub active: bool,
+    pub did: String,
+    pub seq: i64,
+    /// If active=false, this optional field indicates a reason for why the account is not active.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    pub time: crate::lexicon::Datetime,
+}
+
+/// Represents an update of repository state. Note that empty commits are allowed, which include no repo data changes, but an update to rev and signature.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscribeReposCommit {
+    pub blobs: Vec<crate::lexicon::CidLink>,
+    /// CAR file containing relevant blocks, as a diff since the previous repo state. The commit must be included as a block, and the commit block CID must be the first entry in the CAR header 'roots' list.
+    pub blocks: crate::lexicon::Bytes,
+    /// Repo commit object CID.
+    pub commit: crate::lexicon::CidLink,
+    pub ops: Vec<SubscribeReposRepoOp>,
+    /// The root CID of the MST tree for the previous commit from this repo (indicated by the 'since' revision field in this message). Corresponds to the 'data' field in the repo commit object. NOTE: this field is effectively required for the 'inductive' version of firehose.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prev_data: Option<crate::lexicon::CidLink>,
+    /// DEPRECATED -- unused
+    pub rebase: bool,
+    /// The repo this event comes from. Note that all other message types name this field 'did'.
+    pub repo: String,
+    /// The rev of the emitted commit. Note that this information is also in the commit object included in blocks, unless this is a tooBig event.
+    pub rev: String,
+    /// The stream sequence number of this message.
+    pub seq: i64,
+    /// The rev of the last emitted commit from this repo (if any).
+    #[serde(default)]
+    pub since: Option<String>,
+    /// Timestamp of when this message was originally broadcast.
+    pub time: crate::lexicon::Datetime,
+    /// DEPRECATED -- replaced by #sync event and data limits. Indicates that this commit contained too many ops, or data size was too large. Consumers will need to make a separate request to get missing data.
+    pub too_big: bool,
+}
+
+/// Represents a change to an account's identity. Could be an updated handle, signing key, or pds hosting endpoint. Serves as a prod to all downstream services to refresh their identity cache.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscribeReposIdentity {
+    pub did: String,
+    /// The current handle for the account, or 'handle.invalid' if validation fails. This field is optional, might have been validated or passed-through from an upstream source. Semantics and behaviors for PDS vs Relay may evolve in the future; see atproto specs for more details.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub handle: Option<String>,
+    pub seq: i64,
+    pub time: crate::lexicon::Datetime,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscribeReposInfo {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    pub name: String,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct SubscribeReposParams {
+    /// The last known event seq number to backfill from.
+    pub cursor: Option<i64>,
+}
+
+impl SubscribeReposParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.cursor {
+            query.push(("cursor", value.to_string()));
+        }
+        query
+    }
+}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum SubscribeReposMessage {
+        Commit(Box<SubscribeReposCommit>) = "com.atproto.sync.subscribeRepos#commit",
+        Sync(Box<SubscribeReposSync>) = "com.atproto.sync.subscribeRepos#sync",
+        Identity(Box<SubscribeReposIdentity>) = "com.atproto.sync.subscribeRepos#identity",
+        Account(Box<SubscribeReposAccount>) = "com.atproto.sync.subscribeRepos#account",
+        Info(Box<SubscribeReposInfo>) = "com.atproto.sync.subscribeRepos#info",
+    }
+}
+
+/// A repo operation, ie a mutation of a single record.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscribeReposRepoOp {
+    pub action: String,
+    /// For creates and updates, the new record CID. For deletions, null.
+    #[serde(default)]
+    pub cid: Option<crate::lexicon::CidLink>,
+    pub path: String,
+    /// For updates and deletes, the previous record CID (required for inductive firehose). For creations, field should not be defined.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prev: Option<crate::lexicon::CidLink>,
+}
+
+/// Updates the repo to a new state, without necessarily including that state on the firehose. Used to recover from broken commit streams, data loss incidents, or in situations where upstream host does not know recent state of the repository.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscribeReposSync {
+    /// CAR file containing the commit, as a block. The CAR header must include the commit block CID as the first 'root'.
+    pub blocks: crate::lexicon::Bytes,
+    /// The account this repo event corresponds to. Must match that in the commit object.
+    pub did: String,
+    /// The rev of the commit. This value must match that in the commit object.
+    pub rev: String,
+    /// The stream sequence number of this message.
+    pub seq: i64,
+    /// Timestamp of when this message was originally broadcast.
+    pub time: crate::lexicon::Datetime,
+}
+
+impl crate::atproto::Client {
+    ///com.atproto.sync.getHead
+    /// DEPRECATED - please use com.atproto.sync.getLatestCommit instead
+    pub async fn com_atproto_sync_get_head(
+        &mut self,
+        params: &GetHeadParams,
+    ) -> Result<GetHeadOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.sync.getHead", Some(&query))
+            .await
+    }
+
+    ///com.atproto.sync.getHostStatus
+    /// Returns information about a specified upstream host, as consumed by the server. Implemented by relays.
+    pub async fn com_atproto_sync_get_host_status(
+        &mut self,
+        params: &GetHostStatusParams,
+    ) -> Result<GetHostStatusOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.sync.getHostStatus", Some(&query))
+            .await
+    }
+
+    ///com.atproto.sync.getLatestCommit
+    /// Get the current commit CID & revision of the specified repo. Does not require auth.
+    pub async fn com_atproto_sync_get_latest_commit(
+        &mut self,
+        params: &GetLatestCommitParams,
+    ) -> Result<GetLatestCommitOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.sync.getLatestCommit", Some(&query))
+            .await
+    }
+
+    ///com.atproto.sync.getRepoStatus
+    /// Get the hosting status for a repository, on this server. Expected to be implemented by PDS and Relay.
+    pub async fn com_atproto_sync_get_repo_status(
+        &mut self,
+        params: &GetRepoStatusParams,
+    ) -> Result<GetRepoStatusOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.sync.getRepoStatus", Some(&query))
+            .await
+    }
+
+    ///com.atproto.sync.listBlobs
+    /// List blob CIDs for an account, since some repo revision. Does not require auth; implemented by PDS.
+    pub async fn com_atproto_sync_list_blobs(
+        &mut self,
+        params: &ListBlobsParams,
+    ) -> Result<ListBlobsOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.sync.listBlobs", Some(&query))
+            .await
+    }
+
+    ///com.atproto.sync.listHosts
+    /// Enumerates upstream hosts (eg, PDS or relay instances) that this service consumes from. Implemented by relays.
+    pub async fn com_atproto_sync_list_hosts(
+        &mut self,
+        params: &ListHostsParams,
+    ) -> Result<ListHostsOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.sync.listHosts", Some(&query))
+            .await
+    }
+
+    ///com.atproto.sync.listRepos
+    /// Enumerates all the DID, rev, and commit CID for all repos hosted by this service. Does not require auth; implemented by PDS and Relay.
+    pub async fn com_atproto_sync_list_repos(
+        &mut self,
+        params: &ListReposParams,
+    ) -> Result<ListReposOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.sync.listRepos", Some(&query))
+            .await
+    }
+
+    ///com.atproto.sync.listReposByCollection
+    /// Enumerates all the DIDs which have records with the given collection NSID.
+    pub async fn com_atproto_sync_list_repos_by_collection(
+        &mut self,
+        params: &ListReposByCollectionParams,
+    ) -> Result<ListReposByCollectionOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.sync.listReposByCollection", Some(&query))
+            .await
+    }
+
+    ///com.atproto.sync.notifyOfUpdate
+    /// Notify a crawling service of a recent update, and that crawling should resume. Intended use is after a gap between repo stream events caused the crawling service to disconnect. Does not require auth; implemented by Relay. DEPRECATED: just use com.atproto.sync.requestCrawl
+    pub async fn com_atproto_sync_notify_of_update(
+        &mut self,
+        input: &NotifyOfUpdateInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("com.atproto.sync.notifyOfUpdate", input)
+            .await
+    }
+
+    ///com.atproto.sync.requestCrawl
+    /// Request a service to persistently crawl hosted repos. Expected use is new PDS instances declaring their existence to Relays. Does not require auth.
+    pub async fn com_atproto_sync_request_crawl(
+        &mut self,
+        input: &RequestCrawlInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("com.atproto.sync.requestCrawl", input)
+            .await
+    }
+}
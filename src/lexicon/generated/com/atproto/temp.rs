@@ -0,0 +1,230 @@
+//! com.atproto.temp
+// @generated by `cargo xtask codegen`, do not edit
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddReservedHandleInput {
+    pub handle: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddReservedHandleOutput {}
+
+open_union! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum CheckHandleAvailabilityOutputResult {
+        ResultAvailable(Box<CheckHandleAvailabilityResultAvailable>) = "com.atproto.temp.checkHandleAvailability#resultAvailable",
+        ResultUnavailable(Box<CheckHandleAvailabilityResultUnavailable>) = "com.atproto.temp.checkHandleAvailability#resultUnavailable",
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CheckHandleAvailabilityParams {
+    /// User-provided birth date. Might be used to build handle suggestions.
+    pub birth_date: Option<String>,
+    /// User-provided email. Might be used to build handle suggestions.
+    pub email: Option<String>,
+    /// Tentative handle. Will be checked for availability or used to build handle suggestions.
+    pub handle: String,
+}
+
+impl CheckHandleAvailabilityParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.birth_date {
+            query.push(("birthDate", value.to_string()));
+        }
+        if let Some(value) = &self.email {
+            query.push(("email", value.to_string()));
+        }
+        query.push(("handle", self.handle.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckHandleAvailabilityOutput {
+    /// Echo of the input handle.
+    pub handle: String,
+    pub result: CheckHandleAvailabilityOutputResult,
+}
+
+/// Indicates the provided handle is available.
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckHandleAvailabilityResultAvailable {}
+
+/// Indicates the provided handle is unavailable and gives suggestions of available handles.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckHandleAvailabilityResultUnavailable {
+    /// List of suggested handles based on the provided inputs.
+    pub suggestions: Vec<CheckHandleAvailabilitySuggestion>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckHandleAvailabilitySuggestion {
+    pub handle: String,
+    /// Method used to build this suggestion. Should be considered opaque to clients. Can be used for metrics.
+    pub method: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckSignupQueueOutput {
+    pub activated: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimated_time_ms: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub place_in_queue: Option<i64>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DereferenceScopeParams {
+    /// The scope reference (starts with 'ref:')
+    pub scope: String,
+}
+
+impl DereferenceScopeParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        query.push(("scope", self.scope.to_string()));
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DereferenceScopeOutput {
+    /// The full oauth permission scope
+    pub scope: String,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct FetchLabelsParams {
+    pub limit: Option<i64>,
+    pub since: Option<i64>,
+}
+
+impl FetchLabelsParams {
+    /// Query string pairs, arrays repeat their key
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(value) = &self.limit {
+            query.push(("limit", value.to_string()));
+        }
+        if let Some(value) = &self.since {
+            query.push(("since", value.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FetchLabelsOutput {
+    pub labels: Vec<crate::lexicon::com::atproto::label::Label>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestPhoneVerificationInput {
+    pub phone_number: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RevokeAccountCredentialsInput {
+    pub account: String,
+}
+
+impl crate::atproto::Client {
+    ///com.atproto.temp.addReservedHandle
+    /// Add a handle to the set of reserved handles.
+    pub async fn com_atproto_temp_add_reserved_handle(
+        &mut self,
+        input: &AddReservedHandleInput,
+    ) -> Result<AddReservedHandleOutput, crate::errors::BiskyError> {
+        self.xrpc_post("com.atproto.temp.addReservedHandle", input)
+            .await
+    }
+
+    ///com.atproto.temp.checkHandleAvailability
+    /// Checks whether the provided handle is available. If the handle is not available, available suggestions will be returned. Optional inputs will be used to generate suggestions.
+    pub async fn com_atproto_temp_check_handle_availability(
+        &mut self,
+        params: &CheckHandleAvailabilityParams,
+    ) -> Result<CheckHandleAvailabilityOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.temp.checkHandleAvailability", Some(&query))
+            .await
+    }
+
+    ///com.atproto.temp.checkSignupQueue
+    /// Check accounts location in signup queue.
+    pub async fn com_atproto_temp_check_signup_queue(
+        &mut self,
+    ) -> Result<CheckSignupQueueOutput, crate::errors::BiskyError> {
+        self.xrpc_get("com.atproto.temp.checkSignupQueue", None)
+            .await
+    }
+
+    ///com.atproto.temp.dereferenceScope
+    /// Allows finding the oauth permission scope from a reference
+    pub async fn com_atproto_temp_dereference_scope(
+        &mut self,
+        params: &DereferenceScopeParams,
+    ) -> Result<DereferenceScopeOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.temp.dereferenceScope", Some(&query))
+            .await
+    }
+
+    ///com.atproto.temp.fetchLabels
+    /// DEPRECATED: use queryLabels or subscribeLabels instead -- Fetch all labels from a labeler created after a certain date.
+    pub async fn com_atproto_temp_fetch_labels(
+        &mut self,
+        params: &FetchLabelsParams,
+    ) -> Result<FetchLabelsOutput, crate::errors::BiskyError> {
+        let query = params.query();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.xrpc_get("com.atproto.temp.fetchLabels", Some(&query))
+            .await
+    }
+
+    ///com.atproto.temp.requestPhoneVerification
+    /// Request a verification code to be sent to the supplied phone number
+    pub async fn com_atproto_temp_request_phone_verification(
+        &mut self,
+        input: &RequestPhoneVerificationInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("com.atproto.temp.requestPhoneVerification", input)
+            .await
+    }
+
+    ///com.atproto.temp.revokeAccountCredentials
+    /// Revoke sessions, password, and app passwords associated with account. May be resolved by a password reset.
+    pub async fn com_atproto_temp_revoke_account_credentials(
+        &mut self,
+        input: &RevokeAccountCredentialsInput,
+    ) -> Result<(), crate::errors::BiskyError> {
+        self.xrpc_post_no_response("com.atproto.temp.revokeAccountCredentials", input)
+            .await
+    }
+}
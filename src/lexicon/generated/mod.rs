@@ -0,0 +1,33 @@
+//! Types and XRPC methods generated from the lexicon schemas in `lexicons/`
+// @generated by `cargo xtask codegen`, do not edit
+#![allow(clippy::doc_lazy_continuation, clippy::enum_variant_names)]
+#![allow(clippy::vec_init_then_push)]
+
+pub mod app;
+pub mod com;
+pub(crate) mod schemas;
+
+open_union! {
+    /// A record of any collection in the bundled lexicons
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum KnownRecord {
+        Profile(Box<app::bsky::actor::Profile>) = "app.bsky.actor.profile",
+        Status(Box<app::bsky::actor::Status>) = "app.bsky.actor.status",
+        Generator(Box<app::bsky::feed::Generator>) = "app.bsky.feed.generator",
+        Like(Box<app::bsky::feed::Like>) = "app.bsky.feed.like",
+        Post(Box<app::bsky::feed::Post>) = "app.bsky.feed.post",
+        Postgate(Box<app::bsky::feed::Postgate>) = "app.bsky.feed.postgate",
+        Repost(Box<app::bsky::feed::Repost>) = "app.bsky.feed.repost",
+        Threadgate(Box<app::bsky::feed::Threadgate>) = "app.bsky.feed.threadgate",
+        Block(Box<app::bsky::graph::Block>) = "app.bsky.graph.block",
+        Follow(Box<app::bsky::graph::Follow>) = "app.bsky.graph.follow",
+        List(Box<app::bsky::graph::List>) = "app.bsky.graph.list",
+        Listblock(Box<app::bsky::graph::Listblock>) = "app.bsky.graph.listblock",
+        Listitem(Box<app::bsky::graph::Listitem>) = "app.bsky.graph.listitem",
+        Starterpack(Box<app::bsky::graph::Starterpack>) = "app.bsky.graph.starterpack",
+        Verification(Box<app::bsky::graph::Verification>) = "app.bsky.graph.verification",
+        Service(Box<app::bsky::labeler::Service>) = "app.bsky.labeler.service",
+        Declaration(Box<app::bsky::notification::Declaration>) = "app.bsky.notification.declaration",
+        Schema(Box<com::atproto::lexicon::Schema>) = "com.atproto.lexicon.schema",
+    }
+}
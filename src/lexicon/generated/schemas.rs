@@ -0,0 +1,259 @@
+//! Lexicon documents bundled for [`crate::validation::Validator`]
+// @generated by `cargo xtask codegen`, do not edit
+
+pub(crate) const LEXICONS: &[&str] = &[
+    include_str!("../../../lexicons/app/bsky/actor/defs.json"),
+    include_str!("../../../lexicons/app/bsky/actor/getPreferences.json"),
+    include_str!("../../../lexicons/app/bsky/actor/getProfile.json"),
+    include_str!("../../../lexicons/app/bsky/actor/getProfiles.json"),
+    include_str!("../../../lexicons/app/bsky/actor/getSuggestions.json"),
+    include_str!("../../../lexicons/app/bsky/actor/profile.json"),
+    include_str!("../../../lexicons/app/bsky/actor/putPreferences.json"),
+    include_str!("../../../lexicons/app/bsky/actor/searchActors.json"),
+    include_str!("../../../lexicons/app/bsky/actor/searchActorsTypeahead.json"),
+    include_str!("../../../lexicons/app/bsky/actor/status.json"),
+    include_str!("../../../lexicons/app/bsky/ageassurance/begin.json"),
+    include_str!("../../../lexicons/app/bsky/ageassurance/defs.json"),
+    include_str!("../../../lexicons/app/bsky/ageassurance/getConfig.json"),
+    include_str!("../../../lexicons/app/bsky/ageassurance/getState.json"),
+    include_str!("../../../lexicons/app/bsky/authCreatePosts.json"),
+    include_str!("../../../lexicons/app/bsky/authDeleteContent.json"),
+    include_str!("../../../lexicons/app/bsky/authFullApp.json"),
+    include_str!("../../../lexicons/app/bsky/authManageFeedDeclarations.json"),
+    include_str!("../../../lexicons/app/bsky/authManageLabelerService.json"),
+    include_str!("../../../lexicons/app/bsky/authManageModeration.json"),
+    include_str!("../../../lexicons/app/bsky/authManageNotifications.json"),
+    include_str!("../../../lexicons/app/bsky/authManageProfile.json"),
+    include_str!("../../../lexicons/app/bsky/authViewAll.json"),
+    include_str!("../../../lexicons/app/bsky/bookmark/createBookmark.json"),
+    include_str!("../../../lexicons/app/bsky/bookmark/defs.json"),
+    include_str!("../../../lexicons/app/bsky/bookmark/deleteBookmark.json"),
+    include_str!("../../../lexicons/app/bsky/bookmark/getBookmarks.json"),
+    include_str!("../../../lexicons/app/bsky/contact/defs.json"),
+    include_str!("../../../lexicons/app/bsky/contact/dismissMatch.json"),
+    include_str!("../../../lexicons/app/bsky/contact/getMatches.json"),
+    include_str!("../../../lexicons/app/bsky/contact/getSyncStatus.json"),
+    include_str!("../../../lexicons/app/bsky/contact/importContacts.json"),
+    include_str!("../../../lexicons/app/bsky/contact/removeData.json"),
+    include_str!("../../../lexicons/app/bsky/contact/sendNotification.json"),
+    include_str!("../../../lexicons/app/bsky/contact/startPhoneVerification.json"),
+    include_str!("../../../lexicons/app/bsky/contact/verifyPhone.json"),
+    include_str!("../../../lexicons/app/bsky/draft/createDraft.json"),
+    include_str!("../../../lexicons/app/bsky/draft/defs.json"),
+    include_str!("../../../lexicons/app/bsky/draft/deleteDraft.json"),
+    include_str!("../../../lexicons/app/bsky/draft/getDrafts.json"),
+    include_str!("../../../lexicons/app/bsky/draft/updateDraft.json"),
+    include_str!("../../../lexicons/app/bsky/embed/defs.json"),
+    include_str!("../../../lexicons/app/bsky/embed/external.json"),
+    include_str!("../../../lexicons/app/bsky/embed/gallery.json"),
+    include_str!("../../../lexicons/app/bsky/embed/getEmbedExternalView.json"),
+    include_str!("../../../lexicons/app/bsky/embed/images.json"),
+    include_str!("../../../lexicons/app/bsky/embed/record.json"),
+    include_str!("../../../lexicons/app/bsky/embed/recordWithMedia.json"),
+    include_str!("../../../lexicons/app/bsky/embed/video.json"),
+    include_str!("../../../lexicons/app/bsky/feed/defs.json"),
+    include_str!("../../../lexicons/app/bsky/feed/describeFeedGenerator.json"),
+    include_str!("../../../lexicons/app/bsky/feed/generator.json"),
+    include_str!("../../../lexicons/app/bsky/feed/getActorFeeds.json"),
+    include_str!("../../../lexicons/app/bsky/feed/getActorLikes.json"),
+    include_str!("../../../lexicons/app/bsky/feed/getAuthorFeed.json"),
+    include_str!("../../../lexicons/app/bsky/feed/getFeed.json"),
+    include_str!("../../../lexicons/app/bsky/feed/getFeedGenerator.json"),
+    include_str!("../../../lexicons/app/bsky/feed/getFeedGenerators.json"),
+    include_str!("../../../lexicons/app/bsky/feed/getFeedSkeleton.json"),
+    include_str!("../../../lexicons/app/bsky/feed/getLikes.json"),
+    include_str!("../../../lexicons/app/bsky/feed/getListFeed.json"),
+    include_str!("../../../lexicons/app/bsky/feed/getPostThread.json"),
+    include_str!("../../../lexicons/app/bsky/feed/getPosts.json"),
+    include_str!("../../../lexicons/app/bsky/feed/getQuotes.json"),
+    include_str!("../../../lexicons/app/bsky/feed/getRepostedBy.json"),
+    include_str!("../../../lexicons/app/bsky/feed/getSuggestedFeeds.json"),
+    include_str!("../../../lexicons/app/bsky/feed/getTimeline.json"),
+    include_str!("../../../lexicons/app/bsky/feed/like.json"),
+    include_str!("../../../lexicons/app/bsky/feed/post.json"),
+    include_str!("../../../lexicons/app/bsky/feed/postgate.json"),
+    include_str!("../../../lexicons/app/bsky/feed/repost.json"),
+    include_str!("../../../lexicons/app/bsky/feed/searchPosts.json"),
+    include_str!("../../../lexicons/app/bsky/feed/searchPostsV2.json"),
+    include_str!("../../../lexicons/app/bsky/feed/sendInteractions.json"),
+    include_str!("../../../lexicons/app/bsky/feed/threadgate.json"),
+    include_str!("../../../lexicons/app/bsky/graph/block.json"),
+    include_str!("../../../lexicons/app/bsky/graph/defs.json"),
+    include_str!("../../../lexicons/app/bsky/graph/follow.json"),
+    include_str!("../../../lexicons/app/bsky/graph/getActorStarterPacks.json"),
+    include_str!("../../../lexicons/app/bsky/graph/getBlocks.json"),
+    include_str!("../../../lexicons/app/bsky/graph/getFollowers.json"),
+    include_str!("../../../lexicons/app/bsky/graph/getFollows.json"),
+    include_str!("../../../lexicons/app/bsky/graph/getKnownFollowers.json"),
+    include_str!("../../../lexicons/app/bsky/graph/getList.json"),
+    include_str!("../../../lexicons/app/bsky/graph/getListBlocks.json"),
+    include_str!("../../../lexicons/app/bsky/graph/getListMutes.json"),
+    include_str!("../../../lexicons/app/bsky/graph/getLists.json"),
+    include_str!("../../../lexicons/app/bsky/graph/getListsWithMembership.json"),
+    include_str!("../../../lexicons/app/bsky/graph/getMutes.json"),
+    include_str!("../../../lexicons/app/bsky/graph/getRelationships.json"),
+    include_str!("../../../lexicons/app/bsky/graph/getStarterPack.json"),
+    include_str!("../../../lexicons/app/bsky/graph/getStarterPacks.json"),
+    include_str!("../../../lexicons/app/bsky/graph/getStarterPacksWithMembership.json"),
+    include_str!("../../../lexicons/app/bsky/graph/getSuggestedFollowsByActor.json"),
+    include_str!("../../../lexicons/app/bsky/graph/list.json"),
+    include_str!("../../../lexicons/app/bsky/graph/listblock.json"),
+    include_str!("../../../lexicons/app/bsky/graph/listitem.json"),
+    include_str!("../../../lexicons/app/bsky/graph/muteActor.json"),
+    include_str!("../../../lexicons/app/bsky/graph/muteActorList.json"),
+    include_str!("../../../lexicons/app/bsky/graph/muteThread.json"),
+    include_str!("../../../lexicons/app/bsky/graph/searchStarterPacks.json"),
+    include_str!("../../../lexicons/app/bsky/graph/starterpack.json"),
+    include_str!("../../../lexicons/app/bsky/graph/unmuteActor.json"),
+    include_str!("../../../lexicons/app/bsky/graph/unmuteActorList.json"),
+    include_str!("../../../lexicons/app/bsky/graph/unmuteThread.json"),
+    include_str!("../../../lexicons/app/bsky/graph/verification.json"),
+    include_str!("../../../lexicons/app/bsky/labeler/defs.json"),
+    include_str!("../../../lexicons/app/bsky/labeler/getServices.json"),
+    include_str!("../../../lexicons/app/bsky/labeler/service.json"),
+    include_str!("../../../lexicons/app/bsky/notification/declaration.json"),
+    include_str!("../../../lexicons/app/bsky/notification/defs.json"),
+    include_str!("../../../lexicons/app/bsky/notification/getPreferences.json"),
+    include_str!("../../../lexicons/app/bsky/notification/getUnreadCount.json"),
+    include_str!("../../../lexicons/app/bsky/notification/listActivitySubscriptions.json"),
+    include_str!("../../../lexicons/app/bsky/notification/listNotifications.json"),
+    include_str!("../../../lexicons/app/bsky/notification/putActivitySubscription.json"),
+    include_str!("../../../lexicons/app/bsky/notification/putPreferences.json"),
+    include_str!("../../../lexicons/app/bsky/notification/putPreferencesV2.json"),
+    include_str!("../../../lexicons/app/bsky/notification/registerPush.json"),
+    include_str!("../../../lexicons/app/bsky/notification/unregisterPush.json"),
+    include_str!("../../../lexicons/app/bsky/notification/updateSeen.json"),
+    include_str!("../../../lexicons/app/bsky/richtext/facet.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/defs.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/getAgeAssuranceState.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/getConfig.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/getOnboardingSuggestedStarterPacks.json"),
+    include_str!(
+        "../../../lexicons/app/bsky/unspecced/getOnboardingSuggestedStarterPacksSkeleton.json"
+    ),
+    include_str!("../../../lexicons/app/bsky/unspecced/getOnboardingSuggestedUsersSkeleton.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/getPopularFeedGenerators.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/getPostThreadOtherV2.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/getPostThreadV2.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/getSuggestedFeeds.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/getSuggestedFeedsSkeleton.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/getSuggestedOnboardingUsers.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/getSuggestedStarterPacks.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/getSuggestedStarterPacksSkeleton.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/getSuggestedUsers.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/getSuggestedUsersForDiscover.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/getSuggestedUsersForDiscoverSkeleton.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/getSuggestedUsersForExplore.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/getSuggestedUsersForExploreSkeleton.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/getSuggestedUsersForSeeMore.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/getSuggestedUsersForSeeMoreSkeleton.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/getSuggestedUsersSkeleton.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/getSuggestionsSkeleton.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/getTaggedSuggestions.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/getTrendingTopics.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/getTrends.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/getTrendsSkeleton.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/initAgeAssurance.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/searchActorsSkeleton.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/searchPostsSkeleton.json"),
+    include_str!("../../../lexicons/app/bsky/unspecced/searchStarterPacksSkeleton.json"),
+    include_str!("../../../lexicons/app/bsky/video/defs.json"),
+    include_str!("../../../lexicons/app/bsky/video/getJobStatus.json"),
+    include_str!("../../../lexicons/app/bsky/video/getUploadLimits.json"),
+    include_str!("../../../lexicons/app/bsky/video/uploadVideo.json"),
+    include_str!("../../../lexicons/com/atproto/admin/defs.json"),
+    include_str!("../../../lexicons/com/atproto/admin/deleteAccount.json"),
+    include_str!("../../../lexicons/com/atproto/admin/disableAccountInvites.json"),
+    include_str!("../../../lexicons/com/atproto/admin/disableInviteCodes.json"),
+    include_str!("../../../lexicons/com/atproto/admin/enableAccountInvites.json"),
+    include_str!("../../../lexicons/com/atproto/admin/getAccountInfo.json"),
+    include_str!("../../../lexicons/com/atproto/admin/getAccountInfos.json"),
+    include_str!("../../../lexicons/com/atproto/admin/getInviteCodes.json"),
+    include_str!("../../../lexicons/com/atproto/admin/getSubjectStatus.json"),
+    include_str!("../../../lexicons/com/atproto/admin/searchAccounts.json"),
+    include_str!("../../../lexicons/com/atproto/admin/sendEmail.json"),
+    include_str!("../../../lexicons/com/atproto/admin/updateAccountEmail.json"),
+    include_str!("../../../lexicons/com/atproto/admin/updateAccountHandle.json"),
+    include_str!("../../../lexicons/com/atproto/admin/updateAccountPassword.json"),
+    include_str!("../../../lexicons/com/atproto/admin/updateAccountSigningKey.json"),
+    include_str!("../../../lexicons/com/atproto/admin/updateSubjectStatus.json"),
+    include_str!("../../../lexicons/com/atproto/identity/defs.json"),
+    include_str!("../../../lexicons/com/atproto/identity/getRecommendedDidCredentials.json"),
+    include_str!("../../../lexicons/com/atproto/identity/refreshIdentity.json"),
+    include_str!("../../../lexicons/com/atproto/identity/requestPlcOperationSignature.json"),
+    include_str!("../../../lexicons/com/atproto/identity/resolveDid.json"),
+    include_str!("../../../lexicons/com/atproto/identity/resolveHandle.json"),
+    include_str!("../../../lexicons/com/atproto/identity/resolveIdentity.json"),
+    include_str!("../../../lexicons/com/atproto/identity/signPlcOperation.json"),
+    include_str!("../../../lexicons/com/atproto/identity/submitPlcOperation.json"),
+    include_str!("../../../lexicons/com/atproto/identity/updateHandle.json"),
+    include_str!("../../../lexicons/com/atproto/label/defs.json"),
+    include_str!("../../../lexicons/com/atproto/label/queryLabels.json"),
+    include_str!("../../../lexicons/com/atproto/label/subscribeLabels.json"),
+    include_str!("../../../lexicons/com/atproto/lexicon/resolveLexicon.json"),
+    include_str!("../../../lexicons/com/atproto/lexicon/schema.json"),
+    include_str!("../../../lexicons/com/atproto/moderation/createReport.json"),
+    include_str!("../../../lexicons/com/atproto/moderation/defs.json"),
+    include_str!("../../../lexicons/com/atproto/repo/applyWrites.json"),
+    include_str!("../../../lexicons/com/atproto/repo/createRecord.json"),
+    include_str!("../../../lexicons/com/atproto/repo/defs.json"),
+    include_str!("../../../lexicons/com/atproto/repo/deleteRecord.json"),
+    include_str!("../../../lexicons/com/atproto/repo/describeRepo.json"),
+    include_str!("../../../lexicons/com/atproto/repo/getRecord.json"),
+    include_str!("../../../lexicons/com/atproto/repo/importRepo.json"),
+    include_str!("../../../lexicons/com/atproto/repo/listMissingBlobs.json"),
+    include_str!("../../../lexicons/com/atproto/repo/listRecords.json"),
+    include_str!("../../../lexicons/com/atproto/repo/putRecord.json"),
+    include_str!("../../../lexicons/com/atproto/repo/strongRef.json"),
+    include_str!("../../../lexicons/com/atproto/repo/uploadBlob.json"),
+    include_str!("../../../lexicons/com/atproto/server/activateAccount.json"),
+    include_str!("../../../lexicons/com/atproto/server/checkAccountStatus.json"),
+    include_str!("../../../lexicons/com/atproto/server/confirmEmail.json"),
+    include_str!("../../../lexicons/com/atproto/server/createAccount.json"),
+    include_str!("../../../lexicons/com/atproto/server/createAppPassword.json"),
+    include_str!("../../../lexicons/com/atproto/server/createInviteCode.json"),
+    include_str!("../../../lexicons/com/atproto/server/createInviteCodes.json"),
+    include_str!("../../../lexicons/com/atproto/server/createSession.json"),
+    include_str!("../../../lexicons/com/atproto/server/deactivateAccount.json"),
+    include_str!("../../../lexicons/com/atproto/server/defs.json"),
+    include_str!("../../../lexicons/com/atproto/server/deleteAccount.json"),
+    include_str!("../../../lexicons/com/atproto/server/deleteSession.json"),
+    include_str!("../../../lexicons/com/atproto/server/describeServer.json"),
+    include_str!("../../../lexicons/com/atproto/server/getAccountInviteCodes.json"),
+    include_str!("../../../lexicons/com/atproto/server/getServiceAuth.json"),
+    include_str!("../../../lexicons/com/atproto/server/getSession.json"),
+    include_str!("../../../lexicons/com/atproto/server/listAppPasswords.json"),
+    include_str!("../../../lexicons/com/atproto/server/refreshSession.json"),
+    include_str!("../../../lexicons/com/atproto/server/requestAccountDelete.json"),
+    include_str!("../../../lexicons/com/atproto/server/requestEmailConfirmation.json"),
+    include_str!("../../../lexicons/com/atproto/server/requestEmailUpdate.json"),
+    include_str!("../../../lexicons/com/atproto/server/requestPasswordReset.json"),
+    include_str!("../../../lexicons/com/atproto/server/reserveSigningKey.json"),
+    include_str!("../../../lexicons/com/atproto/server/resetPassword.json"),
+    include_str!("../../../lexicons/com/atproto/server/revokeAppPassword.json"),
+    include_str!("../../../lexicons/com/atproto/server/updateEmail.json"),
+    include_str!("../../../lexicons/com/atproto/sync/defs.json"),
+    include_str!("../../../lexicons/com/atproto/sync/getBlob.json"),
+    include_str!("../../../lexicons/com/atproto/sync/getBlocks.json"),
+    include_str!("../../../lexicons/com/atproto/sync/getCheckout.json"),
+    include_str!("../../../lexicons/com/atproto/sync/getHead.json"),
+    include_str!("../../../lexicons/com/atproto/sync/getHostStatus.json"),
+    include_str!("../../../lexicons/com/atproto/sync/getLatestCommit.json"),
+    include_str!("../../../lexicons/com/atproto/sync/getRecord.json"),
+    include_str!("../../../lexicons/com/atproto/sync/getRepo.json"),
+    include_str!("../../../lexicons/com/atproto/sync/getRepoStatus.json"),
+    include_str!("../../../lexicons/com/atproto/sync/listBlobs.json"),
+    include_str!("../../../lexicons/com/atproto/sync/listHosts.json"),
+    include_str!("../../../lexicons/com/atproto/sync/listRepos.json"),
+    include_str!("../../../lexicons/com/atproto/sync/listReposByCollection.json"),
+    include_str!("../../../lexicons/com/atproto/sync/notifyOfUpdate.json"),
+    include_str!("../../../lexicons/com/atproto/sync/requestCrawl.json"),
+    include_str!("../../../lexicons/com/atproto/sync/subscribeRepos.json"),
+    include_str!("../../../lexicons/com/atproto/temp/addReservedHandle.json"),
+    include_str!("../../../lexicons/com/atproto/temp/checkHandleAvailability.json"),
+    include_str!("../../../lexicons/com/atproto/temp/checkSignupQueue.json"),
+    include_str!("../../../lexicons/com/atproto/temp/dereferenceScope.json"),
+    include_str!("../../../lexicons/com/atproto/temp/fetchLabels.json"),
+    include_str!("../../../lexicons/com/atproto/temp/requestPhoneVerification.json"),
+    include_str!("../../../lexicons/com/atproto/temp/revokeAccountCredentials.json"),
+];
@@ -0,0 +1,42 @@
+//! Constructors for generated types
+use super::app::bsky::feed::{Postgate, Threadgate};
+use super::com::atproto::label::{SelfLabel, SelfLabels};
+use super::Datetime;
+use serde_json::Map;
+
+impl Threadgate {
+    /// A gate on the post at `post` that lets everyone reply
+    pub fn new(post: &str) -> Self {
+        Self {
+            post: post.to_string(),
+            allow: None,
+            hidden_replies: None,
+            created_at: Datetime::now(),
+            extra: Map::new(),
+        }
+    }
+}
+
+impl Postgate {
+    /// A gate on the post at `post` that lets everyone quote it
+    pub fn new(post: &str) -> Self {
+        Self {
+            post: post.to_string(),
+            detached_embedding_uris: None,
+            embedding_rules: None,
+            created_at: Datetime::now(),
+            extra: Map::new(),
+        }
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for SelfLabels {
+    fn from_iter<I: IntoIterator<Item = S>>(values: I) -> Self {
+        Self {
+            values: values
+                .into_iter()
+                .map(|val| SelfLabel { val: val.into() })
+                .collect(),
+        }
+    }
+}
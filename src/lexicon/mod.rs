@@ -1,2 +1,81 @@
-pub mod app;
-pub mod com;
+/// Declare an open union, a `$type` tagged enum whose unrecognised members deserialize
+/// into `Unknown` instead of failing the whole response
+macro_rules! open_union {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $( $(#[$variant_meta:meta])* $variant:ident($inner:ty) = $tag:literal, )*
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $( $(#[$variant_meta])* $variant($inner), )*
+            /// A member this version of bisky does not know about
+            Unknown {
+                r#type: String,
+                raw: serde_json::Value,
+            },
+        }
+
+        impl $name {
+            /// The `$type` of this member
+            pub fn type_name(&self) -> &str {
+                match self {
+                    $( Self::$variant(_) => $tag, )*
+                    Self::Unknown { r#type, .. } => r#type,
+                }
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                use serde::de::Error;
+
+                let mut raw = serde_json::Value::deserialize(deserializer)?;
+                let r#type = raw
+                    .get("$type")
+                    .and_then(serde_json::Value::as_str)
+                    .unwrap_or_default()
+                    .to_string();
+                match r#type.as_str() {
+                    $( $tag => {
+                        if let Some(map) = raw.as_object_mut() {
+                            map.remove("$type");
+                        }
+                        serde_json::from_value(raw)
+                            .map(Self::$variant)
+                            .map_err(D::Error::custom)
+                    } )*
+                    _ => Ok(Self::Unknown { r#type, raw }),
+                }
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use serde::ser::Error;
+
+                let value = match self {
+                    $( Self::$variant(inner) => {
+                        let mut value = serde_json::to_value(inner).map_err(S::Error::custom)?;
+                        if let Some(map) = value.as_object_mut() {
+                            map.insert("$type".to_string(), $tag.into());
+                        }
+                        value
+                    } )*
+                    Self::Unknown { raw, .. } => raw.clone(),
+                };
+                value.serialize(serializer)
+            }
+        }
+    };
+}
+
+mod data;
+mod datetime;
+pub(crate) mod generated;
+mod impls;
+
+pub use data::{Blob, Bytes, CidLink, Extra};
+pub use datetime::Datetime;
+pub use generated::{app, com, KnownRecord};
@@ -1,5 +1,20 @@
 pub mod atproto;
 pub mod bluesky;
+pub mod car;
+pub mod composer;
+pub mod crypto;
+pub mod did;
 pub mod errors;
+pub mod firehose;
+pub mod jetstream;
+pub mod labels;
+#[cfg(feature = "lang-detect")]
+pub mod langdetect;
 pub mod lexicon;
+pub mod linkcard;
+pub mod repo;
+pub mod richtext;
 pub mod storage;
+pub mod subscription;
+pub mod tid;
+pub mod validation;
@@ -0,0 +1,315 @@
+//! External link cards: fetch a page, read its OpenGraph and Twitter card tags and turn
+//! them into an `app.bsky.embed.external` with an uploaded thumbnail
+use crate::atproto::Client;
+use crate::errors::BiskyError;
+use crate::lexicon::app::bsky::embed::{External, ExternalExternal};
+use crate::lexicon::app::bsky::feed::PostEmbed;
+use crate::lexicon::com::atproto::repo::UploadBlobOutput;
+use crate::lexicon::Blob;
+use async_trait::async_trait;
+use miette::Diagnostic;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Largest thumbnail `app.bsky.embed.external` accepts, in bytes
+pub const MAX_THUMB_SIZE: usize = 1_000_000;
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum LinkCardError {
+    #[error("{url} is not an HTML page ({content_type})")]
+    NotHtml { url: String, content_type: String },
+}
+
+/// A fetched resource
+#[derive(Debug, Clone)]
+pub struct Fetched {
+    /// URL after redirects, relative image URLs are resolved against it
+    pub url: String,
+    pub content_type: Option<String>,
+    pub body: Vec<u8>,
+}
+
+/// How link cards get pages and images, swap it out to add caching, proxies or test fixtures
+#[async_trait]
+pub trait Fetcher: Send + Sync {
+    async fn fetch(&self, url: &str) -> Result<Fetched, BiskyError>;
+}
+
+/// Fetches over HTTP with reqwest, failing on error statuses
+#[derive(Debug, Clone)]
+pub struct ReqwestFetcher {
+    client: reqwest::Client,
+}
+
+impl ReqwestFetcher {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+impl Default for ReqwestFetcher {
+    fn default() -> Self {
+        let client = reqwest::Client::builder()
+            .user_agent(concat!("bisky/", env!("CARGO_PKG_VERSION")))
+            .timeout(Duration::from_secs(10))
+            .build()
+            .expect("reqwest client builds with static settings");
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl Fetcher for ReqwestFetcher {
+    async fn fetch(&self, url: &str) -> Result<Fetched, BiskyError> {
+        let response = self.client.get(url).send().await?.error_for_status()?;
+        let url = response.url().to_string();
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let body = response.bytes().await?.to_vec();
+        Ok(Fetched {
+            url,
+            content_type,
+            body,
+        })
+    }
+}
+
+/// Preview metadata of a page
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LinkCard {
+    pub uri: String,
+    pub title: String,
+    pub description: String,
+    /// Absolute URL of the preview image
+    pub image: Option<String>,
+}
+
+impl LinkCard {
+    /// Fetch `url` and read its card
+    pub async fn fetch<F: Fetcher + ?Sized>(fetcher: &F, url: &str) -> Result<Self, BiskyError> {
+        let page = fetcher.fetch(url).await?;
+        let content_type = page.content_type.clone().unwrap_or_default();
+        let mime_type = content_type.split(';').next().unwrap_or_default().trim();
+        if !mime_type.eq_ignore_ascii_case("text/html")
+            && !mime_type.eq_ignore_ascii_case("application/xhtml+xml")
+        {
+            return Err(LinkCardError::NotHtml {
+                url: page.url,
+                content_type,
+            }
+            .into());
+        }
+
+        let mut card = Self::parse(&page.url, &String::from_utf8_lossy(&page.body));
+        // Keep the link the author wrote, not where it redirected to
+        card.uri = url.to_string();
+        Ok(card)
+    }
+
+    /// Read the OpenGraph, Twitter card and plain HTML metadata of a page at `url`
+    pub fn parse(url: &str, html: &str) -> Self {
+        let tags = meta_tags(html);
+        let find = |keys: &[&str]| {
+            keys.iter().find_map(|key| {
+                tags.iter()
+                    .find(|(name, content)| name.eq_ignore_ascii_case(key) && !content.is_empty())
+                    .map(|(_, content)| content.clone())
+            })
+        };
+
+        let title = find(&["og:title", "twitter:title"])
+            .or_else(|| title_tag(html))
+            .unwrap_or_default();
+        let description =
+            find(&["og:description", "twitter:description", "description"]).unwrap_or_default();
+        let image = find(&[
+            "og:image:secure_url",
+            "og:image",
+            "og:image:url",
+            "twitter:image",
+            "twitter:image:src",
+        ])
+        .and_then(|image| {
+            reqwest::Url::parse(url)
+                .and_then(|base| base.join(&image))
+                .ok()
+        })
+        .map(|image| image.to_string());
+
+        Self {
+            uri: url.to_string(),
+            title,
+            description,
+            image,
+        }
+    }
+
+    /// Download the preview image and upload it as the card's thumbnail. Images that fail
+    /// to download, are not images or are over [`MAX_THUMB_SIZE`] leave the card without one
+    pub async fn into_embed<F: Fetcher + ?Sized>(
+        self,
+        client: &mut Client,
+        fetcher: &F,
+    ) -> Result<PostEmbed, BiskyError> {
+        let thumb = match &self.image {
+            Some(image) => upload_thumb(client, fetcher, image).await?,
+            None => None,
+        };
+        Ok(PostEmbed::External(Box::new(External {
+            external: ExternalExternal {
+                uri: self.uri,
+                title: self.title,
+                description: self.description,
+                thumb,
+                associated_refs: None,
+            },
+        })))
+    }
+}
+
+async fn upload_thumb<F: Fetcher + ?Sized>(
+    client: &mut Client,
+    fetcher: &F,
+    url: &str,
+) -> Result<Option<Blob>, BiskyError> {
+    let Ok(image) = fetcher.fetch(url).await else {
+        return Ok(None);
+    };
+    let mime_type = image
+        .content_type
+        .as_deref()
+        .and_then(|content_type| content_type.split(';').next())
+        .map(str::trim)
+        .unwrap_or_default();
+    if !mime_type.starts_with("image/") || image.body.len() > MAX_THUMB_SIZE {
+        return Ok(None);
+    }
+    let output: UploadBlobOutput = client.repo_upload_blob(&image.body, mime_type).await?;
+    Ok(Some(output.blob))
+}
+
+/// `(name or property, content)` of every `<meta>` tag
+fn meta_tags(html: &str) -> Vec<(String, String)> {
+    let lower = html.to_ascii_lowercase();
+    let mut tags = Vec::new();
+    let mut from = 0;
+    while let Some(start) = lower[from..].find("<meta").map(|i| from + i) {
+        let end = tag_end(html, start);
+        let attributes = attributes(&html[start + "<meta".len()..end]);
+        let get = |key: &str| {
+            attributes
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(key))
+                .map(|(_, value)| value.clone())
+        };
+        if let (Some(name), Some(content)) =
+            (get("property").or_else(|| get("name")), get("content"))
+        {
+            tags.push((name, decode_entities(content.trim())));
+        }
+        from = end;
+    }
+    tags
+}
+
+/// Byte offset of the `>` closing the tag at `start`, skipping any inside quoted values
+fn tag_end(html: &str, start: usize) -> usize {
+    let mut quote = None;
+    for (i, c) in html[start..].char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(open), c) if c == open => quote = None,
+            (None, '>') => return start + i,
+            _ => {}
+        }
+    }
+    html.len()
+}
+
+/// Attributes of a tag, values unquoted
+fn attributes(tag: &str) -> Vec<(String, String)> {
+    let mut attributes = Vec::new();
+    let mut rest = tag.trim_start_matches(|c: char| c.is_whitespace() || c == '/');
+    while !rest.is_empty() {
+        let name_end = rest
+            .find(|c: char| c.is_whitespace() || c == '=' || c == '/')
+            .unwrap_or(rest.len());
+        let name = rest[..name_end].to_string();
+        rest = rest[name_end..].trim_start();
+
+        let mut value = String::new();
+        if let Some(after) = rest.strip_prefix('=') {
+            let after = after.trim_start();
+            let (parsed, remaining) = match after.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let close = after[1..].find(quote).map_or(after.len(), |i| i + 1);
+                    (&after[1..close], after.get(close + 1..).unwrap_or_default())
+                }
+                _ => {
+                    let end = after.find(char::is_whitespace).unwrap_or(after.len());
+                    (&after[..end], &after[end..])
+                }
+            };
+            value = parsed.to_string();
+            rest = remaining;
+        }
+        if !name.is_empty() {
+            attributes.push((name, value));
+        }
+        rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == '/');
+    }
+    attributes
+}
+
+fn title_tag(html: &str) -> Option<String> {
+    let lower = html.to_ascii_lowercase();
+    let start = lower.find("<title")?;
+    let start = start + lower[start..].find('>')? + 1;
+    let end = start + lower[start..].find("</title")?;
+    let title = decode_entities(html[start..end].trim());
+    (!title.is_empty()).then_some(title)
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest[1..]
+            .find(';')
+            .filter(|end| *end <= 10)
+            .map(|end| &rest[1..end + 1]);
+        let character = entity.and_then(|entity| match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some('\u{a0}'),
+            _ => {
+                let number = entity.strip_prefix('#')?;
+                let code = match number.strip_prefix(['x', 'X']) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                    None => number.parse().ok()?,
+                };
+                char::from_u32(code)
+            }
+        });
+        match (entity, character) {
+            (Some(entity), Some(character)) => {
+                decoded.push(character);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
@@ -0,0 +1,313 @@
+//! Repository decoding: the signed commit and the Merkle Search Tree (MST) of records under it
+use crate::car::{BlockStore, CarError, Cid};
+use crate::crypto::CryptoError;
+use crate::did::DidDocument;
+use crate::lexicon::app::bsky::feed::{Like, Post, Repost};
+use crate::lexicon::app::bsky::graph::Follow;
+use crate::lexicon::com::atproto::repo::ListRecordsRecord;
+use base64::Engine;
+use ipld_core::ipld::Ipld;
+use miette::Diagnostic;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum RepoError {
+    #[error(transparent)]
+    CarError(#[from] CarError),
+    #[error("CAR has no root commit")]
+    MissingRoot,
+    #[error("Invalid MST Key: {0}")]
+    InvalidKey(String),
+    #[error(transparent)]
+    CryptoError(#[from] CryptoError),
+    #[error("DAG-CBOR Encode Error: {0}")]
+    EncodeError(String),
+}
+
+///Signed repo commit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Commit {
+    pub did: String,
+    pub version: u64,
+    pub data: Cid,
+    pub rev: String,
+    pub prev: Option<Cid>,
+    #[serde(with = "serde_bytes")]
+    pub sig: Vec<u8>,
+}
+
+/// Commit fields covered by the signature
+#[derive(Serialize)]
+struct UnsignedCommit<'a> {
+    did: &'a str,
+    version: u64,
+    data: &'a Cid,
+    rev: &'a str,
+    prev: &'a Option<Cid>,
+}
+
+/// Outcome of checking a commit signature against a DID document
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitVerification {
+    /// Signed by the `#atproto` key of the commit's DID
+    Valid,
+    /// The signature does not match the `#atproto` key
+    Invalid,
+    /// The document is for a different DID than the commit, or has no `#atproto` key
+    KeyMismatch,
+}
+
+impl Commit {
+    /// DAG-CBOR encoding of the commit without `sig`, the bytes that get signed
+    pub fn unsigned_bytes(&self) -> Result<Vec<u8>, RepoError> {
+        serde_ipld_dagcbor::to_vec(&UnsignedCommit {
+            did: &self.did,
+            version: self.version,
+            data: &self.data,
+            rev: &self.rev,
+            prev: &self.prev,
+        })
+        .map_err(|e| RepoError::EncodeError(e.to_string()))
+    }
+
+    /// Verify `sig` with the `#atproto` verification method of `did_document`
+    pub fn verify(&self, did_document: &DidDocument) -> Result<CommitVerification, RepoError> {
+        if did_document.id != self.did {
+            return Ok(CommitVerification::KeyMismatch);
+        }
+        let Some(key) = did_document.signing_key() else {
+            return Ok(CommitVerification::KeyMismatch);
+        };
+
+        match key?.verify(&self.unsigned_bytes()?, &self.sig) {
+            true => Ok(CommitVerification::Valid),
+            false => Ok(CommitVerification::Invalid),
+        }
+    }
+}
+
+///MST node as stored in a block
+#[derive(Debug, Clone, Deserialize)]
+pub struct NodeData {
+    pub l: Option<Cid>,
+    pub e: Vec<TreeEntry>,
+}
+
+///MST node entry, `k` is the key suffix after the first `p` bytes of the previous key
+#[derive(Debug, Clone, Deserialize)]
+pub struct TreeEntry {
+    pub p: usize,
+    #[serde(with = "serde_bytes")]
+    pub k: Vec<u8>,
+    pub v: Cid,
+    pub t: Option<Cid>,
+}
+
+/// Record value, typed for the collections bisky knows and raw for everything else
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum RepoRecord {
+    Post(Post),
+    Like(Like),
+    Repost(Repost),
+    Follow(Follow),
+    Raw(Value),
+}
+
+impl RepoRecord {
+    /// Pick the lexicon type from the collection NSID, falling back to `Raw` if it does not decode
+    pub fn from_json(collection: &str, value: Value) -> Self {
+        fn typed<T: DeserializeOwned>(value: &Value, f: fn(T) -> RepoRecord) -> Option<RepoRecord> {
+            T::deserialize(value).ok().map(f)
+        }
+
+        let record = match collection {
+            "app.bsky.feed.post" => typed(&value, RepoRecord::Post),
+            "app.bsky.feed.like" => typed(&value, RepoRecord::Like),
+            "app.bsky.feed.repost" => typed(&value, RepoRecord::Repost),
+            "app.bsky.graph.follow" => typed(&value, RepoRecord::Follow),
+            _ => None,
+        };
+        record.unwrap_or(RepoRecord::Raw(value))
+    }
+}
+
+/// A record found while walking the MST
+#[derive(Debug)]
+pub struct RepoEntry {
+    pub collection: String,
+    pub rkey: String,
+    pub cid: Cid,
+    pub record: RepoRecord,
+}
+
+/// Convert a DAG-CBOR value into the atproto JSON representation,
+/// links become `{"$link": cid}` and bytes become `{"$bytes": base64}`
+pub fn ipld_to_json(ipld: Ipld) -> Value {
+    match ipld {
+        Ipld::Null => Value::Null,
+        Ipld::Bool(b) => Value::Bool(b),
+        Ipld::Integer(i) => i64::try_from(i)
+            .map(Value::from)
+            .or_else(|_| u64::try_from(i).map(Value::from))
+            .unwrap_or_else(|_| Value::String(i.to_string())),
+        Ipld::Float(f) => Value::from(f),
+        Ipld::String(s) => Value::String(s),
+        Ipld::Bytes(b) => serde_json::json!({
+            "$bytes": base64::engine::general_purpose::STANDARD_NO_PAD.encode(b)
+        }),
+        Ipld::List(l) => Value::Array(l.into_iter().map(ipld_to_json).collect()),
+        Ipld::Map(m) => Value::Object(m.into_iter().map(|(k, v)| (k, ipld_to_json(v))).collect()),
+        Ipld::Link(cid) => serde_json::json!({ "$link": cid.to_string() }),
+    }
+}
+
+/// A repo read out of a block store, rooted at its signed commit
+pub struct Repo<S> {
+    store: S,
+    commit_cid: Cid,
+    commit: Commit,
+}
+
+impl<S: BlockStore + Send> Repo<S> {
+    /// Open the repo at `root`, the commit CID from the CAR header
+    pub async fn open(mut store: S, root: &Cid) -> Result<Self, RepoError> {
+        let commit = store.get::<Commit>(root).await?;
+        Ok(Self {
+            store,
+            commit_cid: *root,
+            commit,
+        })
+    }
+
+    pub fn commit(&self) -> &Commit {
+        &self.commit
+    }
+
+    pub fn commit_cid(&self) -> &Cid {
+        &self.commit_cid
+    }
+
+    pub fn did(&self) -> &str {
+        &self.commit.did
+    }
+
+    /// Walk every record in key order
+    pub fn walk(&mut self) -> RepoWalk<'_, S> {
+        RepoWalk {
+            stack: Vec::from([Walk::Node(self.commit.data)]),
+            store: &mut self.store,
+        }
+    }
+
+    /// Collect every record of one collection, decoded as `T`
+    pub async fn records<T: DeserializeOwned>(
+        &mut self,
+        collection: &str,
+    ) -> Result<Vec<ListRecordsRecord<T>>, RepoError> {
+        self.try_records(collection).await?.into_iter().collect()
+    }
+
+    /// Like [`Repo::records`], but a record that does not decode as `T` is returned as its
+    /// error rather than failing the whole collection
+    pub async fn try_records<T: DeserializeOwned>(
+        &mut self,
+        collection: &str,
+    ) -> Result<Vec<Result<ListRecordsRecord<T>, RepoError>>, RepoError> {
+        let did = self.commit.did.clone();
+        let mut records = Vec::new();
+        let mut walk = self.walk();
+
+        while let Some((key, cid)) = walk.next_key().await? {
+            let Some((key_collection, rkey)) = key.split_once('/') else {
+                return Err(RepoError::InvalidKey(key));
+            };
+            if key_collection != collection {
+                continue;
+            }
+
+            let value = ipld_to_json(walk.store.get::<Ipld>(&cid).await?);
+            records.push(
+                serde_json::from_value(value)
+                    .map(|value| ListRecordsRecord {
+                        uri: format!("at://{did}/{collection}/{rkey}"),
+                        cid: cid.to_string(),
+                        value,
+                    })
+                    .map_err(|e| CarError::DecodeError(e.to_string()).into()),
+            );
+        }
+
+        Ok(records)
+    }
+}
+
+enum Walk {
+    Node(Cid),
+    Leaf(String, Cid),
+}
+
+/// In-order traversal of the MST, only one node is loaded at a time
+pub struct RepoWalk<'a, S> {
+    store: &'a mut S,
+    stack: Vec<Walk>,
+}
+
+impl<S: BlockStore + Send> RepoWalk<'_, S> {
+    /// Next `collection/rkey` key and the CID of its record, without loading the record
+    pub async fn next_key(&mut self) -> Result<Option<(String, Cid)>, RepoError> {
+        while let Some(item) = self.stack.pop() {
+            match item {
+                Walk::Leaf(key, cid) => return Ok(Some((key, cid))),
+                Walk::Node(cid) => {
+                    let node = self.store.get::<NodeData>(&cid).await?;
+
+                    let mut items = Vec::new();
+                    if let Some(left) = node.l {
+                        items.push(Walk::Node(left));
+                    }
+                    let mut key: Vec<u8> = Vec::new();
+                    for entry in node.e {
+                        if entry.p > key.len() {
+                            return Err(RepoError::InvalidKey(format!(
+                                "prefix length {} longer than previous key",
+                                entry.p
+                            )));
+                        }
+                        key.truncate(entry.p);
+                        key.extend_from_slice(&entry.k);
+                        let full_key = String::from_utf8(key.clone())
+                            .map_err(|e| RepoError::InvalidKey(e.to_string()))?;
+                        items.push(Walk::Leaf(full_key, entry.v));
+                        if let Some(right) = entry.t {
+                            items.push(Walk::Node(right));
+                        }
+                    }
+                    self.stack.extend(items.into_iter().rev());
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Next record, decoded via the lexicon types where possible
+    pub async fn next(&mut self) -> Result<Option<RepoEntry>, RepoError> {
+        let Some((key, cid)) = self.next_key().await? else {
+            return Ok(None);
+        };
+        let Some((collection, rkey)) = key.split_once('/') else {
+            return Err(RepoError::InvalidKey(key));
+        };
+
+        let value = ipld_to_json(self.store.get::<Ipld>(&cid).await?);
+        Ok(Some(RepoEntry {
+            record: RepoRecord::from_json(collection, value),
+            collection: collection.to_string(),
+            rkey: rkey.to_string(),
+            cid,
+        }))
+    }
+}
@@ -0,0 +1,474 @@
+//! Rich text: detect mentions, links and hashtags in post text as `app.bsky.richtext.facet`s,
+//! and render text with facets as HTML, Markdown or segments
+use crate::atproto::Client;
+use crate::errors::BiskyError;
+use crate::lexicon::app::bsky::feed::Post;
+use crate::lexicon::app::bsky::richtext::{
+    Facet, FacetByteSlice, FacetFeature, FacetLink, FacetMention, FacetTag,
+};
+use crate::lexicon::Datetime;
+use crate::validation::is_handle;
+use std::collections::HashMap;
+
+/// Longest hashtag the app accepts, in characters
+const MAX_TAG_LENGTH: usize = 64;
+/// Punctuation that ends a sentence rather than a URL
+const TRAILING_PUNCTUATION: [char; 6] = ['.', ',', ';', ':', '!', '?'];
+/// TLDs besides two letter country codes that bare domains like `example.com` are linked for
+const BARE_DOMAIN_TLDS: &[&str] = &[
+    "app", "art", "blog", "com", "dev", "edu", "gov", "info", "net", "news", "online", "org",
+    "page", "site", "social", "tech", "xyz",
+];
+
+/// Post text with the facets found in it.
+///
+/// Links and tags are detected by [`RichText::new`]; mentions need the author's handle
+/// resolved to a DID first, see [`RichText::resolve_mentions`]
+#[derive(Debug, Clone, Default)]
+pub struct RichText {
+    text: String,
+    facets: Vec<Facet>,
+    /// `@handle` mentions waiting for a DID
+    mentions: Vec<(FacetByteSlice, String)>,
+}
+
+impl RichText {
+    pub fn new(text: impl Into<String>) -> Self {
+        let text = text.into();
+        let mut facets = Vec::new();
+        let mut mentions = Vec::new();
+
+        // Byte offset up to which text already belongs to a facet
+        let mut consumed = 0;
+        for (start, c) in text.char_indices() {
+            if start < consumed {
+                continue;
+            }
+            let previous = text[..start].chars().next_back();
+            let after_space = previous.into_iter().all(char::is_whitespace);
+            if !after_space && previous != Some('(') {
+                continue;
+            }
+            let word_end = text[start..]
+                .find(char::is_whitespace)
+                .map_or(text.len(), |end| start + end);
+            let word = &text[start..word_end];
+
+            let detected = match c {
+                '@' => mention(word).map(|handle| {
+                    let end = start + 1 + handle.len();
+                    mentions.push((slice(start, end), handle.to_string()));
+                    end
+                }),
+                '#' | '＃' if after_space => tag(word).map(|(tag, len)| {
+                    facets.push(facet(
+                        start,
+                        start + len,
+                        FacetFeature::Tag(Box::new(FacetTag { tag })),
+                    ));
+                    start + len
+                }),
+                _ => link(word).map(|(uri, len)| {
+                    facets.push(facet(
+                        start,
+                        start + len,
+                        FacetFeature::Link(Box::new(FacetLink { uri })),
+                    ));
+                    start + len
+                }),
+            };
+            if let Some(end) = detected {
+                consumed = end;
+            }
+        }
+
+        Self {
+            text,
+            facets,
+            mentions,
+        }
+    }
+
+    /// Text with facets that are already known, e.g. from an existing post
+    pub fn with_facets(text: impl Into<String>, facets: Vec<Facet>) -> Self {
+        Self {
+            text: text.into(),
+            facets,
+            mentions: Vec::new(),
+        }
+    }
+
+    /// Resolve every `@handle` to a DID and add its mention facet. Handles that do not
+    /// resolve stay plain text
+    pub async fn resolve_mentions(mut self, client: &mut Client) -> Result<Self, BiskyError> {
+        let mut dids: HashMap<String, Option<String>> = HashMap::new();
+        for (index, handle) in std::mem::take(&mut self.mentions) {
+            let did = match dids.get(&handle) {
+                Some(did) => did.clone(),
+                None => {
+                    let did = match client.identity_resolve_handle(&handle).await {
+                        Ok(did) => Some(did),
+                        Err(BiskyError::ApiError(_)) => None,
+                        Err(e) => return Err(e),
+                    };
+                    dids.insert(handle, did.clone());
+                    did
+                }
+            };
+            if let Some(did) = did {
+                self.facets.push(Facet {
+                    index,
+                    features: vec![FacetFeature::Mention(Box::new(FacetMention { did }))],
+                });
+            }
+        }
+        self.facets.sort_by_key(|facet| facet.index.byte_start);
+        Ok(self)
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Facets in text order, without unresolved mentions
+    pub fn facets(&self) -> &[Facet] {
+        &self.facets
+    }
+
+    /// Handles of the mentions [`RichText::resolve_mentions`] has yet to resolve
+    pub fn unresolved_mentions(&self) -> impl Iterator<Item = &str> {
+        self.mentions.iter().map(|(_, handle)| handle.as_str())
+    }
+
+    pub fn into_parts(self) -> (String, Vec<Facet>) {
+        (self.text, self.facets)
+    }
+
+    /// A new post dated now
+    pub fn into_post(self) -> Post {
+        Post {
+            created_at: Datetime::now(),
+            text: self.text,
+            facets: (!self.facets.is_empty()).then_some(self.facets),
+            embed: None,
+            entities: None,
+            reply: None,
+            langs: None,
+            labels: None,
+            tags: None,
+            extra: Default::default(),
+        }
+    }
+
+    /// The text split at its facets. Facets that are out of bounds, not on character
+    /// boundaries or overlap an earlier one are ignored, so text from any client renders
+    pub fn segments(&self) -> Segments<'_> {
+        let mut facets: Vec<&Facet> = self
+            .facets
+            .iter()
+            .filter(|facet| {
+                let FacetByteSlice {
+                    byte_start,
+                    byte_end,
+                } = facet.index;
+                byte_start < byte_end
+                    && self.text.is_char_boundary(byte_start)
+                    && byte_end <= self.text.len()
+                    && self.text.is_char_boundary(byte_end)
+            })
+            .collect();
+        facets.sort_by_key(|facet| facet.index.byte_start);
+        let mut end = 0;
+        facets.retain(|facet| {
+            let keep = facet.index.byte_start >= end;
+            if keep {
+                end = facet.index.byte_end;
+            }
+            keep
+        });
+
+        Segments {
+            text: &self.text,
+            facets: facets.into_iter(),
+            position: 0,
+            pending: None,
+        }
+    }
+
+    /// HTML with the text escaped, newlines as `<br>`, and links, mentions and tags as anchors
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        for segment in self.segments() {
+            let text = escape_html(segment.text).replace('\n', "<br>");
+            match segment.href() {
+                Some(href) => {
+                    let href = escape_html(&href);
+                    html.push_str(&format!("<a href=\"{href}\">{text}</a>"));
+                }
+                None => html.push_str(&text),
+            }
+        }
+        html
+    }
+
+    /// Markdown with the text escaped and links, mentions and tags as inline links
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+        for segment in self.segments() {
+            let text = escape_markdown(segment.text);
+            match segment.href() {
+                Some(href) => {
+                    let href = href
+                        .replace(' ', "%20")
+                        .replace('(', "%28")
+                        .replace(')', "%29");
+                    markdown.push_str(&format!("[{text}]({href})"));
+                }
+                None => markdown.push_str(&text),
+            }
+        }
+        markdown
+    }
+}
+
+impl From<&Post> for RichText {
+    fn from(post: &Post) -> Self {
+        Self::with_facets(post.text.clone(), post.facets.clone().unwrap_or_default())
+    }
+}
+
+/// A run of text that is either plain or covered by exactly one facet
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Segment<'a> {
+    pub text: &'a str,
+    pub facet: Option<&'a Facet>,
+}
+
+impl<'a> Segment<'a> {
+    /// First feature bisky understands, facets with only unknown features render as plain text
+    pub fn feature(&self) -> Option<&'a FacetFeature> {
+        self.facet?
+            .features
+            .iter()
+            .find(|feature| !matches!(feature, FacetFeature::Unknown { .. }))
+    }
+
+    pub fn link(&self) -> Option<&'a str> {
+        match self.feature()? {
+            FacetFeature::Link(link) => Some(&link.uri),
+            _ => None,
+        }
+    }
+
+    pub fn mention(&self) -> Option<&'a str> {
+        match self.feature()? {
+            FacetFeature::Mention(mention) => Some(&mention.did),
+            _ => None,
+        }
+    }
+
+    pub fn tag(&self) -> Option<&'a str> {
+        match self.feature()? {
+            FacetFeature::Tag(tag) => Some(&tag.tag),
+            _ => None,
+        }
+    }
+
+    /// Where the segment links to: http(s) links as is, mentions and tags on bsky.app
+    pub fn href(&self) -> Option<String> {
+        match self.feature()? {
+            FacetFeature::Link(link) => {
+                let scheme = link.uri.split_once(':').map(|(scheme, _)| scheme);
+                scheme
+                    .is_some_and(|scheme| {
+                        scheme.eq_ignore_ascii_case("https") || scheme.eq_ignore_ascii_case("http")
+                    })
+                    .then(|| link.uri.clone())
+            }
+            FacetFeature::Mention(mention) => {
+                Some(format!("https://bsky.app/profile/{}", mention.did))
+            }
+            FacetFeature::Tag(tag) => Some(format!(
+                "https://bsky.app/hashtag/{}",
+                percent_encode(&tag.tag)
+            )),
+            FacetFeature::Unknown { .. } => None,
+        }
+    }
+}
+
+/// Iterator over the [`Segment`]s of a [`RichText`]
+pub struct Segments<'a> {
+    text: &'a str,
+    facets: std::vec::IntoIter<&'a Facet>,
+    position: usize,
+    /// Facet to emit after the plain text before it
+    pending: Option<&'a Facet>,
+}
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = Segment<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let facet = self.pending.take().or_else(|| self.facets.next());
+        let Some(facet) = facet else {
+            let text = &self.text[self.position..];
+            self.position = self.text.len();
+            return (!text.is_empty()).then_some(Segment { text, facet: None });
+        };
+
+        let FacetByteSlice {
+            byte_start,
+            byte_end,
+        } = facet.index;
+        if self.position < byte_start {
+            let text = &self.text[self.position..byte_start];
+            self.position = byte_start;
+            self.pending = Some(facet);
+            return Some(Segment { text, facet: None });
+        }
+        self.position = byte_end;
+        Some(Segment {
+            text: &self.text[byte_start..byte_end],
+            facet: Some(facet),
+        })
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '|' | '~'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+fn percent_encode(text: &str) -> String {
+    let mut encoded = String::new();
+    for byte in text.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+fn slice(byte_start: usize, byte_end: usize) -> FacetByteSlice {
+    FacetByteSlice {
+        byte_start,
+        byte_end,
+    }
+}
+
+fn facet(start: usize, end: usize, feature: FacetFeature) -> Facet {
+    Facet {
+        index: slice(start, end),
+        features: vec![feature],
+    }
+}
+
+/// Handle of `@handle.example.com.`, without the `@` and trailing punctuation
+fn mention(word: &str) -> Option<&str> {
+    let rest = &word[1..];
+    let end = rest
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '.' || c == '-'))
+        .unwrap_or(rest.len());
+    let handle = rest[..end].trim_end_matches(['.', '-']);
+    is_handle(handle).then_some(handle)
+}
+
+/// Hashtag of `#word`, without the `#`, and the byte length of the facet
+fn tag(word: &str) -> Option<(String, usize)> {
+    let marker = word.chars().next()?.len_utf8();
+    let body = &word[marker..];
+    let end = body
+        .find(|c: char| {
+            matches!(
+                c,
+                '\u{ad}' | '\u{2060}' | '\u{200a}'..='\u{200d}' | '\u{20e2}'
+            )
+        })
+        .unwrap_or(body.len());
+    let tag = body[..end].trim_end_matches(is_punctuation);
+
+    let valid = !tag.is_empty()
+        && !tag.starts_with('\u{fe0f}')
+        && tag.chars().count() <= MAX_TAG_LENGTH
+        && tag.chars().any(|c| !(c.is_numeric() || is_punctuation(c)));
+    valid.then(|| (tag.to_string(), marker + tag.len()))
+}
+
+fn is_punctuation(c: char) -> bool {
+    c.is_ascii_punctuation()
+        || matches!(
+            c,
+            '…' | '‘'
+                | '’'
+                | '“'
+                | '”'
+                | '«'
+                | '»'
+                | '¡'
+                | '¿'
+                | '、'
+                | '。'
+                | '！'
+                | '？'
+                | '「'
+                | '」'
+        )
+}
+
+/// URI of a link at the start of `word` and the byte length of the facet. Bare domains
+/// get `https://` prepended
+fn link(word: &str) -> Option<(String, usize)> {
+    let mut candidate = word.trim_end_matches(TRAILING_PUNCTUATION);
+    if let Some(unbalanced) = candidate.strip_suffix(')').filter(|c| !c.contains('(')) {
+        candidate = unbalanced.trim_end_matches(TRAILING_PUNCTUATION);
+    }
+
+    let lower = candidate.to_ascii_lowercase();
+    if let Some(rest) = lower
+        .strip_prefix("https://")
+        .or_else(|| lower.strip_prefix("http://"))
+    {
+        return (!rest.is_empty()).then(|| (candidate.to_string(), candidate.len()));
+    }
+
+    let domain = lower.split(['/', '?', '#']).next().unwrap_or_default();
+    let domain = domain.split(':').next().unwrap_or_default();
+    let labels: Vec<&str> = domain.split('.').collect();
+    let tld = labels.last().copied().unwrap_or_default();
+    let valid = labels.len() >= 2
+        && labels[0].starts_with(|c: char| c.is_ascii_alphabetic())
+        && labels.iter().all(|label| {
+            !label.is_empty() && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+        && ((tld.len() == 2 && tld.chars().all(|c| c.is_ascii_alphabetic()))
+            || BARE_DOMAIN_TLDS.contains(&tld));
+    valid.then(|| (format!("https://{candidate}"), candidate.len()))
+}
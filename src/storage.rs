@@ -1,6 +1,7 @@
 use crate::atproto::StorableSession;
 use crate::atproto::UserSession;
 use crate::errors::BiskyError;
+use crate::subscription::StorableCursor;
 use miette::Diagnostic;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
@@ -55,3 +56,5 @@ impl<'a, T: DeserializeOwned + Serialize + Sync> Storage<T> for File<'a, T> {
 }
 
 impl<'a> StorableSession for File<'a, UserSession> {}
+
+impl<'a> StorableCursor for File<'a, i64> {}
@@ -0,0 +1,427 @@
+//! Shared plumbing for XRPC event stream subscriptions (WebSocket + DAG-CBOR frames)
+use crate::errors::BiskyError;
+use crate::storage::Storage;
+use async_trait::async_trait;
+use derive_builder::Builder;
+use futures::{Stream, StreamExt};
+use miette::Diagnostic;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+pub type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum SubscriptionError {
+    #[error(transparent)]
+    WebSocketError(Box<tokio_tungstenite::tungstenite::Error>),
+    #[error("Invalid Frame: {0}")]
+    InvalidFrame(String),
+    #[error(transparent)]
+    ErrorFrame(#[from] ErrorFrame),
+    #[error("Subscription Closed")]
+    Closed,
+    #[error("Invalid Service URL: {0}")]
+    InvalidUrl(String),
+}
+
+impl From<tokio_tungstenite::tungstenite::Error> for SubscriptionError {
+    fn from(error: tokio_tungstenite::tungstenite::Error) -> Self {
+        Self::WebSocketError(Box::new(error))
+    }
+}
+
+/// Frame header, `op` is 1 for a message and -1 for an error
+#[derive(Debug, Clone, Deserialize)]
+pub struct FrameHeader {
+    pub op: i64,
+    pub t: Option<String>,
+}
+
+/// Error sent by the server right before it closes the stream
+#[derive(Debug, Clone, Error, Deserialize)]
+#[error("Error: {error}, Message: {message:?}")]
+pub struct ErrorFrame {
+    pub error: String,
+    pub message: Option<String>,
+}
+
+/// A decoded frame header with its still-encoded body
+#[derive(Debug)]
+pub struct Frame<'a> {
+    pub header: FrameHeader,
+    pub body: &'a [u8],
+}
+
+impl<'a> Frame<'a> {
+    /// Split a binary WebSocket message into its header and body. Error frames become `Err`
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, SubscriptionError> {
+        let mut cursor = std::io::Cursor::new(bytes);
+        let header: FrameHeader = serde_ipld_dagcbor::de::from_reader_once(&mut cursor)
+            .map_err(|e| SubscriptionError::InvalidFrame(e.to_string()))?;
+        let body = &bytes[cursor.position() as usize..];
+
+        match header.op {
+            1 => Ok(Self { header, body }),
+            -1 => Err(SubscriptionError::ErrorFrame(
+                serde_ipld_dagcbor::from_slice(body)
+                    .map_err(|e| SubscriptionError::InvalidFrame(e.to_string()))?,
+            )),
+            op => Err(SubscriptionError::InvalidFrame(format!("unknown op {op}"))),
+        }
+    }
+
+    /// Message type, e.g. `#commit`
+    pub fn message_type(&self) -> &str {
+        self.header.t.as_deref().unwrap_or_default()
+    }
+
+    pub fn body<T: DeserializeOwned>(&self) -> Result<T, SubscriptionError> {
+        serde_ipld_dagcbor::from_slice(self.body)
+            .map_err(|e| SubscriptionError::InvalidFrame(e.to_string()))
+    }
+}
+
+/// Turn an http(s) service URL into the ws(s) URL of a subscription endpoint
+pub fn subscription_url(
+    service: &reqwest::Url,
+    nsid: &str,
+    query: &[(&str, String)],
+) -> Result<reqwest::Url, SubscriptionError> {
+    websocket_url(service, &format!("xrpc/{nsid}"), query)
+}
+
+/// Like [`subscription_url`], for websocket endpoints outside XRPC at `path`
+pub fn websocket_url(
+    service: &reqwest::Url,
+    path: &str,
+    query: &[(&str, String)],
+) -> Result<reqwest::Url, SubscriptionError> {
+    let mut url = service
+        .join(path)
+        .map_err(|e| SubscriptionError::InvalidUrl(format!("{service}: {e}")))?;
+    let scheme = match url.scheme() {
+        "http" | "ws" => "ws",
+        _ => "wss",
+    };
+    // only special schemes like http(s) can be swapped for ws(s)
+    url.set_scheme(scheme)
+        .map_err(|_| SubscriptionError::InvalidUrl(format!("{service}: not an http(s) URL")))?;
+    if !query.is_empty() {
+        url.query_pairs_mut()
+            .extend_pairs(query.iter().map(|(k, v)| (*k, v.as_str())));
+    }
+    Ok(url)
+}
+
+pub async fn connect(url: &reqwest::Url) -> Result<Socket, SubscriptionError> {
+    let (socket, _) = tokio_tungstenite::connect_async(url.as_str()).await?;
+    Ok(socket)
+}
+
+/// A message type that can be consumed through a resumable [`Subscription`]
+#[async_trait]
+pub trait SubscriptionEvent: Sized + Send + 'static {
+    /// XRPC method of the subscription, e.g. `com.atproto.sync.subscribeRepos`
+    const NSID: &'static str;
+    /// Whether `seq` increases by exactly one per event, so jumps can be reported as gaps
+    const SEQUENTIAL: bool = true;
+
+    async fn decode(bytes: &[u8]) -> Result<Self, BiskyError>;
+    fn seq(&self) -> Option<i64>;
+    /// Whether this is the `#info` message telling us the requested cursor was too old
+    fn is_outdated_cursor(&self) -> bool;
+
+    /// URL to (re)connect to, the XRPC endpoint of `NSID` by default
+    fn url(
+        service: &reqwest::Url,
+        query: &[(&str, String)],
+    ) -> Result<reqwest::Url, SubscriptionError> {
+        subscription_url(service, Self::NSID, query)
+    }
+}
+
+pub trait StorableCursor: Storage<i64, Error = BiskyError> + Send + Sync {}
+
+/// Events were missed between two messages
+#[derive(Debug, Clone)]
+pub enum Gap {
+    /// Sequence numbers jumped from `last` to `next`
+    Sequence { last: i64, next: i64 },
+    /// The server no longer had `cursor` and resumed from the oldest event it has
+    OutdatedCursor { cursor: Option<i64> },
+}
+
+pub type GapHook = Arc<dyn Fn(Gap) + Send + Sync>;
+
+pub type ErrorHook = Arc<dyn Fn(&BiskyError) + Send + Sync>;
+
+/// Cursor checkpointing and reconnect settings for a subscription
+#[derive(Clone, Builder)]
+pub struct SubscriptionConfig {
+    #[builder(default = r#"reqwest::Url::parse("https://bsky.network").unwrap()"#)]
+    service: reqwest::Url,
+    /// Cursor to start from, otherwise the one in `storage` is used, otherwise the live tail
+    #[builder(default, setter(strip_option))]
+    cursor: Option<i64>,
+    #[builder(default, setter(strip_option))]
+    storage: Option<Arc<dyn StorableCursor>>,
+    /// Persist the cursor after this many events
+    #[builder(default = "1000")]
+    checkpoint_events: u64,
+    /// Persist the cursor at least this often while events are flowing
+    #[builder(default = "Duration::from_secs(10)")]
+    checkpoint_interval: Duration,
+    #[builder(default = "Duration::from_secs(1)")]
+    min_backoff: Duration,
+    #[builder(default = "Duration::from_secs(60)")]
+    max_backoff: Duration,
+    /// Give up after this many failed reconnects in a row, retries forever when `None`
+    #[builder(default, setter(strip_option))]
+    max_reconnect_attempts: Option<u32>,
+    #[builder(default, setter(custom))]
+    on_gap: Option<GapHook>,
+    #[builder(default, setter(custom))]
+    on_error: Option<ErrorHook>,
+    /// Extra query parameters sent on every (re)connect
+    #[builder(default, setter(custom))]
+    query: Vec<(String, String)>,
+}
+
+impl SubscriptionConfigBuilder {
+    /// Called whenever a gap in the event sequence is detected
+    pub fn on_gap(&mut self, hook: impl Fn(Gap) + Send + Sync + 'static) -> &mut Self {
+        self.on_gap = Some(Some(Arc::new(hook)));
+        self
+    }
+
+    /// Called with each error the subscription recovers from by reconnecting, such as a
+    /// dropped socket or a failed reconnect attempt
+    pub fn on_error(&mut self, hook: impl Fn(&BiskyError) + Send + Sync + 'static) -> &mut Self {
+        self.on_error = Some(Some(Arc::new(hook)));
+        self
+    }
+
+    /// Add a query parameter, may be repeated for list parameters
+    pub fn param(&mut self, name: &str, value: &str) -> &mut Self {
+        self.query
+            .get_or_insert_with(Vec::new)
+            .push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+impl SubscriptionConfig {
+    pub async fn subscribe<E: SubscriptionEvent>(self) -> Result<Subscription<E>, BiskyError> {
+        let cursor = match (self.cursor, &self.storage) {
+            (Some(cursor), _) => Some(cursor),
+            (None, Some(storage)) => match storage.get().await {
+                Ok(cursor) => Some(cursor),
+                // nothing has been checkpointed yet
+                Err(BiskyError::IoError(e)) if e.kind() == std::io::ErrorKind::NotFound => None,
+                Err(e) => return Err(e),
+            },
+            (None, None) => None,
+        };
+
+        let mut subscription = Subscription {
+            backoff: self.min_backoff,
+            config: self,
+            socket: None,
+            cursor,
+            stored_cursor: cursor,
+            pending: 0,
+            last_checkpoint: Instant::now(),
+            failures: 0,
+            finished: false,
+            event: PhantomData,
+        };
+        subscription.socket = Some(subscription.connect().await?);
+        Ok(subscription)
+    }
+}
+
+/// A subscription that checkpoints its cursor and reconnects with backoff when dropped.
+/// An event counts as processed once `next` is called again, so the stored cursor
+/// never runs ahead of the consumer
+pub struct Subscription<E> {
+    config: SubscriptionConfig,
+    socket: Option<Socket>,
+    cursor: Option<i64>,
+    stored_cursor: Option<i64>,
+    pending: u64,
+    last_checkpoint: Instant,
+    backoff: Duration,
+    failures: u32,
+    finished: bool,
+    event: PhantomData<E>,
+}
+
+impl<E: SubscriptionEvent> Subscription<E> {
+    /// Sequence number of the last event handed out
+    pub fn cursor(&self) -> Option<i64> {
+        self.cursor
+    }
+
+    /// Persist the cursor now, e.g. before shutting down
+    pub async fn checkpoint(&mut self) -> Result<(), BiskyError> {
+        if let (Some(storage), Some(cursor)) = (&self.config.storage, self.cursor) {
+            if self.stored_cursor != Some(cursor) {
+                storage
+                    .set(Some(&cursor))
+                    .await
+                    .map_err(|e| BiskyError::StorageError(e.to_string()))?;
+                self.stored_cursor = Some(cursor);
+            }
+        }
+        self.pending = 0;
+        self.last_checkpoint = Instant::now();
+        Ok(())
+    }
+
+    async fn connect(&mut self) -> Result<Socket, BiskyError> {
+        let mut query: Vec<(&str, String)> = self
+            .config
+            .query
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.clone()))
+            .collect();
+        if let Some(cursor) = self.cursor {
+            query.push(("cursor", cursor.to_string()));
+        }
+        let url = E::url(&self.config.service, &query)?;
+        Ok(connect(&url).await?)
+    }
+
+    /// Wait out the backoff and connect again from the current cursor
+    async fn reconnect(&mut self) -> Result<(), BiskyError> {
+        loop {
+            tokio::time::sleep(self.backoff).await;
+            self.backoff = std::cmp::min(self.backoff * 2, self.config.max_backoff);
+
+            match self.connect().await {
+                Ok(socket) => {
+                    self.socket = Some(socket);
+                    return Ok(());
+                }
+                Err(e) => {
+                    self.failures += 1;
+                    if matches!(self.config.max_reconnect_attempts, Some(max) if self.failures >= max)
+                    {
+                        return Err(e);
+                    }
+                    self.error(&e);
+                }
+            }
+        }
+    }
+
+    fn gap(&self, gap: Gap) {
+        if let Some(hook) = &self.config.on_gap {
+            hook(gap);
+        }
+    }
+
+    fn error(&self, error: &BiskyError) {
+        if let Some(hook) = &self.config.on_error {
+            hook(error);
+        }
+    }
+
+    /// Next event, reconnecting as needed. Returns `None` once the stream has failed for good
+    pub async fn next(&mut self) -> Option<Result<E, BiskyError>> {
+        if self.finished {
+            return None;
+        }
+        if self.pending >= self.config.checkpoint_events
+            || (self.pending > 0
+                && self.last_checkpoint.elapsed() >= self.config.checkpoint_interval)
+        {
+            if let Err(e) = self.checkpoint().await {
+                return Some(Err(e));
+            }
+        }
+
+        loop {
+            let Some(socket) = self.socket.as_mut() else {
+                if let Err(e) = self.reconnect().await {
+                    self.finished = true;
+                    return Some(Err(e));
+                }
+                continue;
+            };
+
+            let bytes = match socket.next().await {
+                Some(Ok(Message::Binary(bytes))) => bytes,
+                Some(Ok(Message::Text(text))) => text.into_bytes(),
+                Some(Err(e)) => {
+                    self.error(&SubscriptionError::from(e).into());
+                    self.socket = None;
+                    continue;
+                }
+                Some(Ok(Message::Close(_))) | None => {
+                    self.socket = None;
+                    continue;
+                }
+                Some(Ok(_)) => continue,
+            };
+
+            let event = match E::decode(&bytes).await {
+                Ok(event) => event,
+                Err(BiskyError::SubscriptionError(SubscriptionError::ErrorFrame(frame)))
+                    if frame.error == "ConsumerTooSlow" =>
+                {
+                    // the server drops us right after this, resume from our cursor
+                    self.error(&SubscriptionError::ErrorFrame(frame).into());
+                    self.socket = None;
+                    continue;
+                }
+                Err(e @ BiskyError::SubscriptionError(SubscriptionError::ErrorFrame(_))) => {
+                    self.finished = true;
+                    return Some(Err(e));
+                }
+                Err(e) => return Some(Err(e)),
+            };
+            self.failures = 0;
+            self.backoff = self.config.min_backoff;
+
+            if let Some(seq) = event.seq() {
+                if let Some(last) = self.cursor {
+                    if seq <= last {
+                        // replayed after a reconnect
+                        continue;
+                    }
+                    if E::SEQUENTIAL && seq > last + 1 {
+                        self.gap(Gap::Sequence { last, next: seq });
+                    }
+                }
+                self.cursor = Some(seq);
+                self.pending += 1;
+            }
+            if event.is_outdated_cursor() {
+                self.gap(Gap::OutdatedCursor {
+                    cursor: self.stored_cursor,
+                });
+            }
+            return Some(Ok(event));
+        }
+    }
+
+    pub fn into_stream(self) -> impl Stream<Item = Result<E, BiskyError>> {
+        futures::stream::unfold(self, |mut subscription| async move {
+            match subscription.next().await {
+                Some(event) => Some((event, subscription)),
+                None => {
+                    let _ = subscription.checkpoint().await;
+                    None
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,37 @@
+//! Timestamp identifiers (TIDs), the sortable record keys atproto uses for posts and gates
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const ALPHABET: &[u8; 32] = b"234567abcdefghijklmnopqrstuvwxyz";
+
+/// Encode a 63 bit value as the 13 character sortable base32 a TID is written in
+pub fn encode(value: u64) -> String {
+    (0..13)
+        .rev()
+        .map(|i| ALPHABET[(value >> (i * 5)) as usize & 31] as char)
+        .collect()
+}
+
+/// A new TID for the current time, microseconds since the epoch followed by a random
+/// 10 bit clock id. Strictly increasing within the process
+pub fn next() -> String {
+    static CLOCK_ID: OnceLock<u64> = OnceLock::new();
+    static LAST: AtomicU64 = AtomicU64::new(0);
+
+    let clock_id = *CLOCK_ID.get_or_init(|| RandomState::new().build_hasher().finish() & 0x3ff);
+    let micros = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_micros() as u64;
+    let value = (micros & ((1 << 53) - 1)) << 10 | clock_id;
+    // two calls in the same microsecond must still differ and sort in call order
+    let previous = LAST
+        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |last| {
+            Some(value.max(last + (1 << 10)))
+        })
+        .unwrap();
+    encode(value.max(previous + (1 << 10)))
+}
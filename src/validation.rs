@@ -0,0 +1,567 @@
+//! Runtime validation of records against their lexicon schemas, before they are written
+use crate::lexicon::com::atproto::repo::{
+    ApplyWritesInput, ApplyWritesInputWrite, CreateRecordInput, PutRecordInput,
+};
+use crate::lexicon::generated::schemas::LEXICONS;
+use chrono::DateTime;
+use miette::Diagnostic;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::OnceLock;
+use thiserror::Error;
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum ValidationError {
+    #[error("No lexicon schema for {0}")]
+    UnknownLexicon(String),
+    #[error("Invalid {nsid} record: {}", .violations.iter().map(ToString::to_string).collect::<Vec<_>>().join("; "))]
+    InvalidRecord {
+        nsid: String,
+        violations: Vec<Violation>,
+    },
+    #[error(transparent)]
+    JsonError(#[from] serde_json::Error),
+}
+
+/// One way a value breaks its schema, at a JSON path like `embed.images[0].alt`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub path: String,
+    pub kind: ViolationKind,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.path.is_empty() {
+            true => write!(f, "{}", self.kind),
+            false => write!(f, "{}: {}", self.path, self.kind),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ViolationKind {
+    #[error("required field is missing")]
+    Missing,
+    #[error("expected {0}")]
+    WrongType(&'static str),
+    #[error("longer than {max} bytes ({actual})")]
+    TooLong { max: u64, actual: u64 },
+    #[error("shorter than {min} bytes ({actual})")]
+    TooShort { min: u64, actual: u64 },
+    #[error("more than {max} graphemes ({actual})")]
+    TooManyGraphemes { max: u64, actual: u64 },
+    #[error("fewer than {min} graphemes ({actual})")]
+    TooFewGraphemes { min: u64, actual: u64 },
+    #[error("more than {max} items ({actual})")]
+    TooManyItems { max: u64, actual: u64 },
+    #[error("fewer than {min} items ({actual})")]
+    TooFewItems { min: u64, actual: u64 },
+    #[error("{actual} is outside {min:?}..={max:?}")]
+    OutOfRange {
+        min: Option<i64>,
+        max: Option<i64>,
+        actual: i64,
+    },
+    #[error("not one of the allowed values")]
+    NotAllowed,
+    #[error("not a valid {0}")]
+    InvalidFormat(String),
+    #[error("$type {0} is not allowed here")]
+    UnexpectedType(String),
+    #[error("mime type {mime_type} is not accepted ({})", .accept.join(", "))]
+    MimeType {
+        mime_type: String,
+        accept: Vec<String>,
+    },
+    #[error("blob of {size} bytes is over the {max} byte limit")]
+    BlobTooLarge { max: u64, size: u64 },
+    #[error("reference to unknown schema {0}")]
+    UnresolvedRef(String),
+}
+
+/// Inputs of the procedures that write records, checked before they are sent
+pub(crate) trait RecordWrites {
+    /// Check every written record when the input's `validate` says so, or else `default`
+    fn validate_writes(&self, default: bool) -> Result<(), ValidationError>;
+}
+
+impl<T: Serialize> RecordWrites for CreateRecordInput<T> {
+    fn validate_writes(&self, default: bool) -> Result<(), ValidationError> {
+        match self.validate.unwrap_or(default) {
+            true => Validator::bundled().validate_record(&self.collection, &self.record),
+            false => Ok(()),
+        }
+    }
+}
+
+impl<T: Serialize> RecordWrites for PutRecordInput<T> {
+    fn validate_writes(&self, default: bool) -> Result<(), ValidationError> {
+        match self.validate.unwrap_or(default) {
+            true => Validator::bundled().validate_record(&self.collection, &self.record),
+            false => Ok(()),
+        }
+    }
+}
+
+impl RecordWrites for ApplyWritesInput {
+    fn validate_writes(&self, default: bool) -> Result<(), ValidationError> {
+        if !self.validate.unwrap_or(default) {
+            return Ok(());
+        }
+        for write in &self.writes {
+            let (collection, value) = match write {
+                ApplyWritesInputWrite::Create(create) => (&create.collection, &create.value),
+                ApplyWritesInputWrite::Update(update) => (&update.collection, &update.value),
+                _ => continue,
+            };
+            Validator::bundled().validate_value(collection, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Lexicon schemas to validate against, by default every lexicon bisky bundles
+pub struct Validator {
+    lexicons: HashMap<String, Value>,
+}
+
+impl Default for Validator {
+    fn default() -> Self {
+        let mut validator = Self::empty();
+        for lexicon in LEXICONS {
+            validator.add(serde_json::from_str(lexicon).expect("bundled lexicons are valid JSON"));
+        }
+        validator
+    }
+}
+
+impl Validator {
+    pub fn empty() -> Self {
+        Self {
+            lexicons: HashMap::new(),
+        }
+    }
+
+    /// Shared validator over the bundled lexicons, parsed on first use
+    pub fn bundled() -> &'static Validator {
+        static VALIDATOR: OnceLock<Validator> = OnceLock::new();
+        VALIDATOR.get_or_init(Validator::default)
+    }
+
+    /// Add or replace a lexicon document, keyed by its `id`
+    pub fn add(&mut self, lexicon: Value) {
+        if let Some(id) = lexicon["id"].as_str() {
+            self.lexicons.insert(id.to_string(), lexicon);
+        }
+    }
+
+    /// Check a record against the `main` def of the `collection` lexicon
+    pub fn validate_record<S: Serialize>(
+        &self,
+        collection: &str,
+        record: &S,
+    ) -> Result<(), ValidationError> {
+        self.validate_value(collection, &serde_json::to_value(record)?)
+    }
+
+    /// Check a JSON value against a def, `nsid` or `nsid#def`
+    pub fn validate_value(&self, def: &str, value: &Value) -> Result<(), ValidationError> {
+        let (nsid, name) = split_ref("", def);
+        let Some(schema) = self.def(&nsid, &name) else {
+            return Err(ValidationError::UnknownLexicon(def.to_string()));
+        };
+        let mut check = Check {
+            validator: self,
+            violations: Vec::new(),
+        };
+        check.value(&nsid, schema, value, &mut String::new());
+        match check.violations.is_empty() {
+            true => Ok(()),
+            false => Err(ValidationError::InvalidRecord {
+                nsid: def.to_string(),
+                violations: check.violations,
+            }),
+        }
+    }
+
+    fn def(&self, nsid: &str, name: &str) -> Option<&Value> {
+        self.lexicons.get(nsid)?.get("defs")?.get(name)
+    }
+}
+
+/// `#def` relative to `nsid`, `nsid#def` or a bare `nsid` meaning its main def
+fn split_ref(nsid: &str, reference: &str) -> (String, String) {
+    match reference.split_once('#') {
+        Some(("", def)) => (nsid.to_string(), def.to_string()),
+        Some((other, def)) => (other.to_string(), def.to_string()),
+        None => (reference.to_string(), "main".to_string()),
+    }
+}
+
+struct Check<'a> {
+    validator: &'a Validator,
+    violations: Vec<Violation>,
+}
+
+impl Check<'_> {
+    fn violation(&mut self, path: &str, kind: ViolationKind) {
+        self.violations.push(Violation {
+            path: path.to_string(),
+            kind,
+        });
+    }
+
+    /// Validate `value` against `schema`, a def or property of the lexicon `nsid`
+    fn value(&mut self, nsid: &str, schema: &Value, value: &Value, path: &mut String) {
+        match schema["type"].as_str().unwrap_or_default() {
+            "record" => self.value(nsid, &schema["record"], value, path),
+            "object" => self.object(nsid, schema, value, path),
+            "ref" => {
+                let reference = schema["ref"].as_str().unwrap_or_default();
+                let (target, def) = split_ref(nsid, reference);
+                match self.validator.def(&target, &def) {
+                    // A ref to a token means the value is that token's name
+                    Some(def) if def["type"] == "token" => {
+                        if !value.is_string() {
+                            self.violation(path, ViolationKind::WrongType("string"));
+                        }
+                    }
+                    Some(def) => self.value(&target, def, value, path),
+                    None => self.violation(path, ViolationKind::UnresolvedRef(reference.into())),
+                }
+            }
+            "union" => self.union(nsid, schema, value, path),
+            "string" => self.string(schema, value, path),
+            "integer" => self.integer(schema, value, path),
+            "boolean" => match value.as_bool() {
+                Some(actual) if schema["const"].as_bool().is_some_and(|c| c != actual) => {
+                    self.violation(path, ViolationKind::NotAllowed)
+                }
+                Some(_) => {}
+                None => self.violation(path, ViolationKind::WrongType("boolean")),
+            },
+            "array" => self.array(nsid, schema, value, path),
+            "blob" => self.blob(schema, value, path),
+            "bytes" if !value.get("$bytes").is_some_and(Value::is_string) => {
+                self.violation(path, ViolationKind::WrongType("$bytes object"))
+            }
+            "cid-link" if !value.get("$link").is_some_and(Value::is_string) => {
+                self.violation(path, ViolationKind::WrongType("$link object"))
+            }
+            "unknown" if !value.is_object() => {
+                self.violation(path, ViolationKind::WrongType("object"))
+            }
+            _ => {}
+        }
+    }
+
+    fn object(&mut self, nsid: &str, schema: &Value, value: &Value, path: &mut String) {
+        let Some(object) = value.as_object() else {
+            return self.violation(path, ViolationKind::WrongType("object"));
+        };
+        let nullable = strings(&schema["nullable"]);
+        for required in strings(&schema["required"]) {
+            let present = match object.get(required) {
+                Some(Value::Null) => nullable.contains(&required),
+                Some(_) => true,
+                None => false,
+            };
+            if !present {
+                self.violation(&join(path, required), ViolationKind::Missing);
+            }
+        }
+
+        let Some(properties) = schema["properties"].as_object() else {
+            return;
+        };
+        for (name, property) in properties {
+            match object.get(name) {
+                None => {}
+                Some(Value::Null) if nullable.contains(&name.as_str()) => {}
+                Some(field) => {
+                    let len = path.len();
+                    *path = join(path, name);
+                    self.value(nsid, property, field, path);
+                    path.truncate(len);
+                }
+            }
+        }
+    }
+
+    fn union(&mut self, nsid: &str, schema: &Value, value: &Value, path: &mut String) {
+        let Some(kind) = value.get("$type").and_then(Value::as_str) else {
+            return self.violation(&join(path, "$type"), ViolationKind::Missing);
+        };
+        let (target, def) = split_ref("", kind);
+        let member = schema["refs"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .any(|reference| split_ref(nsid, reference) == (target.clone(), def.clone()));
+
+        match (member, self.validator.def(&target, &def)) {
+            (true, Some(def)) => self.value(&target, def, value, path),
+            (true, None) => self.violation(path, ViolationKind::UnresolvedRef(kind.into())),
+            // Open unions may hold types this lexicon version does not know
+            (false, _) if schema["closed"] != true => {}
+            (false, _) => self.violation(path, ViolationKind::UnexpectedType(kind.into())),
+        }
+    }
+
+    fn string(&mut self, schema: &Value, value: &Value, path: &str) {
+        let Some(string) = value.as_str() else {
+            return self.violation(path, ViolationKind::WrongType("string"));
+        };
+
+        let bytes = string.len() as u64;
+        if let Some(max) = schema["maxLength"].as_u64().filter(|max| bytes > *max) {
+            self.violation(path, ViolationKind::TooLong { max, actual: bytes });
+        }
+        if let Some(min) = schema["minLength"].as_u64().filter(|min| bytes < *min) {
+            self.violation(path, ViolationKind::TooShort { min, actual: bytes });
+        }
+        if schema.get("maxGraphemes").is_some() || schema.get("minGraphemes").is_some() {
+            let graphemes = string.graphemes(true).count() as u64;
+            if let Some(max) = schema["maxGraphemes"].as_u64().filter(|m| graphemes > *m) {
+                let actual = graphemes;
+                self.violation(path, ViolationKind::TooManyGraphemes { max, actual });
+            }
+            if let Some(min) = schema["minGraphemes"].as_u64().filter(|m| graphemes < *m) {
+                let actual = graphemes;
+                self.violation(path, ViolationKind::TooFewGraphemes { min, actual });
+            }
+        }
+
+        let allowed = match (&schema["enum"], &schema["const"]) {
+            (Value::Array(values), _) => values.iter().any(|v| v == string),
+            (_, Value::String(constant)) => constant == string,
+            _ => true,
+        };
+        if !allowed {
+            self.violation(path, ViolationKind::NotAllowed);
+        }
+
+        if let Some(format) = schema["format"].as_str() {
+            if !valid_format(format, string) {
+                self.violation(path, ViolationKind::InvalidFormat(format.to_string()));
+            }
+        }
+    }
+
+    fn integer(&mut self, schema: &Value, value: &Value, path: &str) {
+        let Some(actual) = value.as_i64() else {
+            return self.violation(path, ViolationKind::WrongType("integer"));
+        };
+        let min = schema["minimum"].as_i64();
+        let max = schema["maximum"].as_i64();
+        if min.is_some_and(|min| actual < min) || max.is_some_and(|max| actual > max) {
+            self.violation(path, ViolationKind::OutOfRange { min, max, actual });
+        }
+        let allowed = match (&schema["enum"], schema["const"].as_i64()) {
+            (Value::Array(values), _) => values.iter().any(|v| v.as_i64() == Some(actual)),
+            (_, Some(constant)) => constant == actual,
+            _ => true,
+        };
+        if !allowed {
+            self.violation(path, ViolationKind::NotAllowed);
+        }
+    }
+
+    fn array(&mut self, nsid: &str, schema: &Value, value: &Value, path: &mut String) {
+        let Some(items) = value.as_array() else {
+            return self.violation(path, ViolationKind::WrongType("array"));
+        };
+        let actual = items.len() as u64;
+        if let Some(max) = schema["maxLength"].as_u64().filter(|max| actual > *max) {
+            self.violation(path, ViolationKind::TooManyItems { max, actual });
+        }
+        if let Some(min) = schema["minLength"].as_u64().filter(|min| actual < *min) {
+            self.violation(path, ViolationKind::TooFewItems { min, actual });
+        }
+        for (i, item) in items.iter().enumerate() {
+            let len = path.len();
+            path.push_str(&format!("[{i}]"));
+            self.value(nsid, &schema["items"], item, path);
+            path.truncate(len);
+        }
+    }
+
+    fn blob(&mut self, schema: &Value, value: &Value, path: &str) {
+        let Some(blob) = value.as_object() else {
+            return self.violation(path, ViolationKind::WrongType("blob"));
+        };
+        let Some(mime_type) = blob.get("mimeType").and_then(Value::as_str) else {
+            return self.violation(&join(path, "mimeType"), ViolationKind::Missing);
+        };
+        let accept = strings(&schema["accept"]);
+        if !accept.is_empty()
+            && !accept
+                .iter()
+                .any(|pattern| mime_matches(pattern, mime_type))
+        {
+            let mime_type = mime_type.to_string();
+            let accept = accept.into_iter().map(str::to_string).collect();
+            self.violation(path, ViolationKind::MimeType { mime_type, accept });
+        }
+        // Legacy blobs carry only `cid` and `mimeType`, without a size to check
+        let size = blob.get("size").and_then(Value::as_u64);
+        if let (Some(max), Some(size)) = (schema["maxSize"].as_u64(), size) {
+            if size > max {
+                self.violation(path, ViolationKind::BlobTooLarge { max, size });
+            }
+        }
+    }
+}
+
+fn strings(value: &Value) -> Vec<&str> {
+    value
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect()
+}
+
+fn join(path: &str, field: &str) -> String {
+    match path.is_empty() {
+        true => field.to_string(),
+        false => format!("{path}.{field}"),
+    }
+}
+
+/// `image/*` style patterns from a blob's `accept` list
+fn mime_matches(pattern: &str, mime_type: &str) -> bool {
+    match pattern.strip_suffix("/*") {
+        Some("*") => true,
+        Some(prefix) => mime_type
+            .split_once('/')
+            .is_some_and(|(kind, _)| kind.eq_ignore_ascii_case(prefix)),
+        None => pattern.eq_ignore_ascii_case(mime_type),
+    }
+}
+
+fn valid_format(format: &str, value: &str) -> bool {
+    match format {
+        "datetime" => DateTime::parse_from_rfc3339(value).is_ok(),
+        "did" => is_did(value),
+        "handle" => is_handle(value),
+        "at-identifier" => is_did(value) || is_handle(value),
+        "at-uri" => is_at_uri(value),
+        "nsid" => is_nsid(value),
+        "cid" => value.len() >= 8 && value.chars().all(|c| c.is_ascii_alphanumeric()),
+        "uri" => is_uri(value),
+        "language" => is_language(value),
+        "tid" => {
+            value.len() == 13
+                && value
+                    .bytes()
+                    .all(|b| b"234567abcdefghijklmnopqrstuvwxyz".contains(&b))
+        }
+        "record-key" => {
+            (1..=512).contains(&value.len())
+                && value != "."
+                && value != ".."
+                && value
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "._:~-".contains(c))
+        }
+        _ => true,
+    }
+}
+
+fn is_did(value: &str) -> bool {
+    let Some(("did", rest)) = value.split_once(':') else {
+        return false;
+    };
+    let Some((method, id)) = rest.split_once(':') else {
+        return false;
+    };
+    value.len() <= 2048
+        && !method.is_empty()
+        && method.chars().all(|c| c.is_ascii_lowercase())
+        && !id.is_empty()
+        && !id.ends_with(':')
+        && !id.ends_with('%')
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "._:%-".contains(c))
+}
+
+pub(crate) fn is_handle(value: &str) -> bool {
+    let labels: Vec<&str> = value.split('.').collect();
+    value.len() <= 253
+        && labels.len() >= 2
+        && labels.iter().all(|label| {
+            (1..=63).contains(&label.len())
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+        && labels
+            .last()
+            .is_some_and(|tld| tld.starts_with(|c: char| c.is_ascii_alphabetic()))
+}
+
+fn is_nsid(value: &str) -> bool {
+    let segments: Vec<&str> = value.split('.').collect();
+    value.len() <= 317
+        && segments.len() >= 3
+        && segments.iter().all(|segment| {
+            (1..=63).contains(&segment.len())
+                && segment
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+        && segments
+            .last()
+            .is_some_and(|name| name.chars().all(|c| c.is_ascii_alphanumeric()))
+}
+
+/// `at://authority[/collection[/rkey]]`
+fn is_at_uri(value: &str) -> bool {
+    let Some(rest) = value.strip_prefix("at://") else {
+        return false;
+    };
+    let rest = rest.split(['#', '?']).next().unwrap_or_default();
+    let mut parts = rest.split('/');
+    let authority = parts.next().unwrap_or_default();
+    let collection = parts.next();
+    let rkey = parts.next();
+    value.len() <= 8192
+        && (is_did(authority) || is_handle(authority))
+        && collection.into_iter().all(is_nsid)
+        && rkey
+            .into_iter()
+            .all(|rkey| valid_format("record-key", rkey))
+        && parts.next().is_none()
+}
+
+fn is_uri(value: &str) -> bool {
+    let Some((scheme, rest)) = value.split_once(':') else {
+        return false;
+    };
+    value.len() <= 8192
+        && scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "+.-".contains(c))
+        && !rest.is_empty()
+        && !value.chars().any(char::is_whitespace)
+}
+
+/// BCP-47 shaped: a 2-3 letter primary tag (or `i`/`x`) and alphanumeric subtags
+pub fn is_language(value: &str) -> bool {
+    let mut subtags = value.split('-');
+    let primary = subtags.next().unwrap_or_default();
+    let primary_ok = (primary.len() == 1 && matches!(primary, "i" | "x"))
+        || ((2..=3).contains(&primary.len()) && primary.chars().all(|c| c.is_ascii_alphabetic()));
+    primary_ok
+        && subtags.all(|subtag| {
+            (1..=8).contains(&subtag.len()) && subtag.chars().all(|c| c.is_ascii_alphanumeric())
+        })
+}
@@ -0,0 +1,75 @@
+//! Reading CARs, in memory and through an index
+use bisky::car::{BlockStore, Blocks, CarError, CarIndex, CarReader};
+use common::{block, car, sign_k256, TestRepo};
+use serde_json::json;
+use std::io::Cursor;
+
+mod common;
+
+#[test]
+fn block_cids() {
+    // the well known CID of an empty DAG-CBOR map
+    let (cid, data) = block(&json!({}));
+    assert_eq!(data, [0xa0]);
+    assert_eq!(
+        cid.to_string(),
+        "bafyreigbtj4x7ip5legnfznufuopl4sg4knzc2cof6duas4b3q2fy6swua"
+    );
+}
+
+#[tokio::test]
+async fn read_in_order() {
+    let repo = TestRepo::new(sign_k256);
+    let bytes = repo.car();
+
+    let mut reader = CarReader::new(bytes.as_slice()).await.unwrap();
+    assert_eq!(reader.header().version, 1);
+    assert_eq!(reader.roots(), [repo.commit]);
+    let mut read = Vec::new();
+    while let Some(block) = reader.next_block().await.unwrap() {
+        read.push(block);
+    }
+    assert_eq!(read, repo.blocks);
+
+    let mut blocks = Blocks::from_bytes(&bytes).await.unwrap();
+    for (cid, data) in &repo.blocks {
+        assert_eq!(blocks.get_raw(cid).await.unwrap().as_ref(), Some(data));
+    }
+}
+
+#[tokio::test]
+async fn indexed() {
+    let repo = TestRepo::new(sign_k256);
+    let mut index = CarIndex::build(Cursor::new(repo.car())).await.unwrap();
+    assert_eq!(index.roots(), [repo.commit]);
+    assert_eq!(index.len(), repo.blocks.len());
+
+    // blocks come back in any order
+    for (cid, data) in repo.blocks.iter().rev() {
+        assert!(index.contains(cid));
+        assert_eq!(index.get_raw(cid).await.unwrap().as_ref(), Some(data));
+    }
+    let (missing, _) = block(&json!({ "not": "in the CAR" }));
+    assert_eq!(index.get_raw(&missing).await.unwrap(), None);
+    assert!(matches!(
+        index.get::<serde_json::Value>(&missing).await,
+        Err(CarError::BlockNotFound(cid)) if cid == missing
+    ));
+}
+
+#[tokio::test]
+async fn invalid() {
+    let (_, header) = block(&json!({ "version": 2, "roots": [] }));
+    let mut bytes = vec![header.len() as u8];
+    bytes.extend(header);
+    assert!(matches!(
+        CarReader::new(bytes.as_slice()).await,
+        Err(CarError::UnsupportedVersion(2))
+    ));
+
+    let repo = TestRepo::new(sign_k256);
+    let bytes = car(&[repo.commit], &repo.blocks);
+    let truncated = &bytes[..bytes.len() - 1];
+    let result = Blocks::from_bytes(truncated).await;
+    assert!(matches!(result, Err(CarError::IoError(_))));
+}
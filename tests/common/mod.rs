@@ -0,0 +1,487 @@
+//! Helpers shared by the integration tests
+#![allow(dead_code)]
+use bisky::atproto::{Client, ClientBuilder, UserSession};
+use bisky::car::Cid;
+use bisky::did::DidDocument;
+use k256::sha2::{Digest, Sha256};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// A binary subscription frame, the DAG-CBOR header followed by the DAG-CBOR body
+pub fn frame(t: &str, body: &impl Serialize) -> Vec<u8> {
+    #[derive(Serialize)]
+    struct Header<'a> {
+        op: i64,
+        t: &'a str,
+    }
+
+    let mut bytes = serde_ipld_dagcbor::to_vec(&Header { op: 1, t }).unwrap();
+    bytes.extend(serde_ipld_dagcbor::to_vec(body).unwrap());
+    bytes
+}
+
+/// DAG-CBOR encode `value` as a block, with its CIDv1 (dag-cbor, sha2-256)
+pub fn block(value: &impl Serialize) -> (Cid, Vec<u8>) {
+    let data = serde_ipld_dagcbor::to_vec(value).unwrap();
+    let mut cid = vec![0x01, 0x71, 0x12, 0x20];
+    cid.extend(Sha256::digest(&data));
+    (Cid::try_from(cid).unwrap(), data)
+}
+
+/// A CARv1 file holding `blocks` under `roots`
+pub fn car(roots: &[Cid], blocks: &[(Cid, Vec<u8>)]) -> Vec<u8> {
+    fn varint(mut value: usize, out: &mut Vec<u8>) {
+        while value >= 0x80 {
+            out.push(value as u8 | 0x80);
+            value >>= 7;
+        }
+        out.push(value as u8);
+    }
+
+    #[derive(Serialize)]
+    struct Header<'a> {
+        version: u64,
+        roots: &'a [Cid],
+    }
+
+    let (_, header) = block(&Header { version: 1, roots });
+    let mut out = Vec::new();
+    varint(header.len(), &mut out);
+    out.extend(header);
+    for (cid, data) in blocks {
+        let cid = cid.to_bytes();
+        varint(cid.len() + data.len(), &mut out);
+        out.extend(cid);
+        out.extend(data);
+    }
+    out
+}
+
+/// The secp256k1 key the test repos and labels are signed with
+pub fn k256_key() -> k256::ecdsa::SigningKey {
+    k256::ecdsa::SigningKey::from_slice(&[0x11; 32]).unwrap()
+}
+
+pub fn p256_key() -> p256::ecdsa::SigningKey {
+    p256::ecdsa::SigningKey::from_slice(&[0x22; 32]).unwrap()
+}
+
+/// Compact low-S signature, as atproto requires
+pub fn sign_k256(message: &[u8]) -> Vec<u8> {
+    use k256::ecdsa::signature::Signer;
+    let signature: k256::ecdsa::Signature = k256_key().sign(message);
+    signature.normalize_s().unwrap_or(signature).to_vec()
+}
+
+pub fn sign_p256(message: &[u8]) -> Vec<u8> {
+    use p256::ecdsa::signature::Signer;
+    let signature: p256::ecdsa::Signature = p256_key().sign(message);
+    signature.normalize_s().unwrap_or(signature).to_vec()
+}
+
+/// `publicKeyMultibase` of a Multikey: base58btc of the multicodec prefixed compressed key
+pub fn k256_multikey() -> String {
+    let mut bytes = vec![0xe7, 0x01];
+    bytes.extend(k256_key().verifying_key().to_encoded_point(true).as_bytes());
+    format!("z{}", bs58::encode(bytes).into_string())
+}
+
+pub fn p256_multikey() -> String {
+    let mut bytes = vec![0x80, 0x24];
+    bytes.extend(p256_key().verifying_key().to_encoded_point(true).as_bytes());
+    format!("z{}", bs58::encode(bytes).into_string())
+}
+
+/// DID document of `did` with `multikey` as its `#fragment` verification method
+pub fn did_document(did: &str, fragment: &str, multikey: &str) -> DidDocument {
+    serde_json::from_value(json!({
+        "id": did,
+        "alsoKnownAs": ["at://alice.test"],
+        "verificationMethod": [{
+            "id": format!("{did}#{fragment}"),
+            "type": "Multikey",
+            "controller": did,
+            "publicKeyMultibase": multikey
+        }]
+    }))
+    .unwrap()
+}
+
+/// An MST node as written to a block
+#[derive(Serialize)]
+pub struct MstNode {
+    pub l: Option<Cid>,
+    pub e: Vec<MstEntry>,
+}
+
+#[derive(Serialize)]
+pub struct MstEntry {
+    pub p: usize,
+    #[serde(with = "serde_bytes")]
+    pub k: Vec<u8>,
+    pub v: Cid,
+    pub t: Option<Cid>,
+}
+
+pub const REPO_DID: &str = "did:plc:ewvi7nxzyoun6zhxrhs64oiz";
+
+/// A small signed repo: four records in a three node MST
+pub struct TestRepo {
+    pub commit: Cid,
+    pub blocks: Vec<(Cid, Vec<u8>)>,
+    /// `(collection/rkey, record CID)` in key order
+    pub records: Vec<(String, Cid)>,
+}
+
+impl TestRepo {
+    pub fn new(sign: fn(&[u8]) -> Vec<u8>) -> Self {
+        let records = [
+            (
+                "app.bsky.feed.like/3l6ovf5kmhe2f",
+                json!({
+                    "$type": "app.bsky.feed.like",
+                    "subject": {
+                        "uri": "at://did:plc:z72i7hdynmk6r22z27h6tvur/app.bsky.feed.post/3l6oveex3ii2l",
+                        "cid": "bafyreiadxo7cvcn4qsk6lfwpz5cagrkkd5jjyjzkf5okqu4ykb6bhdwq5a"
+                    },
+                    "createdAt": "2024-10-16T19:31:02.118Z"
+                }),
+            ),
+            (
+                "app.bsky.feed.post/3l6oveex3ii2l",
+                json!({
+                    "$type": "app.bsky.feed.post",
+                    "text": "hello world",
+                    "langs": ["en"],
+                    "createdAt": "2024-10-16T19:25:33.519Z"
+                }),
+            ),
+            (
+                "app.bsky.feed.post/3l7ty3vqgpf2i",
+                json!({
+                    "$type": "app.bsky.feed.post",
+                    "text": "second post",
+                    "createdAt": "2024-10-31T19:01:13.105Z",
+                    "via": "a field bisky does not model"
+                }),
+            ),
+            (
+                "app.bsky.graph.follow/3l6ovtnkcdz2a",
+                json!({
+                    "$type": "app.bsky.graph.follow",
+                    "subject": "did:plc:z72i7hdynmk6r22z27h6tvur",
+                    "createdAt": "2024-10-16T19:40:00.000Z"
+                }),
+            ),
+        ];
+        let mut blocks = Vec::new();
+        let records: Vec<(String, Cid)> = records
+            .into_iter()
+            .map(|(key, record)| {
+                let (cid, data) = block(&record);
+                blocks.push((cid, data));
+                (key.to_string(), cid)
+            })
+            .collect();
+
+        let entry = |index: usize, previous: Option<usize>, t: Option<Cid>| {
+            let key = records[index].0.as_bytes();
+            let p = previous.map_or(0, |previous| {
+                let previous = records[previous].0.as_bytes();
+                key.iter().zip(previous).take_while(|(a, b)| a == b).count()
+            });
+            MstEntry {
+                p,
+                k: key[p..].to_vec(),
+                v: records[index].1,
+                t,
+            }
+        };
+
+        let (left, data) = block(&MstNode {
+            l: None,
+            e: vec![entry(0, None, None)],
+        });
+        blocks.push((left, data));
+        let (right, data) = block(&MstNode {
+            l: None,
+            e: vec![entry(3, None, None)],
+        });
+        blocks.push((right, data));
+        let (root, data) = block(&MstNode {
+            l: Some(left),
+            e: vec![entry(1, None, None), entry(2, Some(1), Some(right))],
+        });
+        blocks.push((root, data));
+
+        let mut commit = bisky::repo::Commit {
+            did: REPO_DID.to_string(),
+            version: 3,
+            data: root,
+            rev: "3l7ty3vsrl52i".to_string(),
+            prev: None,
+            sig: Vec::new(),
+        };
+        commit.sig = sign(&commit.unsigned_bytes().unwrap());
+        let (commit, data) = block(&commit);
+        blocks.push((commit, data));
+
+        Self {
+            commit,
+            blocks,
+            records,
+        }
+    }
+
+    pub fn car(&self) -> Vec<u8> {
+        car(&[self.commit], &self.blocks)
+    }
+}
+
+/// A request received by [`serve`]
+pub struct Request {
+    /// Path with the query string
+    pub path: String,
+    pub body: Vec<u8>,
+}
+
+impl Request {
+    /// Path without the query string
+    pub fn route(&self) -> &str {
+        self.path.split('?').next().unwrap()
+    }
+
+    pub fn json(&self) -> Value {
+        serde_json::from_slice(&self.body).unwrap_or(Value::Null)
+    }
+}
+
+pub struct Response {
+    status: &'static str,
+    content_type: &'static str,
+    body: Vec<u8>,
+}
+
+impl Response {
+    pub fn new(content_type: &'static str, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status: "200 OK",
+            content_type,
+            body: body.into(),
+        }
+    }
+
+    pub fn json(status: &'static str, body: Value) -> Self {
+        Self {
+            status,
+            content_type: "application/json",
+            body: body.to_string().into_bytes(),
+        }
+    }
+}
+
+async fn respond(mut socket: TcpStream, handler: Arc<dyn Fn(Request) -> Response + Send + Sync>) {
+    let mut request = Vec::new();
+    let mut buffer = [0; 4096];
+    let (head, body) = loop {
+        let read = socket.read(&mut buffer).await.unwrap();
+        request.extend_from_slice(&buffer[..read]);
+        if let Some(end) = request.windows(4).position(|window| window == b"\r\n\r\n") {
+            let head = String::from_utf8_lossy(&request[..end]).to_string();
+            let length: usize = head
+                .lines()
+                .find_map(|line| {
+                    line.to_ascii_lowercase()
+                        .strip_prefix("content-length:")
+                        .map(|length| length.trim().parse().unwrap())
+                })
+                .unwrap_or(0);
+            if request.len() >= end + 4 + length {
+                break (head, request[end + 4..end + 4 + length].to_vec());
+            }
+        }
+    };
+    let path = head.split_whitespace().nth(1).unwrap().to_string();
+
+    let response = handler(Request { path, body });
+    let mut bytes = format!(
+        "HTTP/1.1 {}\r\ncontent-type: {}\r\ncontent-length: {}\r\nconnection: close\r\n\r\n",
+        response.status,
+        response.content_type,
+        response.body.len()
+    )
+    .into_bytes();
+    bytes.extend(response.body);
+    socket.write_all(&bytes).await.unwrap();
+}
+
+/// Answer HTTP requests on a local port with `handler`, returns the base URL
+pub async fn serve(handler: impl Fn(Request) -> Response + Send + Sync + 'static) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let handler: Arc<dyn Fn(Request) -> Response + Send + Sync> = Arc::new(handler);
+    tokio::spawn(async move {
+        loop {
+            let (socket, _) = listener.accept().await.unwrap();
+            tokio::spawn(respond(socket, handler.clone()));
+        }
+    });
+    format!("http://{address}")
+}
+
+/// A PDS holding records by `collection/rkey` with their current CID, and every
+/// request it saw as `(path, JSON body)`
+#[derive(Default)]
+pub struct Pds {
+    pub requests: Vec<(String, Value)>,
+    pub records: HashMap<String, (String, Value)>,
+    pub writes: usize,
+    /// Writes another client sneaks in right after our next reads
+    pub interleaved: usize,
+}
+
+impl Pds {
+    /// The stored value at `collection/rkey`
+    pub fn record(&self, key: &str) -> &Value {
+        &self.records[key].1
+    }
+
+    fn write(&mut self, key: String, record: Value) -> Value {
+        self.writes += 1;
+        let cid = format!("bafyrecord{}", self.writes);
+        self.records.insert(key.clone(), (cid.clone(), record));
+        json!({ "uri": format!("at://{REPO_DID}/{key}"), "cid": cid })
+    }
+}
+
+fn query<'a>(request: &'a Request, name: &str) -> Option<&'a str> {
+    let (_, query) = request.path.split_once('?')?;
+    query
+        .split('&')
+        .find_map(|pair| pair.strip_prefix(name)?.strip_prefix('='))
+}
+
+fn respond_xrpc(request: Request, pds: &Mutex<Pds>) -> Response {
+    let body = request.json();
+    let mut pds = pds.lock().unwrap();
+    pds.requests.push((request.path.clone(), body.clone()));
+    let key = match request.route() {
+        "/xrpc/com.atproto.repo.getRecord" => format!(
+            "{}/{}",
+            query(&request, "collection").unwrap(),
+            query(&request, "rkey").unwrap()
+        ),
+        _ => format!(
+            "{}/{}",
+            body["collection"].as_str().unwrap_or_default(),
+            body["rkey"].as_str().unwrap_or_default()
+        ),
+    };
+    let not_found = || {
+        Response::json(
+            "400 Bad Request",
+            json!({ "error": "RecordNotFound", "message": "Could not locate record" }),
+        )
+    };
+    let invalid_swap = || {
+        Response::json(
+            "400 Bad Request",
+            json!({ "error": "InvalidSwap", "message": "Record was at bafyrecord0" }),
+        )
+    };
+
+    match request.route() {
+        "/xrpc/com.atproto.repo.getRecord" => {
+            let current = pds.records.get(&key).cloned();
+            if pds.interleaved > 0 {
+                pds.interleaved -= 1;
+                let mut value = current
+                    .as_ref()
+                    .map_or(json!({}), |(_, value)| value.clone());
+                value["description"] = json!("edited elsewhere");
+                pds.write(key.clone(), value);
+            }
+            let Some((cid, value)) = current else {
+                return not_found();
+            };
+            Response::json(
+                "200 OK",
+                json!({ "uri": format!("at://{REPO_DID}/{key}"), "cid": cid, "value": value }),
+            )
+        }
+        "/xrpc/com.atproto.repo.createRecord" => {
+            let key = match body.get("rkey") {
+                Some(_) => key,
+                None => format!("{}/3l7ty3vsrl52i", body["collection"].as_str().unwrap()),
+            };
+            if pds.records.contains_key(&key) {
+                return Response::json(
+                    "400 Bad Request",
+                    json!({ "error": "InvalidRequest", "message": "Record already exists" }),
+                );
+            }
+            Response::json("200 OK", pds.write(key, body["record"].clone()))
+        }
+        "/xrpc/com.atproto.repo.putRecord" => {
+            let current = pds.records.get(&key).map(|(cid, _)| cid.as_str());
+            if body.get("swapRecord").is_some() && body["swapRecord"].as_str() != current {
+                return invalid_swap();
+            }
+            Response::json("200 OK", pds.write(key, body["record"].clone()))
+        }
+        "/xrpc/com.atproto.repo.deleteRecord" => match pds.records.remove(&key) {
+            Some(_) => Response::json("200 OK", json!({})),
+            None => not_found(),
+        },
+        "/xrpc/com.atproto.repo.applyWrites" => {
+            let head = format!("bafycommit{}", pds.writes);
+            if body.get("swapCommit").is_some() && body["swapCommit"] != head.as_str() {
+                return invalid_swap();
+            }
+            let mut results = Vec::new();
+            for write in body["writes"].as_array().unwrap() {
+                let key = format!(
+                    "{}/{}",
+                    write["collection"].as_str().unwrap(),
+                    write["rkey"].as_str().unwrap_or("3l7ty3vsrl52i")
+                );
+                let result = match write["$type"].as_str().unwrap() {
+                    "com.atproto.repo.applyWrites#delete" => {
+                        pds.records.remove(&key);
+                        json!({ "$type": "com.atproto.repo.applyWrites#deleteResult" })
+                    }
+                    kind => {
+                        let mut result = pds.write(key, write["value"].clone());
+                        result["$type"] = json!(format!("{kind}Result"));
+                        result
+                    }
+                };
+                results.push(result);
+            }
+            let commit =
+                json!({ "cid": format!("bafycommit{}", pds.writes), "rev": "3l7ty3vsrl52i" });
+            Response::json("200 OK", json!({ "commit": commit, "results": results }))
+        }
+        _ => Response::json("404 Not Found", json!({})),
+    }
+}
+
+/// A client logged in as [`REPO_DID`] to a fresh [`Pds`]
+pub async fn pds() -> (Client, Arc<Mutex<Pds>>) {
+    let pds = Arc::new(Mutex::new(Pds::default()));
+    let server = pds.clone();
+    let base = serve(move |request| respond_xrpc(request, &server)).await;
+    let client = ClientBuilder::default()
+        .service(base.parse().unwrap())
+        .session(Some(UserSession {
+            did: REPO_DID.to_string(),
+            ..Default::default()
+        }))
+        .build()
+        .unwrap();
+    (client, pds)
+}
@@ -0,0 +1,290 @@
+//! Grapheme limits, thread splitting and publishing
+use bisky::atproto::{ClientBuilder, UserSession};
+use bisky::bluesky::Bluesky;
+use bisky::composer::{ComposeError, Composer, MAX_GRAPHEMES};
+use bisky::lexicon::app::bsky::embed::{
+    AspectRatio, External, ExternalExternal, Video, VideoCaption,
+};
+use bisky::lexicon::app::bsky::feed::PostEmbed;
+use bisky::lexicon::app::bsky::richtext::FacetFeature;
+use bisky::lexicon::com::atproto::repo::StrongRef;
+use bisky::richtext::RichText;
+use bisky::validation::Validator;
+use common::{serve, Response};
+use serde_json::{json, Value};
+use std::sync::{Arc, Mutex};
+use unicode_segmentation::UnicodeSegmentation;
+
+mod common;
+
+#[test]
+fn grapheme_limit() {
+    // Each family emoji is one grapheme but 25 bytes
+    let family = "👨‍👩‍👧‍👦".repeat(MAX_GRAPHEMES);
+    let posts = Composer::from(family.as_str()).into_posts().unwrap();
+    assert_eq!(posts.len(), 1);
+
+    let result = Composer::from(format!("{family}!").as_str()).into_posts();
+    assert!(matches!(
+        result,
+        Err(ComposeError::TooLong {
+            graphemes: 301,
+            max: 300
+        })
+    ));
+    assert!(matches!(
+        Composer::from(" \n").into_posts(),
+        Err(ComposeError::Empty)
+    ));
+}
+
+#[test]
+fn split_thread() {
+    let sentence = "The quick brown fox jumps over the lazy dog. ";
+    let text = format!(
+        "{}See https://example.com/a/long/path for more. {}#done",
+        sentence.repeat(6),
+        sentence.repeat(8)
+    );
+    let posts = Composer::new(RichText::new(text.as_str()))
+        .split_thread(true)
+        .into_posts()
+        .unwrap();
+
+    assert_eq!(posts.len(), 3);
+    for (i, post) in posts.iter().enumerate() {
+        assert!(post.text.graphemes(true).count() <= MAX_GRAPHEMES);
+        assert!(post.text.ends_with(&format!(" {}/3", i + 1)));
+        assert!(post.reply.is_none());
+    }
+    // Cut at sentence ends
+    assert!(posts[0].text.ends_with(". 1/3"));
+
+    // Every facet lands in some chunk, shifted to that chunk's text
+    let facets: Vec<(&str, &FacetFeature)> = posts
+        .iter()
+        .flat_map(|post| {
+            post.facets.iter().flatten().map(|facet| {
+                let text = &post.text[facet.index.byte_start..facet.index.byte_end];
+                (text, &facet.features[0])
+            })
+        })
+        .collect();
+    assert_eq!(facets.len(), 2);
+    assert_eq!(facets[0].0, "https://example.com/a/long/path");
+    assert_eq!(facets[1].0, "#done");
+
+    let rejoined: Vec<&str> = posts
+        .iter()
+        .flat_map(|post| post.text.rsplit_once(' ').unwrap().0.split_whitespace())
+        .collect();
+    assert_eq!(rejoined, text.split_whitespace().collect::<Vec<_>>());
+}
+
+#[test]
+fn split_without_sentences() {
+    let text = "word ".repeat(200);
+    let posts = Composer::from(text.as_str())
+        .split_thread(true)
+        .numbered(false)
+        .into_posts()
+        .unwrap();
+    assert_eq!(posts.len(), 4);
+    assert!(posts.iter().all(|post| post.text.ends_with("word")));
+}
+
+#[test]
+fn quote() {
+    let quoted = StrongRef {
+        uri: "at://did:plc:z72i7hdynmk6r22z27h6tvur/app.bsky.feed.post/3l6oveex3ii2l".to_string(),
+        cid: "bafyreiadxo7cvcn4qsk6lfwpz5cagrkkd5jjyjzkf5okqu4ykb6bhdwq5a".to_string(),
+    };
+    let external = External {
+        external: ExternalExternal {
+            uri: "https://docs.bsky.app".to_string(),
+            title: "Docs".to_string(),
+            description: String::new(),
+            thumb: None,
+            associated_refs: None,
+        },
+    };
+    let posts = Composer::from("this")
+        .embed(PostEmbed::External(Box::new(external)))
+        .quote(quoted.clone())
+        .into_posts()
+        .unwrap();
+
+    let embed = serde_json::to_value(&posts[0].embed).unwrap();
+    assert_eq!(embed["$type"], "app.bsky.embed.recordWithMedia");
+    assert_eq!(embed["record"]["record"]["uri"], quoted.uri.as_str());
+    assert_eq!(embed["media"]["$type"], "app.bsky.embed.external");
+
+    let posts = Composer::from("this").quote(quoted).into_posts().unwrap();
+    assert!(matches!(posts[0].embed, Some(PostEmbed::Record(_))));
+}
+
+#[test]
+fn quote_with_video() {
+    let quoted = StrongRef {
+        uri: "at://did:plc:z72i7hdynmk6r22z27h6tvur/app.bsky.feed.post/3l6oveex3ii2l".to_string(),
+        cid: "bafyreiadxo7cvcn4qsk6lfwpz5cagrkkd5jjyjzkf5okqu4ykb6bhdwq5a".to_string(),
+    };
+    let blob = |mime_type: &str| {
+        serde_json::from_value(serde_json::json!({
+            "$type": "blob",
+            "ref": { "$link": "bafkreifmgq7xa6l4bmhdrdn6ljqjyszt4rcrqtydvapxdqzeqp4axq7ova" },
+            "mimeType": mime_type,
+            "size": 5301122
+        }))
+        .unwrap()
+    };
+    let video = Video {
+        alt: Some("A quick demo".to_string()),
+        aspect_ratio: Some(AspectRatio {
+            width: 1280,
+            height: 720,
+        }),
+        captions: Some(vec![VideoCaption {
+            file: blob("text/vtt"),
+            lang: "en".to_string(),
+        }]),
+        presentation: None,
+        video: blob("video/mp4"),
+    };
+    let posts = Composer::from("this")
+        .embed(PostEmbed::Video(Box::new(video)))
+        .quote(quoted)
+        .into_posts()
+        .unwrap();
+
+    let media = &serde_json::to_value(&posts[0].embed).unwrap()["media"];
+    assert_eq!(media["$type"], "app.bsky.embed.video");
+    assert_eq!(media["video"]["mimeType"], "video/mp4");
+    assert_eq!(media["captions"][0]["lang"], "en");
+    assert_eq!(media["alt"], "A quick demo");
+    assert_eq!(media["aspectRatio"]["width"], 1280);
+}
+
+#[test]
+fn metadata() {
+    let posts = Composer::from("Hola! Olá! ".repeat(30).as_str())
+        .split_thread(true)
+        .langs(["es", "pt-BR"])
+        .self_labels(["nudity"])
+        .tags(["#art", "sketch"])
+        .into_posts()
+        .unwrap();
+    assert_eq!(posts.len(), 2);
+    for post in &posts {
+        assert_eq!(
+            post.langs.as_deref(),
+            Some(&["es".to_string(), "pt-BR".to_string()][..])
+        );
+    }
+    assert!(posts[1].tags.is_none());
+
+    let json = serde_json::to_value(&posts[0]).unwrap();
+    assert_eq!(
+        json["labels"],
+        serde_json::json!({
+            "$type": "com.atproto.label.defs#selfLabels",
+            "values": [{ "val": "nudity" }]
+        })
+    );
+    assert_eq!(json["tags"], serde_json::json!(["art", "sketch"]));
+    Validator::bundled()
+        .validate_record("app.bsky.feed.post", &posts[0])
+        .unwrap();
+
+    assert!(matches!(
+        Composer::from("hi").langs(["english"]).into_posts(),
+        Err(ComposeError::InvalidLanguage(lang)) if lang == "english"
+    ));
+    assert!(matches!(
+        Composer::from("hi")
+            .langs(["en", "de", "fr", "it"])
+            .into_posts(),
+        Err(ComposeError::TooManyLangs { count: 4, max: 3 })
+    ));
+    assert!(matches!(
+        Composer::from("hi").tags(["#"]).into_posts(),
+        Err(ComposeError::InvalidTag(_))
+    ));
+}
+
+#[cfg(feature = "lang-detect")]
+#[test]
+fn detect_langs() {
+    use bisky::langdetect::DEFAULT_MIN_CONFIDENCE;
+
+    let detect = |text: &str| {
+        Composer::new(RichText::new(text))
+            .detect_langs(DEFAULT_MIN_CONFIDENCE)
+            .into_posts()
+            .unwrap()
+            .remove(0)
+            .langs
+    };
+    let langs = detect("Der schnelle braune Fuchs springt über den faulen Hund, siehe https://example.com/the-quick-brown-fox");
+    assert_eq!(langs, Some(vec!["de".to_string()]));
+    assert_eq!(detect("ok"), None);
+
+    // Explicit languages win
+    let posts = Composer::from("Der schnelle braune Fuchs springt über den faulen Hund")
+        .detect_langs(0.0)
+        .langs(["en"])
+        .into_posts()
+        .unwrap();
+    assert_eq!(posts[0].langs, Some(vec!["en".to_string()]));
+}
+
+#[tokio::test]
+async fn publish_resolves_mentions() {
+    let posts = Arc::new(Mutex::new(Vec::<Value>::new()));
+    let created = posts.clone();
+    let base = serve(move |request| match request.route() {
+        "/xrpc/com.atproto.identity.resolveHandle" => {
+            Response::json("200 OK", json!({ "did": "did:plc:alice" }))
+        }
+        "/xrpc/com.atproto.repo.createRecord" => {
+            let mut posts = created.lock().unwrap();
+            posts.push(request.json()["record"].clone());
+            let uri = format!("at://did:plc:me/app.bsky.feed.post/{}", posts.len());
+            Response::json("200 OK", json!({ "uri": uri, "cid": "bafypost" }))
+        }
+        _ => Response::json("404 Not Found", json!({})),
+    })
+    .await;
+    let client = ClientBuilder::default()
+        .service(base.parse().unwrap())
+        .session(Some(UserSession {
+            did: "did:plc:me".to_string(),
+            ..Default::default()
+        }))
+        .build()
+        .unwrap();
+
+    // the mention lands in the second post of the thread
+    let text = format!(
+        "{}thanks @alice.test!",
+        "The quick brown fox jumps over the lazy dog. ".repeat(7)
+    );
+    let composer = Composer::from(text.as_str()).split_thread(true);
+    Bluesky::new(client)
+        .me()
+        .unwrap()
+        .publish(composer)
+        .await
+        .unwrap();
+
+    let posts = posts.lock().unwrap();
+    assert_eq!(posts.len(), 2);
+    let facet = &posts[1]["facets"][0];
+    assert_eq!(facet["features"][0]["did"], "did:plc:alice");
+    let text = posts[1]["text"].as_str().unwrap().as_bytes();
+    let (start, end) = (
+        facet["index"]["byteStart"].as_u64().unwrap() as usize,
+        facet["index"]["byteEnd"].as_u64().unwrap() as usize,
+    );
+    assert_eq!(&text[start..end], b"@alice.test");
+}
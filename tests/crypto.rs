@@ -0,0 +1,95 @@
+//! Commit signatures over secp256k1 and P-256 keys
+use bisky::car::Blocks;
+use bisky::crypto::{KeyType, PublicKey};
+use bisky::repo::{Commit, CommitVerification, Repo};
+use common::{
+    did_document, k256_multikey, p256_multikey, sign_k256, sign_p256, TestRepo, REPO_DID,
+};
+
+mod common;
+
+async fn commit(sign: fn(&[u8]) -> Vec<u8>) -> Commit {
+    let test = TestRepo::new(sign);
+    let blocks = Blocks::from_bytes(&test.car()).await.unwrap();
+    Repo::open(blocks, &test.commit)
+        .await
+        .unwrap()
+        .commit()
+        .clone()
+}
+
+#[test]
+fn spec_did_keys() {
+    let key = PublicKey::from_did_key("did:key:zQ3shokFTS3brHcDQrn82RUDfCZESWL1ZdCEJwekUDPQiYBme")
+        .unwrap();
+    assert_eq!(key.key_type(), KeyType::Secp256k1);
+    let key = PublicKey::from_did_key("did:key:zDnaembgSGUhZULN2Caob4HLJPaxBh92N7rtH21TErzqf8HQo")
+        .unwrap();
+    assert_eq!(key.key_type(), KeyType::P256);
+    assert!(PublicKey::from_did_key("did:web:example.com").is_err());
+}
+
+#[tokio::test]
+async fn signed_commits() {
+    let k256 = did_document(REPO_DID, "atproto", &k256_multikey());
+    let p256 = did_document(REPO_DID, "atproto", &p256_multikey());
+
+    let signed = commit(sign_k256).await;
+    assert_eq!(signed.verify(&k256).unwrap(), CommitVerification::Valid);
+    assert_eq!(signed.verify(&p256).unwrap(), CommitVerification::Invalid);
+
+    let signed = commit(sign_p256).await;
+    assert_eq!(signed.verify(&p256).unwrap(), CommitVerification::Valid);
+    assert_eq!(signed.verify(&k256).unwrap(), CommitVerification::Invalid);
+}
+
+#[tokio::test]
+async fn forged_commits() {
+    let document = did_document(REPO_DID, "atproto", &k256_multikey());
+    let signed = commit(sign_k256).await;
+
+    // same signature over different content
+    let mut forged = signed.clone();
+    forged.rev = "3l7tzzzzzzz2i".to_string();
+    assert_eq!(
+        forged.verify(&document).unwrap(),
+        CommitVerification::Invalid
+    );
+
+    let mut forged = signed.clone();
+    forged.sig[10] ^= 1;
+    assert_eq!(
+        forged.verify(&document).unwrap(),
+        CommitVerification::Invalid
+    );
+
+    // the high-S twin of a valid signature is valid ECDSA, but not in atproto
+    let signature = k256::ecdsa::Signature::from_slice(&signed.sig).unwrap();
+    let (r, s) = signature.split_scalars();
+    let high_s = k256::ecdsa::Signature::from_scalars(r, -s).unwrap();
+    let mut forged = signed.clone();
+    forged.sig = high_s.to_vec();
+    assert_eq!(
+        forged.verify(&document).unwrap(),
+        CommitVerification::Invalid
+    );
+}
+
+#[tokio::test]
+async fn key_mismatch() {
+    let signed = commit(sign_k256).await;
+    let other = did_document(
+        "did:plc:z72i7hdynmk6r22z27h6tvur",
+        "atproto",
+        &k256_multikey(),
+    );
+    assert_eq!(
+        signed.verify(&other).unwrap(),
+        CommitVerification::KeyMismatch
+    );
+    let labeler = did_document(REPO_DID, "atproto_label", &k256_multikey());
+    assert_eq!(
+        signed.verify(&labeler).unwrap(),
+        CommitVerification::KeyMismatch
+    );
+}
@@ -0,0 +1,159 @@
+//! Decoding subscribeRepos frames
+use bisky::car::Cid;
+use bisky::errors::BiskyError;
+use bisky::firehose::FirehoseEvent;
+use bisky::repo::{CommitVerification, RepoRecord};
+use bisky::subscription::{SubscriptionError, SubscriptionEvent};
+use common::{car, did_document, frame, k256_multikey, sign_k256, TestRepo, REPO_DID};
+use serde::Serialize;
+use serde_json::json;
+
+mod common;
+
+#[tokio::test]
+async fn too_big_commit_without_blocks() {
+    let commit: bisky::lexicon::com::atproto::sync::SubscribeReposCommit =
+        serde_json::from_value(json!({
+            "seq": 4187,
+            "rebase": false,
+            "tooBig": true,
+            "repo": "did:plc:ewvi7nxzyoun6zhxrhs64oiz",
+            "commit": { "$link": "bafyreihvxmdvejkuzvo3k4yl2tvhdsqbqo3hkhppvlvqzvn4ajaktiwbri" },
+            "rev": "3l7ty3vsrl52i",
+            "since": "3l7tq4r2smc2v",
+            "blocks": { "$bytes": "" },
+            "ops": [{
+                "action": "create",
+                "path": "app.bsky.feed.post/3l7ty3vqgpf2i",
+                "cid": { "$link": "bafyreigpvsmvkqlfxzwffbdsqekmk3c4fxuxznrqrskcaunrntfdnlsfhi" }
+            }],
+            "blobs": [],
+            "time": "2024-10-31T19:01:13.105Z"
+        }))
+        .unwrap();
+
+    let event = FirehoseEvent::decode(&frame("#commit", &commit))
+        .await
+        .unwrap();
+    let FirehoseEvent::Commit(commit) = event else {
+        panic!("expected a commit");
+    };
+    assert_eq!(commit.ops.len(), 1);
+    assert_eq!(commit.ops[0].rkey, "3l7ty3vqgpf2i");
+    assert!(commit.ops[0].record.is_none());
+}
+
+#[derive(Serialize)]
+struct Op {
+    action: &'static str,
+    path: &'static str,
+    cid: Option<Cid>,
+}
+
+/// A `#commit` body as a relay encodes it, with real CBOR links and bytes
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Commit {
+    seq: i64,
+    rebase: bool,
+    too_big: bool,
+    repo: &'static str,
+    commit: Cid,
+    rev: &'static str,
+    since: Option<&'static str>,
+    #[serde(with = "serde_bytes")]
+    blocks: Vec<u8>,
+    ops: Vec<Op>,
+    blobs: Vec<Cid>,
+    time: &'static str,
+}
+
+#[tokio::test]
+async fn commit_with_blocks() {
+    let repo = TestRepo::new(sign_k256);
+    let body = Commit {
+        seq: 4188,
+        rebase: false,
+        too_big: false,
+        repo: REPO_DID,
+        commit: repo.commit,
+        rev: "3l7ty3vsrl52i",
+        since: Some("3l7tq4r2smc2v"),
+        blocks: car(&[repo.commit], &repo.blocks),
+        ops: vec![
+            Op {
+                action: "create",
+                path: "app.bsky.feed.post/3l7ty3vqgpf2i",
+                cid: Some(repo.records[2].1),
+            },
+            Op {
+                action: "update",
+                path: "app.bsky.feed.like/3l6ovf5kmhe2f",
+                cid: Some(repo.records[0].1),
+            },
+            Op {
+                action: "delete",
+                path: "app.bsky.graph.follow/3l6ovtnkcdz2b",
+                cid: None,
+            },
+        ],
+        blobs: Vec::new(),
+        time: "2024-10-31T19:01:13.105Z",
+    };
+
+    let event = FirehoseEvent::decode(&frame("#commit", &body))
+        .await
+        .unwrap();
+    assert_eq!(event.seq(), Some(4188));
+    let FirehoseEvent::Commit(mut commit) = event else {
+        panic!("expected a commit");
+    };
+    assert_eq!(commit.commit.repo, REPO_DID);
+    let ops: Vec<(&str, &str, &str)> = commit
+        .ops
+        .iter()
+        .map(|op| (op.action.as_str(), op.collection.as_str(), op.rkey.as_str()))
+        .collect();
+    assert_eq!(
+        ops,
+        [
+            ("create", "app.bsky.feed.post", "3l7ty3vqgpf2i"),
+            ("update", "app.bsky.feed.like", "3l6ovf5kmhe2f"),
+            ("delete", "app.bsky.graph.follow", "3l6ovtnkcdz2b"),
+        ]
+    );
+    let Some(RepoRecord::Post(post)) = &commit.ops[0].record else {
+        panic!("expected the created post");
+    };
+    assert_eq!(post.text, "second post");
+    assert!(matches!(commit.ops[1].record, Some(RepoRecord::Like(_))));
+    assert!(commit.ops[2].cid.is_none() && commit.ops[2].record.is_none());
+
+    let signed = commit.signed_commit().await.unwrap();
+    let document = did_document(REPO_DID, "atproto", &k256_multikey());
+    assert_eq!(signed.verify(&document).unwrap(), CommitVerification::Valid);
+}
+
+#[tokio::test]
+async fn info_error_and_unknown_frames() {
+    let info = json!({ "name": "OutdatedCursor", "message": "Requested cursor exceeded limit" });
+    let event = FirehoseEvent::decode(&frame("#info", &info)).await.unwrap();
+    assert!(event.is_outdated_cursor());
+    assert_eq!(event.seq(), None);
+
+    let event = FirehoseEvent::decode(&frame("#somethingNew", &json!({ "seq": 1 })))
+        .await
+        .unwrap();
+    assert!(matches!(event, FirehoseEvent::Unknown(t) if t == "#somethingNew"));
+
+    let mut error = serde_ipld_dagcbor::to_vec(&json!({ "op": -1 })).unwrap();
+    error.extend(
+        serde_ipld_dagcbor::to_vec(&json!({ "error": "FutureCursor", "message": null })).unwrap(),
+    );
+    let result = FirehoseEvent::decode(&error).await;
+    assert!(matches!(
+        result,
+        Err(BiskyError::SubscriptionError(SubscriptionError::ErrorFrame(frame)))
+            if frame.error == "FutureCursor"
+    ));
+}
@@ -0,0 +1,78 @@
+//! Parsing Jetstream messages, plain and zstd compressed
+use bisky::jetstream::{JetstreamEvent, JetstreamKind, Operation};
+use bisky::repo::RepoRecord;
+use bisky::subscription::SubscriptionEvent;
+
+/// The like from the Jetstream README
+const LIKE: &str = r#"{"did":"did:plc:eygmaihciaxprqvxpfvl6flk","time_us":1725911162329308,"kind":"commit","commit":{"rev":"3l3qo2vutsw2b","operation":"create","collection":"app.bsky.feed.like","rkey":"3l3qo2vuowo2b","record":{"$type":"app.bsky.feed.like","createdAt":"2024-09-09T19:46:02.102Z","subject":{"cid":"bafyreidc6sydkkbchcyg62v77wbhzvb2mvytlmsychqgwf2xojjtirmzj4","uri":"at://did:plc:wa7b35aakoll7hugkrjtf3xf/app.bsky.feed.post/3l3pte3p2e325"}},"cid":"bafyreidwaivazkwu67xztlmuobx35hs2lnfh3kolmgfmucldvhd3sgzcqi"}}"#;
+
+fn commit(event: &JetstreamEvent) -> &bisky::jetstream::JetstreamCommit {
+    match &event.kind {
+        JetstreamKind::Commit(commit) => commit,
+        other => panic!("expected a commit, got {other:?}"),
+    }
+}
+
+#[test]
+fn commits() {
+    let event = JetstreamEvent::parse(LIKE.as_bytes()).unwrap();
+    assert_eq!(event.did, "did:plc:eygmaihciaxprqvxpfvl6flk");
+    assert_eq!(event.seq(), Some(1725911162329308));
+    let like = commit(&event);
+    assert_eq!(like.operation, Operation::Create);
+    assert_eq!(
+        (like.collection.as_str(), like.rkey.as_str()),
+        ("app.bsky.feed.like", "3l3qo2vuowo2b")
+    );
+    let Some(RepoRecord::Like(record)) = &like.record else {
+        panic!("expected a like record");
+    };
+    assert_eq!(
+        record.subject.uri,
+        "at://did:plc:wa7b35aakoll7hugkrjtf3xf/app.bsky.feed.post/3l3pte3p2e325"
+    );
+
+    let delete = r#"{"did":"did:plc:eygmaihciaxprqvxpfvl6flk","time_us":1725911162329309,"kind":"commit","commit":{"rev":"3l3qo2vutsw2c","operation":"delete","collection":"app.bsky.feed.like","rkey":"3l3qo2vuowo2b"}}"#;
+    let event = JetstreamEvent::parse(delete.as_bytes()).unwrap();
+    let delete = commit(&event);
+    assert_eq!(delete.operation, Operation::Delete);
+    assert!(delete.cid.is_none() && delete.record.is_none());
+}
+
+#[test]
+fn identity_and_account() {
+    let identity = r#"{"did":"did:plc:ufbl4k27gp6kzas5glhz7fim","time_us":1725516665234703,"kind":"identity","identity":{"did":"did:plc:ufbl4k27gp6kzas5glhz7fim","handle":"yohenrique.bsky.social","seq":1409752997,"time":"2024-09-05T06:11:04.870Z"}}"#;
+    let event = JetstreamEvent::parse(identity.as_bytes()).unwrap();
+    let JetstreamKind::Identity(identity) = &event.kind else {
+        panic!("expected an identity event");
+    };
+    assert_eq!(identity.handle.as_deref(), Some("yohenrique.bsky.social"));
+
+    let account = r#"{"did":"did:plc:ufbl4k27gp6kzas5glhz7fim","time_us":1725516665333808,"kind":"account","account":{"active":true,"did":"did:plc:ufbl4k27gp6kzas5glhz7fim","seq":1409753013,"time":"2024-09-05T06:11:04.870Z"}}"#;
+    let event = JetstreamEvent::parse(account.as_bytes()).unwrap();
+    assert!(matches!(&event.kind, JetstreamKind::Account(account) if account.active));
+}
+
+#[test]
+fn compressed() {
+    let dictionary = include_bytes!("../assets/jetstream_zstd_dictionary");
+    let compressed = zstd::bulk::Compressor::with_dictionary(3, dictionary)
+        .unwrap()
+        .compress(LIKE.as_bytes())
+        .unwrap();
+    let event = JetstreamEvent::parse(&compressed).unwrap();
+    assert_eq!(commit(&event).rkey, "3l3qo2vuowo2b");
+}
+
+#[test]
+fn subscribe_url() {
+    let service = reqwest::Url::parse("https://jetstream2.us-east.bsky.network").unwrap();
+    let query = [
+        ("wantedCollections", "app.bsky.feed.like".to_string()),
+        ("cursor", "1725911162329308".to_string()),
+    ];
+    assert_eq!(
+        JetstreamEvent::url(&service, &query).unwrap().as_str(),
+        "wss://jetstream2.us-east.bsky.network/subscribe?wantedCollections=app.bsky.feed.like&cursor=1725911162329308"
+    );
+}
@@ -0,0 +1,93 @@
+//! Label signatures checked against the labeler's `#atproto_label` key
+use bisky::errors::BiskyError;
+use bisky::labels::{LabelEvent, LabelVerification, LabelsEvent};
+use bisky::subscription::SubscriptionEvent;
+use common::{did_document, frame, k256_multikey, p256_multikey, sign_k256};
+use serde::Serialize;
+
+mod common;
+
+const LABELER: &str = "did:plc:ar7c4by46qjdydhdevvrndac";
+
+#[derive(Clone, Serialize)]
+struct Label {
+    ver: i64,
+    src: &'static str,
+    uri: &'static str,
+    val: &'static str,
+    cts: &'static str,
+    #[serde(with = "serde_bytes", skip_serializing_if = "Vec::is_empty")]
+    sig: Vec<u8>,
+}
+
+#[derive(Serialize)]
+struct Labels {
+    seq: i64,
+    labels: Vec<Label>,
+}
+
+fn signed(src: &'static str, val: &'static str) -> Label {
+    let mut label = Label {
+        ver: 1,
+        src,
+        uri: "at://did:plc:ewvi7nxzyoun6zhxrhs64oiz/app.bsky.feed.post/3l7ty3vqgpf2i",
+        val,
+        cts: "2024-10-31T19:05:00.000Z",
+        sig: Vec::new(),
+    };
+    label.sig = sign_k256(&serde_ipld_dagcbor::to_vec(&label).unwrap());
+    label
+}
+
+async fn decode(labels: Vec<Label>) -> LabelsEvent {
+    let body = Labels { seq: 12, labels };
+    match LabelEvent::decode(&frame("#labels", &body)).await.unwrap() {
+        LabelEvent::Labels(labels) => labels,
+        other => panic!("expected labels, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn verify_labels() {
+    let mut forged = signed(LABELER, "spam");
+    forged.val = "!takedown";
+    let labels = decode(vec![
+        signed(LABELER, "spam"),
+        forged,
+        signed("did:plc:z72i7hdynmk6r22z27h6tvur", "spam"),
+    ])
+    .await;
+    assert_eq!(labels.seq, 12);
+    assert_eq!(labels.labels[1].val, "!takedown");
+
+    let labeler = did_document(LABELER, "atproto_label", &k256_multikey());
+    assert_eq!(
+        labels.verify(&labeler).unwrap(),
+        [
+            LabelVerification::Valid,
+            LabelVerification::Invalid,
+            LabelVerification::KeyMismatch
+        ]
+    );
+
+    // signed with a different key than the document has
+    let rotated = did_document(LABELER, "atproto_label", &p256_multikey());
+    assert_eq!(
+        labels.verify(&rotated).unwrap()[0],
+        LabelVerification::Invalid
+    );
+    // the repo key is not the label key
+    let repo_key = did_document(LABELER, "atproto", &k256_multikey());
+    assert_eq!(
+        labels.verify(&repo_key).unwrap()[0],
+        LabelVerification::KeyMismatch
+    );
+}
+
+#[tokio::test]
+async fn unusable_label_key() {
+    let labels = decode(vec![signed(LABELER, "spam")]).await;
+    let labeler = did_document(LABELER, "atproto_label", "not multibase");
+    let error: BiskyError = labels.verify(&labeler).unwrap_err().into();
+    assert!(matches!(error, BiskyError::CryptoError(_)));
+}
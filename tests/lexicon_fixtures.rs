@@ -0,0 +1,277 @@
+//! Round-trip captured API responses through the lexicon types and compare with the
+//! original JSON.
+use bisky::lexicon::app::bsky::actor::GetProfileOutput;
+use bisky::lexicon::app::bsky::embed::{
+    RecordView, RecordViewRecordDef, RecordViewRecordEmbed, RecordWithMediaViewMedia,
+};
+use bisky::lexicon::app::bsky::feed::{
+    FeedViewPostReason, GetAuthorFeedOutput, GetLikesOutput, GetPostThreadOutput,
+    GetPostThreadOutputThread, GetPostsOutput, Post, PostEmbed, PostViewEmbed, ThreadViewPostReply,
+    ThreadgateAllowItem,
+};
+use bisky::lexicon::app::bsky::graph::GetFollowsOutput;
+use bisky::lexicon::app::bsky::notification::{GetUnreadCountOutput, ListNotificationsOutput};
+use bisky::lexicon::com::atproto::repo::ListRecordsOutput;
+use bisky::lexicon::{Datetime, KnownRecord};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::path::Path;
+
+fn fixture_json(fixture: &str) -> Value {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(fixture);
+    serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+fn round_trip<T: DeserializeOwned + Serialize>(fixture: &str) -> T {
+    let json = fixture_json(fixture);
+    let typed: T = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&typed).unwrap(), json, "{fixture}");
+    typed
+}
+
+#[test]
+fn get_profile() {
+    let profile = round_trip::<GetProfileOutput>("app.bsky.actor.getProfile.json");
+    assert_eq!(profile.display_name.as_deref(), Some("Bluesky"));
+    assert_eq!(profile.followers_count, Some(23450123));
+    assert!(profile.indexed_at.is_some());
+    assert!(profile.pinned_post.is_some());
+    let viewer = profile.viewer.unwrap();
+    assert_eq!(
+        (viewer.muted, viewer.blocked_by),
+        (Some(false), Some(false))
+    );
+}
+
+#[test]
+fn get_follows() {
+    let follows = round_trip::<GetFollowsOutput>("app.bsky.graph.getFollows.json");
+    assert_eq!(follows.follows.len(), 2);
+    assert_eq!(
+        follows.follows[1].labels.as_ref().unwrap()[0].val,
+        "!no-unauthenticated"
+    );
+    assert!(follows.subject.indexed_at.is_some());
+    let viewer = follows.follows[0].viewer.as_ref().unwrap();
+    assert!(viewer.following.is_some() && viewer.followed_by.is_some());
+    assert_eq!(viewer.known_followers.as_ref().unwrap().count, 2);
+    assert!(follows.follows[1]
+        .viewer
+        .as_ref()
+        .unwrap()
+        .following
+        .is_none());
+}
+
+#[test]
+fn get_likes() {
+    round_trip::<GetLikesOutput>("app.bsky.feed.getLikes.json");
+}
+
+#[test]
+fn get_post_thread() {
+    let output = round_trip::<GetPostThreadOutput>("app.bsky.feed.getPostThread.json");
+    let GetPostThreadOutputThread::ThreadViewPost(thread) = output.thread else {
+        panic!("expected a threadViewPost");
+    };
+    assert!(thread.post.record.embed.is_some());
+    assert_eq!(thread.post.record.facets.as_ref().map(Vec::len), Some(1));
+    assert_eq!(thread.post.like_count, Some(8851));
+    let viewer = thread.post.viewer.as_ref().unwrap();
+    assert!(viewer.like.is_some() && viewer.repost.is_none());
+    assert_eq!(viewer.reply_disabled, Some(true));
+    let threadgate = thread.post.threadgate.as_ref().unwrap();
+    let allow = threadgate
+        .record
+        .as_ref()
+        .unwrap()
+        .allow
+        .as_deref()
+        .unwrap();
+    assert!(matches!(allow, [ThreadgateAllowItem::FollowingRule(_)]));
+
+    let output = round_trip::<GetPostThreadOutput>("app.bsky.feed.getPostThread.notFound.json");
+    assert!(matches!(
+        output.thread,
+        GetPostThreadOutputThread::NotFoundPost(_)
+    ));
+}
+
+#[test]
+fn unknown_thread_members_and_embeds() {
+    let fixture = "app.bsky.feed.getPostThread.unknownTypes.json";
+    let output = round_trip::<GetPostThreadOutput>(fixture);
+    let GetPostThreadOutputThread::ThreadViewPost(thread) = output.thread else {
+        panic!("expected a threadViewPost");
+    };
+    let json = fixture_json(fixture);
+
+    let Some(PostEmbed::Unknown { r#type, raw }) = &thread.post.record.embed else {
+        panic!("expected an unknown record embed");
+    };
+    assert_eq!(r#type, "app.bsky.embed.poll");
+    assert_eq!(raw, &json["thread"]["post"]["record"]["embed"]);
+
+    let Some(PostViewEmbed::Unknown { r#type, raw }) = &thread.post.embed else {
+        panic!("expected an unknown embed view");
+    };
+    assert_eq!(r#type, "app.bsky.embed.poll#view");
+    assert_eq!(raw, &json["thread"]["post"]["embed"]);
+
+    let replies = thread.replies.as_deref().unwrap();
+    assert!(matches!(replies[0], ThreadViewPostReply::ThreadViewPost(_)));
+    let ThreadViewPostReply::Unknown { r#type, raw } = &replies[1] else {
+        panic!("expected an unknown reply");
+    };
+    assert_eq!(r#type, "app.bsky.feed.defs#collapsedPost");
+    assert_eq!(raw, &json["thread"]["replies"][1]);
+    assert_eq!(serde_json::to_value(&replies[1]).unwrap(), *raw);
+}
+
+#[test]
+fn get_author_feed() {
+    let feed = round_trip::<GetAuthorFeedOutput>("app.bsky.feed.getAuthorFeed.json");
+    assert!(matches!(
+        feed.feed[0].reason,
+        Some(FeedViewPostReason::ReasonRepost(_))
+    ));
+    assert!(matches!(
+        feed.feed[1].reason,
+        Some(FeedViewPostReason::ReasonPin(_))
+    ));
+    assert!(feed.feed[1].post.record.reply.is_some());
+
+    let embeds: Vec<_> = feed.feed.iter().map(|item| &item.post.embed).collect();
+    assert!(matches!(embeds[0], Some(PostViewEmbed::ExternalView(_))));
+    assert!(embeds[1].is_none());
+    let Some(PostViewEmbed::ImagesView(images)) = embeds[2] else {
+        panic!("expected an images view");
+    };
+    let aspect_ratio = images.images[0].aspect_ratio.as_ref().unwrap();
+    assert_eq!((aspect_ratio.width, aspect_ratio.height), (1920, 1080));
+    let Some(PostViewEmbed::VideoView(video)) = embeds[3] else {
+        panic!("expected a video view");
+    };
+    assert!(video.playlist.ends_with("playlist.m3u8"));
+}
+
+#[test]
+fn list_notifications() {
+    let output = round_trip::<ListNotificationsOutput<KnownRecord>>(
+        "app.bsky.notification.listNotifications.json",
+    );
+    let types: Vec<_> = output
+        .notifications
+        .iter()
+        .map(|notification| notification.record.type_name())
+        .collect();
+    assert_eq!(
+        types,
+        [
+            "app.bsky.feed.like",
+            "app.bsky.graph.follow",
+            "app.bsky.feed.post",
+            "app.bsky.graph.starterpack"
+        ]
+    );
+}
+
+#[test]
+fn unknown_notification_record() {
+    let fixture = "app.bsky.notification.listNotifications.unknownTypes.json";
+    let output = round_trip::<ListNotificationsOutput<KnownRecord>>(fixture);
+    assert!(matches!(
+        output.notifications[0].record,
+        KnownRecord::Like(_)
+    ));
+    let record = &output.notifications[1].record;
+    let KnownRecord::Unknown { r#type, raw } = record else {
+        panic!("expected an unknown record");
+    };
+    assert_eq!(r#type, "app.bsky.feed.vote");
+    assert_eq!(raw, &fixture_json(fixture)["notifications"][1]["record"]);
+    assert_eq!(serde_json::to_value(record).unwrap(), *raw);
+}
+
+#[test]
+fn get_unread_count() {
+    round_trip::<GetUnreadCountOutput>("app.bsky.notification.getUnreadCount.json");
+}
+
+#[test]
+fn list_records() {
+    let output = round_trip::<ListRecordsOutput<Post>>("com.atproto.repo.listRecords.json");
+    let post = &output.records[0].value;
+    assert_eq!(post.tags.as_deref(), Some(&["release".to_string()][..]));
+    assert!(post.extra.contains_key("via"));
+
+    let created_at = &output.records[1].value.created_at;
+    assert_eq!(created_at.as_str(), "2024-10-16T09:12:44+00:00");
+    assert_eq!(created_at.timestamp_subsec_nanos(), 0);
+}
+
+#[test]
+fn datetime_now_has_milliseconds() {
+    for _ in 0..100 {
+        let now = Datetime::now();
+        let (_, fraction) = now.as_str().split_once('.').unwrap();
+        assert_eq!(fraction.len(), "123Z".len(), "{now}");
+        assert!(fraction.ends_with('Z'));
+    }
+}
+
+#[test]
+fn record_embed_views() {
+    let output = round_trip::<GetPostsOutput>("app.bsky.feed.getPosts.json");
+    let views: Vec<_> = output
+        .posts
+        .into_iter()
+        .map(|post| post.embed.unwrap())
+        .collect();
+    let PostViewEmbed::RecordView(view) = &views[0] else {
+        panic!("expected a record view");
+    };
+    let RecordViewRecordDef::ViewRecord(quoted) = &view.record else {
+        panic!("expected a quoted post");
+    };
+    assert_eq!(quoted.quote_count, Some(521));
+    assert!(matches!(
+        quoted.embeds.as_deref(),
+        Some([RecordViewRecordEmbed::ImagesView(_)])
+    ));
+
+    let PostViewEmbed::RecordWithMediaView(with_media) = &views[1] else {
+        panic!("expected recordWithMedia");
+    };
+    assert!(matches!(
+        with_media.record.record,
+        RecordViewRecordDef::ViewNotFound(_)
+    ));
+    assert!(matches!(
+        with_media.media,
+        RecordWithMediaViewMedia::ExternalView(_)
+    ));
+
+    let quoted: Vec<&str> = views[2..]
+        .iter()
+        .map(|view| match view {
+            PostViewEmbed::RecordView(view) => {
+                let RecordView { record } = view.as_ref();
+                record.type_name()
+            }
+            other => other.type_name(),
+        })
+        .collect();
+    assert_eq!(
+        quoted,
+        [
+            "app.bsky.embed.record#viewBlocked",
+            "app.bsky.embed.record#viewDetached",
+            "app.bsky.feed.defs#generatorView",
+            "app.bsky.graph.defs#listView"
+        ]
+    );
+}
@@ -0,0 +1,94 @@
+//! Link cards fetched from a local HTTP server that also plays the PDS
+use bisky::atproto::{ClientBuilder, UserSession};
+use bisky::errors::BiskyError;
+use bisky::lexicon::app::bsky::feed::PostEmbed;
+use bisky::linkcard::{LinkCard, LinkCardError, ReqwestFetcher};
+use common::{serve, Request, Response};
+use serde_json::json;
+
+mod common;
+
+const PAGE: &str = r#"<!doctype html>
+<html><head>
+<title>Fallback title</title>
+<meta charset="utf-8">
+<meta property="og:title" content="Pinned Posts &amp; More">
+<meta name="description" content="plain description">
+<meta property="og:description" content='Pin a post to the "top" of your profile > everything else'>
+<meta property="og:image" content="/thumb.png?size=large&amp;v=2">
+</head><body></body></html>"#;
+
+fn respond(request: Request) -> Response {
+    match request.path.as_str() {
+        "/page" => Response::new("text/html; charset=utf-8", PAGE),
+        "/thumb.png?size=large&v=2" => Response::new("image/png", "\u{89}PNG fake"),
+        "/notes.txt" => Response::new("text/plain", "just text"),
+        "/xrpc/com.atproto.repo.uploadBlob" => Response::json(
+            "200 OK",
+            json!({
+                "blob": {
+                    "$type": "blob",
+                    "ref": { "$link": "bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku" },
+                    "mimeType": "image/png",
+                    "size": request.body.len()
+                }
+            }),
+        ),
+        _ => Response::new("text/plain", ""),
+    }
+}
+
+#[tokio::test]
+async fn card_with_thumbnail() {
+    let base = serve(respond).await;
+    let fetcher = ReqwestFetcher::default();
+    let card = LinkCard::fetch(&fetcher, &format!("{base}/page"))
+        .await
+        .unwrap();
+    assert_eq!(
+        card,
+        LinkCard {
+            uri: format!("{base}/page"),
+            title: "Pinned Posts & More".to_string(),
+            description: "Pin a post to the \"top\" of your profile > everything else".to_string(),
+            image: Some(format!("{base}/thumb.png?size=large&v=2")),
+        }
+    );
+
+    let mut client = ClientBuilder::default()
+        .service(base.parse().unwrap())
+        .session(Some(UserSession::default()))
+        .build()
+        .unwrap();
+    let PostEmbed::External(external) = card.into_embed(&mut client, &fetcher).await.unwrap()
+    else {
+        panic!("expected an external embed");
+    };
+    let thumb = external.external.thumb.unwrap();
+    assert_eq!(
+        thumb.r#ref.0.to_string(),
+        "bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku"
+    );
+    assert_eq!(thumb.size, "\u{89}PNG fake".len());
+}
+
+#[tokio::test]
+async fn not_html() {
+    let base = serve(respond).await;
+    let result = LinkCard::fetch(&ReqwestFetcher::default(), &format!("{base}/notes.txt")).await;
+    assert!(matches!(
+        result,
+        Err(BiskyError::LinkCardError(LinkCardError::NotHtml { .. }))
+    ));
+}
+
+#[test]
+fn title_fallback() {
+    let card = LinkCard::parse(
+        "https://example.com/a/",
+        "<TITLE>Only &#x1F980; a title</TITLE><meta name=twitter:image content=img.jpg>",
+    );
+    assert_eq!(card.title, "Only 🦀 a title");
+    assert_eq!(card.description, "");
+    assert_eq!(card.image.as_deref(), Some("https://example.com/a/img.jpg"));
+}
@@ -0,0 +1,260 @@
+//! Record reads and writes against a local server playing the PDS
+use bisky::errors::BiskyError;
+use bisky::lexicon::app::bsky::actor::Profile;
+use bisky::lexicon::com::atproto::repo::ApplyWritesOutputResult;
+use common::{pds, REPO_DID};
+use serde_json::{json, Value};
+
+mod common;
+
+#[tokio::test]
+async fn get_put_delete() {
+    let (mut client, pds) = pds().await;
+    let profile = Profile {
+        display_name: Some("Alice".to_string()),
+        ..Default::default()
+    };
+    let put = client
+        .repo_put_record(
+            REPO_DID,
+            "app.bsky.actor.profile",
+            "self",
+            &profile,
+            Some(true),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        put.uri,
+        format!("at://{REPO_DID}/app.bsky.actor.profile/self")
+    );
+
+    let record = client
+        .repo_get_record::<Profile>(REPO_DID, "app.bsky.actor.profile", "self", Some(&put.cid))
+        .await
+        .unwrap();
+    assert_eq!(record.cid.as_deref(), Some(put.cid.as_str()));
+    assert_eq!(record.value, profile);
+
+    // a stale swap is refused and surfaces as the PDS error
+    let result = client
+        .repo_put_record(
+            REPO_DID,
+            "app.bsky.actor.profile",
+            "self",
+            &profile,
+            None,
+            Some("bafystale"),
+            None,
+        )
+        .await;
+    assert!(matches!(result, Err(BiskyError::ApiError(e)) if e.error == "InvalidSwap"));
+
+    client
+        .repo_delete_record(
+            REPO_DID,
+            "app.bsky.actor.profile",
+            "self",
+            Some(&put.cid),
+            None,
+        )
+        .await
+        .unwrap();
+    let result = client
+        .repo_get_record::<Profile>(REPO_DID, "app.bsky.actor.profile", "self", None)
+        .await;
+    assert!(matches!(result, Err(BiskyError::ApiError(e)) if e.error == "RecordNotFound"));
+
+    let pds = pds.lock().unwrap();
+    let (path, put) = &pds.requests[0];
+    assert_eq!(path, "/xrpc/com.atproto.repo.putRecord");
+    assert_eq!(put["validate"], true);
+    assert_eq!(put["record"]["displayName"], "Alice");
+    assert!(put.get("swapRecord").is_none());
+    let (path, _) = &pds.requests[1];
+    assert!(path.starts_with("/xrpc/com.atproto.repo.getRecord?repo=did%3Aplc%3A"));
+    assert!(path.ends_with("&collection=app.bsky.actor.profile&rkey=self&cid=bafyrecord1"));
+    let (_, delete) = &pds.requests[3];
+    assert_eq!(delete["swapRecord"], "bafyrecord1");
+}
+
+#[tokio::test]
+async fn apply_writes() {
+    let (mut client, pds) = pds().await;
+    let profile = Profile {
+        description: Some("hello".to_string()),
+        ..Default::default()
+    };
+    let output = client
+        .repo_apply_writes(REPO_DID)
+        .create("app.bsky.actor.profile", Some("self"), &profile)
+        .create("app.bsky.actor.profile", None, &profile)
+        .validate(false)
+        .swap_commit("bafycommit0")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(output.commit.unwrap().cid, "bafycommit2");
+    let results = output.results.unwrap();
+    assert!(matches!(
+        &results[..],
+        [ApplyWritesOutputResult::CreateResult(a), ApplyWritesOutputResult::CreateResult(b)]
+            if a.cid == "bafyrecord1" && b.uri.ends_with("/app.bsky.actor.profile/3l7ty3vsrl52i")
+    ));
+
+    let output = client
+        .repo_apply_writes(REPO_DID)
+        .update("app.bsky.actor.profile", "self", &profile)
+        .delete("app.bsky.actor.profile", "3l7ty3vsrl52i")
+        .send()
+        .await
+        .unwrap();
+    assert!(matches!(
+        &output.results.unwrap()[..],
+        [ApplyWritesOutputResult::UpdateResult(update), ApplyWritesOutputResult::DeleteResult(_)]
+            if update.cid == "bafyrecord3"
+    ));
+
+    // the head has moved on since bafycommit0
+    let result = client
+        .repo_apply_writes(REPO_DID)
+        .delete("app.bsky.actor.profile", "self")
+        .swap_commit("bafycommit0")
+        .send()
+        .await;
+    assert!(matches!(result, Err(BiskyError::ApiError(e)) if e.error == "InvalidSwap"));
+
+    let pds = pds.lock().unwrap();
+    let (_, first) = &pds.requests[0];
+    assert_eq!(first["repo"], REPO_DID);
+    assert_eq!(first["validate"], false);
+    assert_eq!(first["swapCommit"], "bafycommit0");
+    assert_eq!(
+        first["writes"][0]["$type"],
+        "com.atproto.repo.applyWrites#create"
+    );
+    assert_eq!(first["writes"][0]["rkey"], "self");
+    assert_eq!(first["writes"][0]["value"]["description"], "hello");
+    assert!(first["writes"][1].get("rkey").is_none());
+    let (_, second) = &pds.requests[1];
+    assert!(second.get("validate").is_none());
+    assert!(second.get("swapCommit").is_none());
+    assert_eq!(
+        second["writes"][0]["$type"],
+        "com.atproto.repo.applyWrites#update"
+    );
+    assert_eq!(
+        second["writes"][1],
+        json!({
+            "$type": "com.atproto.repo.applyWrites#delete",
+            "collection": "app.bsky.actor.profile",
+            "rkey": "3l7ty3vsrl52i",
+        })
+    );
+}
+
+#[tokio::test]
+async fn update_record_retries_and_falls_back() {
+    let (mut client, pds) = pds().await;
+    client
+        .repo_put_record(
+            REPO_DID,
+            "app.bsky.actor.profile",
+            "self",
+            &Profile::default(),
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    pds.lock().unwrap().interleaved = 1;
+
+    let mut calls = 0;
+    let output = client
+        .update_record(
+            REPO_DID,
+            "app.bsky.actor.profile",
+            "self",
+            1,
+            |profile: &mut Profile| {
+                calls += 1;
+                profile.display_name = Some("Alice".to_string());
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(calls, 2);
+    assert_eq!(output.cid, "bafyrecord3");
+    {
+        let pds = pds.lock().unwrap();
+        let swaps: Vec<_> = pds.requests[1..]
+            .iter()
+            .filter(|(path, _)| path == "/xrpc/com.atproto.repo.putRecord")
+            .map(|(_, body)| body["swapRecord"].clone())
+            .collect();
+        assert_eq!(swaps, vec![json!("bafyrecord1"), json!("bafyrecord2")]);
+        // the retry kept the other writer's change
+        let (_, record) = &pds.records["app.bsky.actor.profile/self"];
+        assert_eq!(record["description"], "edited elsewhere");
+        assert_eq!(record["displayName"], "Alice");
+    }
+
+    // a missing record starts from the default and is only created if still missing
+    client
+        .update_record(
+            REPO_DID,
+            "app.bsky.actor.profile",
+            "other",
+            0,
+            |profile: &mut Profile| {
+                profile.description = Some("new".to_string());
+            },
+        )
+        .await
+        .unwrap();
+    {
+        let pds = pds.lock().unwrap();
+        let (path, put) = pds.requests.last().unwrap();
+        assert_eq!(path, "/xrpc/com.atproto.repo.putRecord");
+        assert_eq!(put["swapRecord"], Value::Null);
+        assert!(put.as_object().unwrap().contains_key("swapRecord"));
+        assert_eq!(put["record"], json!({ "description": "new" }));
+    }
+
+    // another writer creates the record after our read, so the create-only write loses
+    pds.lock().unwrap().interleaved = 1;
+    client
+        .update_record(
+            REPO_DID,
+            "app.bsky.actor.profile",
+            "raced",
+            1,
+            |profile: &mut Profile| {
+                profile.display_name = Some("Bob".to_string());
+            },
+        )
+        .await
+        .unwrap();
+    {
+        let pds = pds.lock().unwrap();
+        let (_, record) = &pds.records["app.bsky.actor.profile/raced"];
+        assert_eq!(record["description"], "edited elsewhere");
+        assert_eq!(record["displayName"], "Bob");
+    }
+
+    // every attempt loses the race
+    pds.lock().unwrap().interleaved = 2;
+    let result = client
+        .update_record(
+            REPO_DID,
+            "app.bsky.actor.profile",
+            "self",
+            1,
+            |_: &mut Profile| {},
+        )
+        .await;
+    assert!(matches!(result, Err(BiskyError::ApiError(e)) if e.error == "InvalidSwap"));
+}
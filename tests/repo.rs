@@ -0,0 +1,129 @@
+//! Walking the MST of a generated test repo
+use bisky::atproto::{ClientBuilder, UserSession};
+use bisky::bluesky::Bluesky;
+use bisky::car::{Blocks, CarIndex};
+use bisky::lexicon::app::bsky::feed::Post;
+use bisky::repo::{Commit, Repo, RepoError, RepoRecord};
+use common::{block, serve, sign_k256, MstEntry, MstNode, Response, TestRepo, REPO_DID};
+use serde_json::json;
+use std::io::Cursor;
+
+mod common;
+
+#[tokio::test]
+async fn walk_in_key_order() {
+    let test = TestRepo::new(sign_k256);
+    let blocks = Blocks::from_bytes(&test.car()).await.unwrap();
+    let mut repo = Repo::open(blocks, &test.commit).await.unwrap();
+    assert_eq!(repo.did(), REPO_DID);
+    assert_eq!(repo.commit_cid(), &test.commit);
+
+    let mut walk = repo.walk();
+    let mut entries = Vec::new();
+    while let Some(entry) = walk.next().await.unwrap() {
+        entries.push(entry);
+    }
+    let keys: Vec<(String, _)> = entries
+        .iter()
+        .map(|entry| (format!("{}/{}", entry.collection, entry.rkey), entry.cid))
+        .collect();
+    assert_eq!(keys, test.records);
+
+    assert!(matches!(entries[0].record, RepoRecord::Like(_)));
+    let RepoRecord::Post(post) = &entries[1].record else {
+        panic!("expected a post");
+    };
+    assert_eq!(post.text, "hello world");
+    let RepoRecord::Post(post) = &entries[2].record else {
+        panic!("expected a post");
+    };
+    assert_eq!(post.extra["via"], "a field bisky does not model");
+    assert!(matches!(entries[3].record, RepoRecord::Follow(_)));
+}
+
+#[tokio::test]
+async fn records_of_one_collection() {
+    let test = TestRepo::new(sign_k256);
+    let index = CarIndex::build(Cursor::new(test.car())).await.unwrap();
+    let mut repo = Repo::open(index, &test.commit).await.unwrap();
+
+    let posts = repo.records::<Post>("app.bsky.feed.post").await.unwrap();
+    let uris: Vec<&str> = posts.iter().map(|post| post.uri.as_str()).collect();
+    assert_eq!(
+        uris,
+        [
+            format!("at://{REPO_DID}/app.bsky.feed.post/3l6oveex3ii2l"),
+            format!("at://{REPO_DID}/app.bsky.feed.post/3l7ty3vqgpf2i"),
+        ]
+    );
+    assert_eq!(posts[0].cid, test.records[1].1.to_string());
+    assert_eq!(posts[1].value.text, "second post");
+
+    // a like has no text, so it does not decode as a post
+    let likes = repo
+        .try_records::<Post>("app.bsky.feed.like")
+        .await
+        .unwrap();
+    assert!(matches!(likes[..], [Err(RepoError::CarError(_))]));
+    let result = repo.records::<Post>("app.bsky.feed.like").await;
+    assert!(matches!(result, Err(RepoError::CarError(_))));
+}
+
+#[tokio::test]
+async fn list_posts_concurrently() {
+    let car = TestRepo::new(sign_k256).car();
+    let base = serve(move |request| match request.route() {
+        "/xrpc/com.atproto.sync.getRepo" => Response::new("application/vnd.ipld.car", car.clone()),
+        _ => Response::json("404 Not Found", json!({})),
+    })
+    .await;
+    let bluesky = || {
+        Bluesky::new(
+            ClientBuilder::default()
+                .service(base.parse().unwrap())
+                .session(Some(UserSession::default()))
+                .build()
+                .unwrap(),
+        )
+    };
+    let (mut first, mut second) = (bluesky(), bluesky());
+    let mut first = first.user(REPO_DID).unwrap();
+    let mut second = second.user(REPO_DID).unwrap();
+
+    // each listing spools the same repo to its own file
+    let (first, second) = tokio::join!(first.list_posts(), second.list_posts());
+    let (first, second) = (first.unwrap(), second.unwrap());
+    assert_eq!(first, second);
+    let texts: Vec<&str> = first.iter().map(|post| post.value.text.as_str()).collect();
+    assert_eq!(texts, ["second post", "hello world"]);
+}
+
+#[tokio::test]
+async fn prefix_longer_than_previous_key() {
+    let test = TestRepo::new(sign_k256);
+    let (node, data) = block(&MstNode {
+        l: None,
+        e: vec![MstEntry {
+            p: 4,
+            k: b"bsky.feed.post/3l6oveex3ii2l".to_vec(),
+            v: test.records[1].1,
+            t: None,
+        }],
+    });
+    let (commit, commit_data) = block(&Commit {
+        did: REPO_DID.to_string(),
+        version: 3,
+        data: node,
+        rev: "3l7ty3vsrl52i".to_string(),
+        prev: None,
+        sig: vec![0; 64],
+    });
+    let blocks = Blocks {
+        roots: vec![commit],
+        blocks: [(node, data), (commit, commit_data)].into_iter().collect(),
+    };
+
+    let mut repo = Repo::open(blocks, &commit).await.unwrap();
+    let result = repo.walk().next_key().await;
+    assert!(matches!(result, Err(RepoError::InvalidKey(_))));
+}
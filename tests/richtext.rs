@@ -0,0 +1,151 @@
+//! Facet detection, offsets are UTF-8 bytes
+use bisky::lexicon::app::bsky::richtext::{Facet, FacetByteSlice, FacetFeature};
+use bisky::richtext::RichText;
+use serde_json::json;
+
+/// The text each facet covers, with its link or tag
+fn detected(text: &RichText) -> Vec<(&str, String)> {
+    text.facets()
+        .iter()
+        .map(|facet| {
+            let FacetByteSlice {
+                byte_start,
+                byte_end,
+            } = facet.index;
+            let feature = match &facet.features[0] {
+                FacetFeature::Link(link) => link.uri.clone(),
+                FacetFeature::Tag(tag) => format!("tag:{}", tag.tag),
+                other => other.type_name().to_string(),
+            };
+            (&text.text()[byte_start..byte_end], feature)
+        })
+        .collect()
+}
+
+#[test]
+fn links() {
+    let text = RichText::new(
+        "👋🏽 see https://example.com/a?b=c, (https://en.wikipedia.org/wiki/Rust) and bsky.app. e.g. file.txt",
+    );
+    assert_eq!(
+        detected(&text),
+        [
+            (
+                "https://example.com/a?b=c",
+                "https://example.com/a?b=c".to_string()
+            ),
+            (
+                "https://en.wikipedia.org/wiki/Rust",
+                "https://en.wikipedia.org/wiki/Rust".to_string()
+            ),
+            ("bsky.app", "https://bsky.app".to_string()),
+        ]
+    );
+    assert_eq!(text.facets()[0].index.byte_start, "👋🏽 see ".len());
+}
+
+#[test]
+fn tags() {
+    let text = RichText::new("#rust, ＃日本語 #123 #1st a#b #tag.");
+    assert_eq!(
+        detected(&text),
+        [
+            ("#rust", "tag:rust".to_string()),
+            ("＃日本語", "tag:日本語".to_string()),
+            ("#1st", "tag:1st".to_string()),
+            ("#tag", "tag:tag".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn mentions_wait_for_resolution() {
+    let text = RichText::new("hi @alice.bsky.social, @bob and (@carol.example.com)");
+    assert_eq!(
+        text.unresolved_mentions().collect::<Vec<_>>(),
+        ["alice.bsky.social", "carol.example.com"]
+    );
+    assert!(text.facets().is_empty());
+
+    let post = RichText::new("plain text").into_post();
+    assert!(post.facets.is_none());
+}
+
+fn facet(byte_start: usize, byte_end: usize, feature: serde_json::Value) -> Facet {
+    serde_json::from_value(serde_json::json!({
+        "index": {"byteStart": byte_start, "byteEnd": byte_end},
+        "features": [feature]
+    }))
+    .unwrap()
+}
+
+#[test]
+fn render() {
+    let text = "héllo <@bob.test> #rust\nsee example.com";
+    let facets = vec![
+        facet(
+            19,
+            24,
+            json!({"$type": "app.bsky.richtext.facet#tag", "tag": "rust"}),
+        ),
+        facet(
+            8,
+            17,
+            json!({"$type": "app.bsky.richtext.facet#mention", "did": "did:plc:bob"}),
+        ),
+        facet(
+            29,
+            40,
+            json!({"$type": "app.bsky.richtext.facet#link", "uri": "https://example.com"}),
+        ),
+    ];
+    let text = RichText::with_facets(text, facets);
+
+    assert_eq!(
+        text.to_html(),
+        "héllo &lt;<a href=\"https://bsky.app/profile/did:plc:bob\">@bob.test</a>&gt; \
+         <a href=\"https://bsky.app/hashtag/rust\">#rust</a><br>see \
+         <a href=\"https://example.com\">example.com</a>"
+    );
+    assert_eq!(
+        text.to_markdown(),
+        "héllo \\<[@bob.test](https://bsky.app/profile/did:plc:bob)\\> \
+         [\\#rust](https://bsky.app/hashtag/rust)\nsee [example.com](https://example.com)"
+    );
+}
+
+#[test]
+fn bad_facets_are_ignored() {
+    let link = |uri: &str| json!({"$type": "app.bsky.richtext.facet#link", "uri": uri});
+    let facets = vec![
+        // Starts inside the two byte "é"
+        facet(2, 4, link("https://a.example")),
+        facet(0, 3, link("https://b.example")),
+        // Overlaps the facet before it
+        facet(1, 5, link("https://c.example")),
+        facet(5, 99, link("https://d.example")),
+        facet(6, 6, link("https://e.example")),
+        facet(6, 8, link("javascript:alert(1)")),
+        facet(8, 9, json!({"$type": "com.example.facet#sparkle"})),
+    ];
+    let text = RichText::with_facets("héllo there", facets);
+
+    let segments: Vec<(&str, Option<String>)> = text
+        .segments()
+        .map(|segment| (segment.text, segment.href()))
+        .collect();
+    assert_eq!(
+        segments,
+        [
+            ("hé", Some("https://b.example".to_string())),
+            ("llo", None),
+            (" t", None),
+            ("h", None),
+            ("ere", None),
+        ]
+    );
+    assert_eq!(
+        text.to_html(),
+        "<a href=\"https://b.example\">hé</a>llo there"
+    );
+}
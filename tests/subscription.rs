@@ -0,0 +1,161 @@
+//! Reconnecting and cursor handling against a local websocket server
+use bisky::errors::BiskyError;
+use bisky::firehose::FirehoseEvent;
+use bisky::storage::{File, Storage};
+use bisky::subscription::{StorableCursor, SubscriptionConfigBuilder, SubscriptionError};
+use common::frame;
+use futures::SinkExt;
+use serde_json::json;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+use tokio_tungstenite::tungstenite::Message;
+
+mod common;
+
+fn identity(seq: i64) -> Message {
+    let body = json!({
+        "seq": seq,
+        "did": "did:plc:ewvi7nxzyoun6zhxrhs64oiz",
+        "time": "2024-10-31T19:01:13.105Z",
+    });
+    Message::binary(frame("#identity", &body))
+}
+
+fn cursor_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("bisky-{name}-{}.json", std::process::id()))
+}
+
+/// Serve one batch of frames per connection and report each request's query string.
+/// Every connection but the last is dropped without a close handshake
+// the handshake callback's error type is tungstenite's
+#[allow(clippy::result_large_err)]
+async fn serve(batches: Vec<Vec<Message>>) -> (reqwest::Url, mpsc::UnboundedReceiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = reqwest::Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+    let (queries, received) = mpsc::unbounded_channel();
+
+    tokio::spawn(async move {
+        let last = batches.len() - 1;
+        for (i, batch) in batches.into_iter().enumerate() {
+            let (stream, _) = listener.accept().await.unwrap();
+            let queries = queries.clone();
+            let mut socket =
+                tokio_tungstenite::accept_hdr_async(stream, |request: &Request, response| {
+                    let query = request.uri().query().unwrap_or_default().to_string();
+                    queries.send(query).unwrap();
+                    Ok::<Response, _>(response)
+                })
+                .await
+                .unwrap();
+            for message in batch {
+                socket.send(message).await.unwrap();
+            }
+            if i == last {
+                // keep the final connection open so the client has nothing more to read
+                std::future::pending::<()>().await;
+            }
+        }
+    });
+
+    (url, received)
+}
+
+#[tokio::test]
+async fn resumes_from_cursor_after_socket_error() {
+    let path = cursor_path("resume");
+    let _ = std::fs::remove_file(&path);
+    let storage: Arc<dyn StorableCursor> = Arc::new(File::<i64>::new(path.clone()));
+    let (service, mut queries) = serve(vec![
+        vec![identity(1), identity(2)],
+        vec![identity(2), identity(3)],
+    ])
+    .await;
+    let errors = Arc::new(Mutex::new(Vec::new()));
+
+    let mut subscription = SubscriptionConfigBuilder::default()
+        .service(service)
+        .storage(storage)
+        .min_backoff(Duration::from_millis(10))
+        .on_error({
+            let errors = errors.clone();
+            move |e: &BiskyError| errors.lock().unwrap().push(e.to_string())
+        })
+        .build()
+        .unwrap()
+        .subscribe::<FirehoseEvent>()
+        .await
+        .unwrap();
+
+    let mut seqs = Vec::new();
+    for _ in 0..3 {
+        seqs.push(subscription.next().await.unwrap().unwrap().seq().unwrap());
+    }
+    assert_eq!(seqs, vec![1, 2, 3]);
+    assert_eq!(queries.recv().await.unwrap(), "");
+    assert_eq!(queries.recv().await.unwrap(), "cursor=2");
+    assert_eq!(errors.lock().unwrap().len(), 1);
+
+    subscription.checkpoint().await.unwrap();
+    assert_eq!(File::<i64>::new(path.clone()).get().await.unwrap(), 3);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn starts_from_stored_cursor() {
+    let path = cursor_path("stored");
+    std::fs::write(&path, "41").unwrap();
+    let (service, mut queries) = serve(vec![vec![identity(42)]]).await;
+
+    let mut subscription = SubscriptionConfigBuilder::default()
+        .service(service)
+        .storage(Arc::new(File::<i64>::new(path.clone())))
+        .build()
+        .unwrap()
+        .subscribe::<FirehoseEvent>()
+        .await
+        .unwrap();
+
+    assert_eq!(queries.recv().await.unwrap(), "cursor=41");
+    assert_eq!(subscription.next().await.unwrap().unwrap().seq(), Some(42));
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn unreadable_cursor_is_an_error() {
+    let path = cursor_path("corrupt");
+    std::fs::write(&path, "not a cursor").unwrap();
+
+    let result = SubscriptionConfigBuilder::default()
+        .service(reqwest::Url::parse("http://127.0.0.1:9/").unwrap())
+        .storage(Arc::new(File::<i64>::new(path.clone())))
+        .build()
+        .unwrap()
+        .subscribe::<FirehoseEvent>()
+        .await;
+
+    assert!(matches!(result, Err(BiskyError::JsonError(_))));
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn service_without_http_scheme_is_an_error() {
+    // parses with `localhost` as the scheme, which can't become ws(s)
+    let service = reqwest::Url::parse("localhost:8080").unwrap();
+    let result = SubscriptionConfigBuilder::default()
+        .service(service)
+        .build()
+        .unwrap()
+        .subscribe::<FirehoseEvent>()
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(BiskyError::SubscriptionError(
+            SubscriptionError::InvalidUrl(_)
+        ))
+    ));
+}
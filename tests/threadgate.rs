@@ -0,0 +1,163 @@
+//! Reply and quote controls against a local server playing the PDS
+use bisky::bluesky::Bluesky;
+use bisky::composer::{ComposeError, Composer};
+use bisky::lexicon::app::bsky::feed::{
+    PostReplyRef, Postgate, PostgateEmbeddingRule, Threadgate, ThreadgateAllowItem,
+    ThreadgateFollowingRule, ThreadgateListRule,
+};
+use bisky::lexicon::com::atproto::repo::StrongRef;
+use common::{pds, Pds, REPO_DID};
+use serde_json::{json, Value};
+use std::sync::{Arc, Mutex};
+
+mod common;
+
+async fn bluesky() -> (Bluesky, Arc<Mutex<Pds>>) {
+    let (client, pds) = pds().await;
+    (Bluesky::new(client), pds)
+}
+
+#[tokio::test]
+async fn gates_at_post_time() {
+    let (mut bluesky, pds) = bluesky().await;
+    let composer = Composer::from("Followers only")
+        .reply_controls(vec![
+            ThreadgateAllowItem::FollowingRule(Box::new(ThreadgateFollowingRule {})),
+            ThreadgateAllowItem::ListRule(Box::new(ThreadgateListRule {
+                list: format!("at://{REPO_DID}/app.bsky.graph.list/3l6ovtnkcdz2a"),
+            })),
+        ])
+        .quote_controls(vec![PostgateEmbeddingRule::DisableRule(Box::default())]);
+    let created = bluesky.me().unwrap().publish(composer).await.unwrap();
+
+    let pds = pds.lock().unwrap();
+    // The post and its gates land in one commit
+    assert_eq!(pds.requests.len(), 1);
+    let (path, body) = &pds.requests[0];
+    assert_eq!(path, "/xrpc/com.atproto.repo.applyWrites");
+    let writes = body["writes"].as_array().unwrap();
+    let collections: Vec<&str> = writes
+        .iter()
+        .map(|write| write["collection"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        collections,
+        [
+            "app.bsky.feed.post",
+            "app.bsky.feed.threadgate",
+            "app.bsky.feed.postgate"
+        ]
+    );
+    let rkey = writes[0]["rkey"].as_str().unwrap();
+    assert_eq!(rkey.len(), 13);
+    assert!(writes.iter().all(|write| write["rkey"] == rkey));
+    let post = format!("at://{REPO_DID}/app.bsky.feed.post/{rkey}");
+    assert_eq!(created[0].uri, post);
+
+    let threadgate = &writes[1]["value"];
+    assert_eq!(threadgate["post"], post);
+    assert_eq!(
+        threadgate["allow"][0],
+        json!({ "$type": "app.bsky.feed.threadgate#followingRule" })
+    );
+    assert_eq!(
+        threadgate["allow"][1]["$type"],
+        "app.bsky.feed.threadgate#listRule"
+    );
+    let postgate = &writes[2]["value"];
+    assert_eq!(postgate["post"], post);
+    assert_eq!(
+        postgate["embeddingRules"],
+        json!([{ "$type": "app.bsky.feed.postgate#disableRule" }])
+    );
+}
+
+#[tokio::test]
+async fn hide_replies() {
+    let (mut bluesky, pds) = bluesky().await;
+    let reply = "at://did:plc:z72i7hdynmk6r22z27h6tvur/app.bsky.feed.post/3l7tq4qxhxc2v";
+    let mut me = bluesky.me().unwrap();
+    me.hide_reply("3l7ty3vqgpf2i", reply).await.unwrap();
+    me.hide_reply("3l7ty3vqgpf2i", reply).await.unwrap();
+    me.set_reply_controls("3l7ty3vqgpf2i", Some(Vec::new()))
+        .await
+        .unwrap();
+
+    {
+        let pds = pds.lock().unwrap();
+        let gate: Threadgate =
+            serde_json::from_value(pds.record("app.bsky.feed.threadgate/3l7ty3vqgpf2i").clone())
+                .unwrap();
+        assert_eq!(gate.hidden_replies, Some(vec![reply.to_string()]));
+        assert!(gate.allow.unwrap().is_empty());
+        // Updates swap against the version they read
+        assert_eq!(pds.requests.last().unwrap().1["swapRecord"], "bafyrecord2");
+    }
+
+    me.unhide_reply("3l7ty3vqgpf2i", reply).await.unwrap();
+    let pds = pds.lock().unwrap();
+    let gate: Threadgate =
+        serde_json::from_value(pds.record("app.bsky.feed.threadgate/3l7ty3vqgpf2i").clone())
+            .unwrap();
+    assert_eq!(gate.hidden_replies, Some(Vec::new()));
+}
+
+#[tokio::test]
+async fn detach_quotes() {
+    let (mut bluesky, pds) = bluesky().await;
+    let quote = "at://did:plc:z72i7hdynmk6r22z27h6tvur/app.bsky.feed.post/3l7tq4qxhxc2v";
+    let mut me = bluesky.me().unwrap();
+    me.detach_quote("3l7ty3vqgpf2i", quote).await.unwrap();
+    {
+        let pds = pds.lock().unwrap();
+        // A new gate is only created if there still is none
+        let (_, put) = pds.requests.last().unwrap();
+        assert!(put.as_object().unwrap().contains_key("swapRecord"));
+        assert_eq!(put["swapRecord"], Value::Null);
+    }
+    me.detach_quote("3l7ty3vqgpf2i", quote).await.unwrap();
+    me.set_quote_controls(
+        "3l7ty3vqgpf2i",
+        Some(vec![PostgateEmbeddingRule::DisableRule(Box::default())]),
+    )
+    .await
+    .unwrap();
+
+    {
+        let pds = pds.lock().unwrap();
+        let gate: Postgate =
+            serde_json::from_value(pds.record("app.bsky.feed.postgate/3l7ty3vqgpf2i").clone())
+                .unwrap();
+        assert_eq!(
+            gate.post,
+            format!("at://{REPO_DID}/app.bsky.feed.post/3l7ty3vqgpf2i")
+        );
+        assert_eq!(gate.detached_embedding_uris, Some(vec![quote.to_string()]));
+        assert_eq!(gate.embedding_rules.unwrap().len(), 1);
+        assert_eq!(pds.requests.last().unwrap().1["swapRecord"], "bafyrecord2");
+    }
+
+    me.reattach_quote("3l7ty3vqgpf2i", quote).await.unwrap();
+    me.set_quote_controls("3l7ty3vqgpf2i", None).await.unwrap();
+    let pds = pds.lock().unwrap();
+    let gate: Postgate =
+        serde_json::from_value(pds.record("app.bsky.feed.postgate/3l7ty3vqgpf2i").clone()).unwrap();
+    assert_eq!(gate.detached_embedding_uris, Some(Vec::new()));
+    assert!(gate.embedding_rules.is_none());
+}
+
+#[test]
+fn gated_reply() {
+    let post = StrongRef {
+        uri: format!("at://{REPO_DID}/app.bsky.feed.post/3l7ty3vqgpf2i"),
+        cid: "bafypost".to_string(),
+    };
+    let result = Composer::from("no")
+        .reply_to(PostReplyRef {
+            root: post.clone(),
+            parent: post,
+        })
+        .reply_controls(Vec::new())
+        .into_posts();
+    assert!(matches!(result, Err(ComposeError::GatedReply)));
+}
@@ -0,0 +1,18 @@
+//! Record keys generated for gated posts
+use bisky::tid;
+
+#[test]
+fn sortable_and_increasing() {
+    assert_eq!(tid::encode(0), "2222222222222");
+    // the example TID from the atproto spec, 2023-06-30T15:03:01.887007Z with clock id 6
+    assert_eq!(
+        tid::encode(1_688_137_381_887_007 << 10 | 6),
+        "3jzfcijpj2z2a"
+    );
+
+    let tids: Vec<String> = (0..100).map(|_| tid::next()).collect();
+    assert!(tids.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(tids
+        .iter()
+        .all(|tid| tid.len() == 13 && tid.starts_with('3')));
+}
@@ -0,0 +1,204 @@
+//! Records checked against the bundled lexicons
+use bisky::atproto::ClientBuilder;
+use bisky::errors::BiskyError;
+use bisky::lexicon::com::atproto::repo::{ApplyWritesInput, CreateRecordInput, PutRecordInput};
+use bisky::validation::{ValidationError, Validator, Violation, ViolationKind};
+use serde_json::{json, Value};
+
+fn violations(collection: &str, record: Value) -> Vec<Violation> {
+    match Validator::bundled().validate_value(collection, &record) {
+        Ok(()) => Vec::new(),
+        Err(ValidationError::InvalidRecord { violations, .. }) => violations,
+        Err(e) => panic!("{e}"),
+    }
+}
+
+fn image(mime_type: &str, size: u64) -> Value {
+    json!({
+        "alt": "",
+        "image": {
+            "$type": "blob",
+            "ref": {"$link": "bafkreidgy6fnvrvoyamg5jbw6ab2htb6hqfchggxgblbytvicvd7t6bquq"},
+            "mimeType": mime_type,
+            "size": size
+        }
+    })
+}
+
+#[test]
+fn valid_post() {
+    let post = json!({
+        "$type": "app.bsky.feed.post",
+        "text": "hello 👋🏽",
+        "createdAt": "2024-10-16T19:25:33.519Z",
+        "langs": ["en", "pt-BR"],
+        "embed": {"$type": "app.bsky.embed.images", "images": [image("image/png", 1000)]},
+        "reply": {
+            "root": {"uri": "at://did:plc:z72i7hdynmk6r22z27h6tvur/app.bsky.feed.post/3l6oveex3ii2l", "cid": "bafyreiadxo7cvcn4qsk6lfwpz5cagrkkd5jjyjzkf5okqu4ykb6bhdwq5a"},
+            "parent": {"uri": "at://bsky.app/app.bsky.feed.post/3l6oveex3ii2l", "cid": "bafyreiadxo7cvcn4qsk6lfwpz5cagrkkd5jjyjzkf5okqu4ykb6bhdwq5a"}
+        }
+    });
+    assert_eq!(violations("app.bsky.feed.post", post), []);
+}
+
+#[test]
+fn invalid_post() {
+    let post = json!({
+        "text": "👋🏽".repeat(301),
+        "createdAt": "yesterday",
+        "langs": ["not a language"],
+        "embed": {"$type": "app.bsky.embed.images", "images": [image("video/mp4", 3_000_000)]},
+    });
+    let kinds: Vec<(String, ViolationKind)> = violations("app.bsky.feed.post", post)
+        .into_iter()
+        .map(|violation| (violation.path, violation.kind))
+        .collect();
+
+    assert!(kinds.contains(&(
+        "text".into(),
+        ViolationKind::TooManyGraphemes {
+            max: 300,
+            actual: 301
+        }
+    )));
+    assert!(kinds.contains(&(
+        "createdAt".into(),
+        ViolationKind::InvalidFormat("datetime".into())
+    )));
+    assert!(kinds.contains(&(
+        "langs[0]".into(),
+        ViolationKind::InvalidFormat("language".into())
+    )));
+    assert!(kinds.contains(&(
+        "embed.images[0].image".into(),
+        ViolationKind::BlobTooLarge {
+            max: 2_000_000,
+            size: 3_000_000
+        }
+    )));
+    assert!(kinds
+        .iter()
+        .any(|(path, kind)| path == "embed.images[0].image"
+            && matches!(kind, ViolationKind::MimeType { .. })));
+}
+
+#[test]
+fn missing_fields_and_open_unions() {
+    let follow = json!({"createdAt": "2024-10-16T19:25:33Z"});
+    assert_eq!(
+        violations("app.bsky.graph.follow", follow),
+        [Violation {
+            path: "subject".into(),
+            kind: ViolationKind::Missing
+        }]
+    );
+
+    // Embed types newer than the bundled lexicons are let through
+    let post = json!({
+        "text": "",
+        "createdAt": "2024-10-16T19:25:33Z",
+        "embed": {"$type": "app.bsky.embed.somethingNew"}
+    });
+    assert_eq!(violations("app.bsky.feed.post", post), []);
+}
+
+#[test]
+fn unknown_lexicon() {
+    let result = Validator::bundled().validate_value("com.example.record", &json!({}));
+    assert!(matches!(result, Err(ValidationError::UnknownLexicon(_))));
+}
+
+#[tokio::test]
+async fn per_call_validate_overrides_client() {
+    // nothing listens here, a validated write fails before it is sent
+    let mut client = ClientBuilder::default()
+        .service("http://127.0.0.1:9".parse().unwrap())
+        .build()
+        .unwrap();
+    let post = json!({ "text": "no createdAt" });
+
+    let result = client
+        .repo_create_record::<Value, _>("did:plc:test", "app.bsky.feed.post", &post, Some(true))
+        .await;
+    assert!(matches!(result, Err(BiskyError::ValidationError(_))));
+
+    let result = client
+        .repo_put_record(
+            "did:plc:test",
+            "app.bsky.feed.post",
+            "3l6oveex3ii2l",
+            &post,
+            Some(true),
+            None,
+            None,
+        )
+        .await;
+    assert!(matches!(result, Err(BiskyError::ValidationError(_))));
+
+    let result = client
+        .repo_apply_writes("did:plc:test")
+        .create("app.bsky.feed.post", None, &post)
+        .validate(true)
+        .send()
+        .await;
+    assert!(matches!(result, Err(BiskyError::ValidationError(_))));
+
+    let result = client
+        .repo_create_record::<Value, _>("did:plc:test", "app.bsky.feed.post", &post, Some(false))
+        .await;
+    assert!(!matches!(result, Err(BiskyError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn generated_writes_honor_client_validation() {
+    let mut client = ClientBuilder::default()
+        .service("http://127.0.0.1:9".parse().unwrap())
+        .validate_records(true)
+        .build()
+        .unwrap();
+    let post = json!({ "text": "no createdAt" });
+
+    let result = client
+        .com_atproto_repo_create_record(&CreateRecordInput {
+            repo: "did:plc:test".to_string(),
+            collection: "app.bsky.feed.post".to_string(),
+            record: &post,
+            rkey: None,
+            validate: None,
+            swap_commit: None,
+        })
+        .await;
+    assert!(matches!(result, Err(BiskyError::ValidationError(_))));
+
+    let result = client
+        .com_atproto_repo_put_record(&PutRecordInput {
+            repo: "did:plc:test".to_string(),
+            collection: "app.bsky.feed.post".to_string(),
+            rkey: "3l6oveex3ii2l".to_string(),
+            record: &post,
+            validate: None,
+            swap_record: None,
+            swap_commit: None,
+        })
+        .await;
+    assert!(matches!(result, Err(BiskyError::ValidationError(_))));
+
+    let writes = client
+        .repo_apply_writes("did:plc:test")
+        .create("app.bsky.feed.post", None, &post)
+        .writes()
+        .to_vec();
+    let mut input = ApplyWritesInput {
+        repo: "did:plc:test".to_string(),
+        swap_commit: None,
+        validate: None,
+        writes,
+    };
+    let result = client.com_atproto_repo_apply_writes(&input).await;
+    assert!(matches!(result, Err(BiskyError::ValidationError(_))));
+
+    // the input's own flag still wins
+    input.validate = Some(false);
+    let result = client.com_atproto_repo_apply_writes(&input).await;
+    assert!(!matches!(result, Err(BiskyError::ValidationError(_))));
+}
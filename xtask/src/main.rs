@@ -0,0 +1,999 @@
+//! Generates `src/lexicon/generated` from the lexicon schemas vendored in `lexicons/`,
+//! re-exported as `bisky::lexicon::app` and `bisky::lexicon::com`.
+//!
+//! Every record, object, union, token and alias def becomes a Rust type, and every
+//! JSON query and procedure becomes a typed method on `Client`.
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const OUT_DIR: &str = "src/lexicon/generated";
+const ROOT: &str = "crate::lexicon";
+const BLOB: &str = "crate::lexicon::Blob";
+const BYTES: &str = "crate::lexicon::Bytes";
+const LINK: &str = "crate::lexicon::CidLink";
+const DATETIME: &str = "crate::lexicon::Datetime";
+const EXTRA: &str = "crate::lexicon::Extra";
+
+/// `unknown` properties that always hold one kind of record, as (def, property, record)
+const KNOWN_RECORDS: &[(&str, &str, &str)] = &[
+    ("app.bsky.feed.defs#postView", "record", "app.bsky.feed.post"),
+    ("app.bsky.feed.defs#threadgateView", "record", "app.bsky.feed.threadgate"),
+];
+
+/// `unknown` properties holding any record, typed by a parameter `T` that defaults to
+/// `serde_json::Value`. Structs that contain one of these pass the parameter on
+const GENERIC_RECORDS: &[(&str, &str)] = &[
+    ("com.atproto.repo.createRecord#input", "record"),
+    ("com.atproto.repo.getRecord#output", "value"),
+    ("com.atproto.repo.listRecords#record", "value"),
+    ("com.atproto.repo.putRecord#input", "record"),
+    ("app.bsky.notification.listNotifications#notification", "record"),
+];
+
+/// Procedures whose records are checked locally first when the client has
+/// `validate_records` set, see `crate::validation::RecordWrites`
+const VALIDATED_WRITES: &[&str] = &[
+    "com.atproto.repo.applyWrites",
+    "com.atproto.repo.createRecord",
+    "com.atproto.repo.putRecord",
+];
+
+/// Names that would shadow the prelude inside a generated module
+const RESERVED: &[&str] = &["Box", "Option", "Result", "String", "Vec"];
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "dyn", "else", "enum", "extern", "false",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "static", "struct", "trait", "true", "type", "unsafe", "use", "where", "while",
+    "abstract", "become", "box", "do", "final", "macro", "override", "priv", "try", "typeof",
+    "unsized", "virtual", "yield",
+];
+
+fn main() {
+    match std::env::args().nth(1).as_deref() {
+        Some("codegen") => codegen(),
+        _ => {
+            eprintln!("usage: cargo xtask codegen");
+            std::process::exit(1);
+        }
+    }
+}
+
+fn codegen() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let mut lexicons = BTreeMap::new();
+    load(&root.join("lexicons"), &mut lexicons);
+
+    let mut generator = Generator::new(lexicons);
+    generator.assign_names();
+    generator.find_generics();
+    generator.generate();
+
+    let out = root.join(OUT_DIR);
+    if out.exists() {
+        std::fs::remove_dir_all(&out).unwrap();
+    }
+    let files = generator.write(&out);
+    let status = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .args(&files)
+        .status()
+        .expect("rustfmt is required to format the generated code");
+    assert!(status.success(), "rustfmt failed");
+    println!("generated {} files in {OUT_DIR}", files.len());
+}
+
+fn load(dir: &Path, lexicons: &mut BTreeMap<String, Value>) {
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            load(&path, lexicons);
+        } else if path.extension().is_some_and(|ext| ext == "json") {
+            let doc: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap())
+                .unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+            let id = doc["id"].as_str().unwrap().to_string();
+            lexicons.insert(id, doc);
+        }
+    }
+}
+
+fn pascal(name: &str) -> String {
+    let mut out = String::new();
+    let mut upper = true;
+    for c in name.chars() {
+        if !c.is_ascii_alphanumeric() {
+            upper = true;
+        } else if upper {
+            out.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+fn snake(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_ascii_alphanumeric() {
+            if !out.ends_with('_') {
+                out.push('_');
+            }
+            continue;
+        }
+        if c.is_ascii_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|n| n.is_ascii_lowercase());
+            if (prev.is_ascii_lowercase() || prev.is_ascii_digit())
+                || (prev.is_ascii_uppercase() && next_lower)
+            {
+                out.push('_');
+            }
+        }
+        out.push(c.to_ascii_lowercase());
+    }
+    out
+}
+
+/// What serde's `rename_all = "camelCase"` turns a snake_case field into
+fn camel(name: &str) -> String {
+    let mut out = String::new();
+    let mut upper = false;
+    for c in name.trim_start_matches("r#").chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            out.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Type name for the items of an array property, e.g. `features` to `Feature`
+fn singular(field: &str) -> String {
+    let field = pascal(field);
+    if let Some(stem) = field.strip_suffix("ies") {
+        format!("{stem}y")
+    } else if field.ends_with('s') && !field.ends_with("ss") && !field.ends_with("us") {
+        field[..field.len() - 1].to_string()
+    } else {
+        format!("{field}Item")
+    }
+}
+
+fn field_ident(name: &str) -> String {
+    let ident = snake(name);
+    match ident.as_str() {
+        "self" | "super" | "crate" => format!("{ident}_"),
+        _ if KEYWORDS.contains(&ident.as_str()) => format!("r#{ident}"),
+        _ => ident,
+    }
+}
+
+fn doc(out: &mut String, description: Option<&Value>) {
+    if let Some(description) = description.and_then(Value::as_str) {
+        for line in description.lines() {
+            let line = line.trim_end();
+            match line.is_empty() {
+                true => out.push_str("///\n"),
+                false => writeln!(out, "/// {line}").unwrap(),
+            }
+        }
+    }
+}
+
+/// Module path of a lexicon, its id without the last segment
+fn module_of(nsid: &str) -> &str {
+    nsid.rsplit_once('.').map(|(module, _)| module).unwrap_or(nsid)
+}
+
+fn lexicon_name(nsid: &str) -> &str {
+    nsid.rsplit('.').next().unwrap()
+}
+
+fn resolve_ref(nsid: &str, reference: &str) -> (String, String) {
+    match reference.split_once('#') {
+        Some(("", def)) => (nsid.to_string(), def.to_string()),
+        Some((other, def)) => (other.to_string(), def.to_string()),
+        None => (reference.to_string(), "main".to_string()),
+    }
+}
+
+#[derive(Default)]
+struct Module {
+    items: String,
+    client: String,
+    types: HashSet<String>,
+    consts: HashSet<String>,
+}
+
+impl Module {
+    fn unique(&mut self, candidates: &[String]) -> String {
+        for candidate in candidates {
+            if !RESERVED.contains(&candidate.as_str()) && self.types.insert(candidate.clone()) {
+                return candidate.clone();
+            }
+        }
+        let mut name = candidates.last().unwrap().clone();
+        while !self.types.insert(name.clone()) {
+            name.push_str("Def");
+        }
+        name
+    }
+}
+
+struct Endpoint {
+    params: Option<String>,
+    input: Option<String>,
+    output: Option<String>,
+    message: Option<String>,
+}
+
+struct Generator {
+    lexicons: BTreeMap<String, Value>,
+    modules: BTreeMap<String, Module>,
+    /// (lexicon id, def name) to type name
+    names: HashMap<(String, String), String>,
+    endpoints: HashMap<String, Endpoint>,
+    /// (lexicon id, def name) of structs generic over a record, endpoint bodies are
+    /// named `input`, `output` and `message`
+    generics: HashSet<(String, String)>,
+    /// Collection and type path of every record
+    records: Vec<(String, String)>,
+}
+
+impl Generator {
+    fn new(lexicons: BTreeMap<String, Value>) -> Self {
+        Self {
+            lexicons,
+            modules: BTreeMap::new(),
+            names: HashMap::new(),
+            endpoints: HashMap::new(),
+            generics: HashSet::new(),
+            records: Vec::new(),
+        }
+    }
+
+    fn def(&self, nsid: &str, def: &str) -> Option<&Value> {
+        self.lexicons.get(nsid)?.get("defs")?.get(def)
+    }
+
+    fn def_type(&self, nsid: &str, def: &str) -> &str {
+        self.def(nsid, def)
+            .and_then(|schema| schema["type"].as_str())
+            .unwrap_or("")
+    }
+
+    /// Name every def before generating, main defs first so they get the short names
+    fn assign_names(&mut self) {
+        let lexicons = std::mem::take(&mut self.lexicons);
+        for pass in 0..3 {
+            for (nsid, doc) in &lexicons {
+                let lexicon = lexicon_name(nsid);
+                let module = self.modules.entry(module_of(nsid).to_string()).or_default();
+                for (def, schema) in doc["defs"].as_object().unwrap() {
+                    let pass_of = match (def.as_str(), lexicon) {
+                        ("main", _) => 0,
+                        (_, "defs") => 1,
+                        _ => 2,
+                    };
+                    if pass != pass_of {
+                        continue;
+                    }
+
+                    let short = match (def.as_str(), lexicon) {
+                        ("main", _) => pascal(lexicon),
+                        (_, "defs") => pascal(def),
+                        _ => format!("{}{}", pascal(lexicon), pascal(def)),
+                    };
+                    let long = format!("{}{}", pascal(lexicon), pascal(def));
+                    match schema["type"].as_str().unwrap() {
+                        "permission" | "permission-set" => {}
+                        "token" => {}
+                        "query" | "procedure" | "subscription" => {
+                            let has_params = schema["parameters"]["properties"]
+                                .as_object()
+                                .is_some_and(|properties| !properties.is_empty());
+                            let mut name = |suffix: &str, present: bool| {
+                                present.then(|| module.unique(&[format!("{short}{suffix}")]))
+                            };
+                            let endpoint = Endpoint {
+                                params: name("Params", has_params),
+                                input: name("Input", schema["input"].get("schema").is_some()),
+                                output: name("Output", schema["output"].get("schema").is_some()),
+                                message: name("Message", schema["message"].get("schema").is_some()),
+                            };
+                            self.endpoints.insert(nsid.clone(), endpoint);
+                        }
+                        _ => {
+                            let name = module.unique(&[short, long]);
+                            self.names.insert((nsid.clone(), def.clone()), name);
+                        }
+                    }
+                }
+            }
+        }
+        self.lexicons = lexicons;
+    }
+
+    /// Mark every struct that holds a generic record, directly or through a property
+    fn find_generics(&mut self) {
+        for (def, _) in GENERIC_RECORDS {
+            let (nsid, def) = def.split_once('#').unwrap();
+            self.generics.insert((nsid.to_string(), def.to_string()));
+        }
+        loop {
+            let mut found = Vec::new();
+            for (nsid, doc) in &self.lexicons {
+                for (def, schema) in doc["defs"].as_object().unwrap() {
+                    for (def, object) in objects(def, schema) {
+                        let key = (nsid.clone(), def);
+                        if !self.generics.contains(&key) && self.holds_generic(nsid, object) {
+                            found.push(key);
+                        }
+                    }
+                }
+            }
+            if found.is_empty() {
+                break;
+            }
+            self.generics.extend(found);
+        }
+    }
+
+    fn holds_generic(&self, nsid: &str, object: &Value) -> bool {
+        let empty = Map::new();
+        let properties = object["properties"].as_object().unwrap_or(&empty);
+        properties.values().any(|property| {
+            let property = match property["type"] == "array" {
+                true => &property["items"],
+                false => property,
+            };
+            property["ref"].as_str().is_some_and(|reference| {
+                self.generics.contains(&resolve_ref(nsid, reference))
+            })
+        })
+    }
+
+    fn type_path(&self, module: &str, nsid: &str, def: &str) -> Option<String> {
+        let name = self.names.get(&(nsid.to_string(), def.to_string()))?;
+        Some(match module_of(nsid) == module {
+            true => name.clone(),
+            false => format!("{ROOT}::{}::{name}", module_of(nsid).replace('.', "::")),
+        })
+    }
+
+    fn generate(&mut self) {
+        let lexicons = std::mem::take(&mut self.lexicons);
+        for (nsid, doc) in &lexicons {
+            self.lexicons.insert(nsid.clone(), doc.clone());
+        }
+        for (nsid, doc) in &lexicons {
+            for (def, schema) in doc["defs"].as_object().unwrap() {
+                self.generate_def(nsid, def, schema);
+            }
+        }
+    }
+
+    fn generate_def(&mut self, nsid: &str, def: &str, schema: &Value) {
+        let module = module_of(nsid).to_string();
+        let tag = match def {
+            "main" => nsid.to_string(),
+            _ => format!("{nsid}#{def}"),
+        };
+        let mut out = String::new();
+        match schema["type"].as_str().unwrap() {
+            "permission" | "permission-set" => return,
+            "token" => {
+                let lexicon = lexicon_name(nsid);
+                let candidates = [
+                    snake(def).to_uppercase(),
+                    format!("{}_{}", snake(lexicon), snake(def)).to_uppercase(),
+                ];
+                let module = self.modules.get_mut(&module).unwrap();
+                let mut name = candidates
+                    .iter()
+                    .find(|name| !module.consts.contains(*name))
+                    .cloned()
+                    .unwrap_or_else(|| candidates[1].clone());
+                while !module.consts.insert(name.clone()) {
+                    name.push_str("_DEF");
+                }
+                doc(&mut out, schema.get("description"));
+                writeln!(out, "pub const {name}: &str = {tag:?};\n").unwrap();
+            }
+            "query" | "procedure" | "subscription" => {
+                self.generate_endpoint(nsid, schema);
+                return;
+            }
+            "record" => {
+                let name = self.names[&(nsid.to_string(), def.to_string())].clone();
+                let description = schema.get("description");
+                let record = &schema["record"];
+                self.generate_struct(&mut out, nsid, def, &name, description, record, true);
+                let path = self.type_path("", nsid, def).unwrap();
+                self.records.push((nsid.to_string(), path));
+            }
+            "object" => {
+                let name = self.names[&(nsid.to_string(), def.to_string())].clone();
+                let description = schema.get("description");
+                self.generate_struct(&mut out, nsid, def, &name, description, schema, false);
+            }
+            "union" => {
+                let name = self.names[&(nsid.to_string(), def.to_string())].clone();
+                self.generate_union(&mut out, nsid, &name, schema);
+            }
+            _ => {
+                let name = self.names[&(nsid.to_string(), def.to_string())].clone();
+                let ty = match self.field_type(nsid, &name, "", schema, false, false) {
+                    ty if ty == DATETIME => "String".to_string(),
+                    ty => ty,
+                };
+                doc(&mut out, schema.get("description"));
+                writeln!(out, "pub type {name} = {ty};\n").unwrap();
+            }
+        }
+        self.modules.get_mut(&module).unwrap().items.push_str(&out);
+    }
+
+    /// Rust type of a property, generating inline unions next to their owner. Refs to
+    /// generic structs pass on `T` when `generic` is set
+    fn field_type(
+        &mut self,
+        nsid: &str,
+        owner: &str,
+        field: &str,
+        schema: &Value,
+        in_array: bool,
+        generic: bool,
+    ) -> String {
+        let module = module_of(nsid).to_string();
+        match schema["type"].as_str().unwrap_or("unknown") {
+            "string" if schema["format"] == "datetime" => DATETIME.to_string(),
+            "string" => "String".to_string(),
+            "integer" if schema["minimum"].as_i64().is_some_and(|min| min >= 0) => {
+                "usize".to_string()
+            }
+            "integer" => "i64".to_string(),
+            "boolean" => "bool".to_string(),
+            "blob" => BLOB.to_string(),
+            "bytes" => BYTES.to_string(),
+            "cid-link" => LINK.to_string(),
+            "array" => {
+                let item = self.field_type(nsid, owner, field, &schema["items"], true, generic);
+                format!("Vec<{item}>")
+            }
+            "ref" => {
+                let key = resolve_ref(nsid, schema["ref"].as_str().unwrap());
+                let (target, def) = (&key.0, &key.1);
+                match self.def_type(target, def) {
+                    "token" => "String".to_string(),
+                    _ => match self.type_path(&module, target, def) {
+                        Some(path) if generic && self.generics.contains(&key) => {
+                            format!("{path}<T>")
+                        }
+                        Some(path) => path,
+                        None => {
+                            eprintln!("warning: {nsid}: unresolved ref {target}#{def}");
+                            "serde_json::Value".to_string()
+                        }
+                    },
+                }
+            }
+            "union" => {
+                let candidate = match in_array {
+                    true => format!("{owner}{}", singular(field)),
+                    false => format!("{owner}{}", pascal(field)),
+                };
+                let name = self.modules.get_mut(&module).unwrap().unique(&[candidate]);
+                let mut out = String::new();
+                self.generate_union(&mut out, nsid, &name, schema);
+                self.modules.get_mut(&module).unwrap().items.push_str(&out);
+                name
+            }
+            _ => "serde_json::Value".to_string(),
+        }
+    }
+
+    fn generate_union(&mut self, out: &mut String, nsid: &str, name: &str, schema: &Value) {
+        let module = module_of(nsid).to_string();
+        let mut variants: Vec<(String, String, String)> = Vec::new();
+        let mut seen = BTreeSet::new();
+        for reference in schema["refs"].as_array().into_iter().flatten() {
+            let (target, def) = resolve_ref(nsid, reference.as_str().unwrap());
+            let lexicon = lexicon_name(&target);
+            let mut variant = match (def.as_str(), lexicon) {
+                ("main", _) => pascal(lexicon),
+                (_, "defs") => pascal(&def),
+                // `#view` alone says too little next to the other embeds' views
+                _ if target == nsid && def != "view" => pascal(&def),
+                _ => format!("{}{}", pascal(lexicon), pascal(&def)),
+            };
+            if variant == "Unknown" || !seen.insert(variant.clone()) {
+                variant = pascal(&format!("{target}.{def}"));
+                seen.insert(variant.clone());
+            }
+            let tag = match def.as_str() {
+                "main" => target.clone(),
+                _ => format!("{target}#{def}"),
+            };
+            let Some(ty) = self.type_path(&module, &target, &def) else {
+                eprintln!("warning: {nsid}: unresolved union member {tag}");
+                continue;
+            };
+            variants.push((variant, ty, tag));
+        }
+
+        out.push_str("open_union! {\n");
+        let mut docs = String::new();
+        doc(&mut docs, schema.get("description"));
+        for line in docs.lines() {
+            writeln!(out, "    {line}").unwrap();
+        }
+        writeln!(out, "    #[derive(Debug, Clone, PartialEq)]\n    pub enum {name} {{").unwrap();
+        for (variant, ty, tag) in variants {
+            writeln!(out, "        {variant}(Box<{ty}>) = {tag:?},").unwrap();
+        }
+        out.push_str("    }\n}\n\n");
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn generate_struct(
+        &mut self,
+        out: &mut String,
+        nsid: &str,
+        def: &str,
+        name: &str,
+        description: Option<&Value>,
+        schema: &Value,
+        record: bool,
+    ) {
+        let key = format!("{nsid}#{def}");
+        let generic = self.generics.contains(&(nsid.to_string(), def.to_string()));
+        let required = string_set(&schema["required"]);
+        let nullable = string_set(&schema["nullable"]);
+        let empty = Map::new();
+        let properties = schema["properties"].as_object().unwrap_or(&empty);
+
+        let mut fields = String::new();
+        let mut idents = HashSet::new();
+        // with every field optional there is an obvious empty value
+        let mut default = true;
+        for (property, property_schema) in properties {
+            let ident = field_ident(property);
+            idents.insert(ident.clone());
+            let known = KNOWN_RECORDS
+                .iter()
+                .find(|(owner, field, _)| *owner == key && field == property);
+            let ty = if let Some((_, _, record)) = known {
+                let schema = serde_json::json!({ "type": "ref", "ref": record });
+                self.field_type(nsid, name, property, &schema, false, false)
+            } else if generic && GENERIC_RECORDS.contains(&(key.as_str(), property.as_str())) {
+                "T".to_string()
+            } else {
+                self.field_type(nsid, name, property, property_schema, false, generic)
+            };
+
+            let mut attributes = Vec::new();
+            if camel(&ident) != *property {
+                attributes.push(format!("rename = {property:?}"));
+            }
+            let is_required = required.contains(property.as_str());
+            let is_nullable = nullable.contains(property.as_str());
+            let ty = match is_required && !is_nullable {
+                true => {
+                    default = false;
+                    ty
+                }
+                false => {
+                    attributes.push("default".to_string());
+                    if !is_required {
+                        attributes.push("skip_serializing_if = \"Option::is_none\"".to_string());
+                    }
+                    match is_required || !is_nullable {
+                        true => format!("Option<{ty}>"),
+                        // absent is `None`, an explicit null is `Some(None)`
+                        false => {
+                            attributes.push(
+                                "deserialize_with = \"crate::lexicon::data::nullable\"".to_string(),
+                            );
+                            format!("Option<Option<{ty}>>")
+                        }
+                    }
+                }
+            };
+
+            doc(&mut fields, property_schema.get("description"));
+            if !attributes.is_empty() {
+                writeln!(fields, "#[serde({})]", attributes.join(", ")).unwrap();
+            }
+            writeln!(fields, "pub {ident}: {ty},").unwrap();
+        }
+        if record {
+            let extra = match idents.contains("extra") {
+                true => "extra_fields",
+                false => "extra",
+            };
+            writeln!(
+                fields,
+                "#[serde(flatten)]\npub {extra}: {EXTRA},"
+            )
+            .unwrap();
+        }
+
+        let parameters = match generic {
+            true => "<T = serde_json::Value>",
+            false => "",
+        };
+        let derives = match default {
+            true => "Debug, Default, Clone, PartialEq",
+            false => "Debug, Clone, PartialEq",
+        };
+        doc(out, description);
+        writeln!(out, "#[derive({derives}, serde::Deserialize, serde::Serialize)]").unwrap();
+        out.push_str("#[serde(rename_all = \"camelCase\")]\n");
+        writeln!(out, "pub struct {name}{parameters} {{\n{fields}}}\n").unwrap();
+    }
+
+    fn generate_params(&mut self, out: &mut String, name: &str, schema: &Value) {
+        let required = string_set(&schema["required"]);
+        let mut fields = String::new();
+        let mut pairs = String::new();
+        for (property, property_schema) in schema["properties"].as_object().unwrap() {
+            let ident = field_ident(property);
+            let (scalar, array) = match property_schema["type"].as_str().unwrap() {
+                "array" => (property_schema["items"]["type"].as_str().unwrap(), true),
+                other => (other, false),
+            };
+            let scalar = match scalar {
+                "integer" => "i64",
+                "boolean" => "bool",
+                _ => "String",
+            };
+            let is_required = required.contains(property.as_str());
+
+            doc(&mut fields, property_schema.get("description"));
+            match (array, is_required) {
+                (true, _) => {
+                    writeln!(fields, "pub {ident}: Vec<{scalar}>,").unwrap();
+                    writeln!(
+                        pairs,
+                        "for value in &self.{ident} {{ query.push(({property:?}, value.to_string())); }}"
+                    )
+                    .unwrap();
+                }
+                (false, true) => {
+                    writeln!(fields, "pub {ident}: {scalar},").unwrap();
+                    writeln!(
+                        pairs,
+                        "query.push(({property:?}, self.{ident}.to_string()));"
+                    )
+                    .unwrap();
+                }
+                (false, false) => {
+                    writeln!(fields, "pub {ident}: Option<{scalar}>,").unwrap();
+                    writeln!(
+                        pairs,
+                        "if let Some(value) = &self.{ident} {{ query.push(({property:?}, value.to_string())); }}"
+                    )
+                    .unwrap();
+                }
+            }
+        }
+
+        out.push_str("#[derive(Debug, Default, Clone)]\n");
+        writeln!(out, "pub struct {name} {{\n{fields}}}\n").unwrap();
+        writeln!(
+            out,
+            "impl {name} {{\n\
+             /// Query string pairs, arrays repeat their key\n\
+             pub fn query(&self) -> Vec<(&'static str, String)> {{\n\
+             let mut query = Vec::new();\n\
+             {pairs}\
+             query\n\
+             }}\n\
+             }}\n"
+        )
+        .unwrap();
+    }
+
+    /// Input, output or message body: a struct for inline objects, otherwise an alias
+    fn generate_body(
+        &mut self,
+        out: &mut String,
+        nsid: &str,
+        body: &str,
+        name: &str,
+        schema: &Value,
+    ) {
+        match schema["type"].as_str() {
+            Some("object") => self.generate_struct(out, nsid, body, name, None, schema, false),
+            Some("union") => self.generate_union(out, nsid, name, schema),
+            _ => {
+                let ty = self.field_type(nsid, name, "", schema, false, false);
+                writeln!(out, "pub type {name} = {ty};\n").unwrap();
+            }
+        }
+    }
+
+    fn generate_endpoint(&mut self, nsid: &str, schema: &Value) {
+        let module = module_of(nsid).to_string();
+        let endpoint = self.endpoints.remove(nsid).unwrap();
+        let mut out = String::new();
+
+        if let Some(params) = &endpoint.params {
+            self.generate_params(&mut out, params, &schema["parameters"]);
+        }
+        if let Some(input) = &endpoint.input {
+            self.generate_body(&mut out, nsid, "input", input, &schema["input"]["schema"]);
+        }
+        if let Some(output) = &endpoint.output {
+            self.generate_body(&mut out, nsid, "output", output, &schema["output"]["schema"]);
+        }
+        if let Some(message) = &endpoint.message {
+            self.generate_body(&mut out, nsid, "message", message, &schema["message"]["schema"]);
+        }
+        self.modules
+            .get_mut(&module)
+            .unwrap()
+            .items
+            .push_str(&out);
+
+        if let Some(method) = self.generate_method(nsid, schema, &endpoint) {
+            self.modules
+                .get_mut(&module)
+                .unwrap()
+                .client
+                .push_str(&method);
+        }
+    }
+
+    /// Typed XRPC call, `None` for subscriptions and non-JSON responses
+    fn generate_method(&self, nsid: &str, schema: &Value, endpoint: &Endpoint) -> Option<String> {
+        let is_json = |body: &Value| body.is_null() || body["encoding"] == "application/json";
+        let input_json = is_json(&schema["input"]);
+        let output_json = is_json(&schema["output"]);
+        if !output_json {
+            return None;
+        }
+        let generic = |body: &str| self.generics.contains(&(nsid.to_string(), body.to_string()));
+        let (output, bound) = match generic("output") {
+            true => (
+                endpoint.output.as_ref().map(|output| format!("{output}<T>")),
+                "<T: serde::de::DeserializeOwned + std::fmt::Debug>",
+            ),
+            false => (endpoint.output.clone(), ""),
+        };
+        let (input, bound) = match generic("input") {
+            true => (
+                endpoint.input.as_ref().map(|input| format!("{input}<T>")),
+                "<T: serde::Serialize>",
+            ),
+            false => (endpoint.input.clone(), bound),
+        };
+        let output = match (output, schema["output"].is_null()) {
+            (Some(output), _) => Some(output),
+            (None, false) => Some("serde_json::Value".to_string()),
+            (None, true) => None,
+        };
+
+        let mut out = String::new();
+        writeln!(out, "///{nsid}").unwrap();
+        doc(&mut out, schema.get("description"));
+        let method = snake(nsid);
+        let error = "crate::errors::BiskyError";
+        match schema["type"].as_str().unwrap() {
+            "query" => {
+                let output = output.unwrap_or_else(|| "serde_json::Value".to_string());
+                match &endpoint.params {
+                    Some(params) => writeln!(
+                        out,
+                        "pub async fn {method}{bound}(&mut self, params: &{params}) -> Result<{output}, {error}> {{\n\
+                         let query = params.query();\n\
+                         let query: Vec<(&str, &str)> = query.iter().map(|(name, value)| (*name, value.as_str())).collect();\n\
+                         self.xrpc_get({nsid:?}, Some(&query)).await\n\
+                         }}\n"
+                    ),
+                    None => writeln!(
+                        out,
+                        "pub async fn {method}{bound}(&mut self) -> Result<{output}, {error}> {{\n\
+                         self.xrpc_get({nsid:?}, None).await\n\
+                         }}\n"
+                    ),
+                }
+                .unwrap();
+            }
+            "procedure" if !input_json => {
+                let output = output?;
+                writeln!(
+                    out,
+                    "pub async fn {method}(&mut self, body: &[u8], mime_type: &str) -> Result<{output}, {error}> {{\n\
+                     self.xrpc_post_binary({nsid:?}, body, mime_type).await\n\
+                     }}\n"
+                )
+                .unwrap();
+            }
+            "procedure" => {
+                let (argument, body) = match &input {
+                    Some(input) => (format!(", input: &{input}"), "input".to_string()),
+                    None => (String::new(), "&serde_json::json!({})".to_string()),
+                };
+                let validate = match VALIDATED_WRITES.contains(&nsid) {
+                    true => "crate::validation::RecordWrites::validate_writes(input, self.validate_records)?;\n",
+                    false => "",
+                };
+                match output {
+                    Some(output) => writeln!(
+                        out,
+                        "pub async fn {method}{bound}(&mut self{argument}) -> Result<{output}, {error}> {{\n\
+                         {validate}\
+                         self.xrpc_post({nsid:?}, {body}).await\n\
+                         }}\n"
+                    ),
+                    None => writeln!(
+                        out,
+                        "pub async fn {method}{bound}(&mut self{argument}) -> Result<(), {error}> {{\n\
+                         self.xrpc_post_no_response({nsid:?}, {body}).await\n\
+                         }}\n"
+                    ),
+                }
+                .unwrap();
+            }
+            _ => return None,
+        }
+        Some(out)
+    }
+
+    /// Write one file per namespace, returning the paths written
+    fn write(&self, out: &Path) -> Vec<PathBuf> {
+        // Namespace tree, e.g. app -> bsky -> feed
+        let mut children: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        for (module, contents) in &self.modules {
+            if contents.items.is_empty() && contents.client.is_empty() {
+                continue;
+            }
+            let segments: Vec<&str> = module.split('.').collect();
+            for depth in 0..segments.len() {
+                let parent = segments[..depth].join(".");
+                children
+                    .entry(parent)
+                    .or_default()
+                    .insert(segments[depth].to_string());
+            }
+        }
+
+        let mut files = Vec::new();
+        for (module, contents) in &self.modules {
+            if contents.items.is_empty() && contents.client.is_empty() {
+                continue;
+            }
+            let mut source = format!("//! {module}\n// @generated by `cargo xtask codegen`, do not edit\n\n");
+            if let Some(children) = children.get(module) {
+                for child in children {
+                    writeln!(source, "pub mod {child};").unwrap();
+                }
+                source.push('\n');
+            }
+            source.push_str(&contents.items);
+            if !contents.client.is_empty() {
+                write!(source, "impl crate::atproto::Client {{\n{}}}\n", contents.client).unwrap();
+            }
+            let has_children = children.contains_key(module);
+            files.push(write_module(out, module, has_children, &source));
+        }
+
+        for (module, modules) in &children {
+            if self.modules.get(module).is_some_and(|contents| {
+                !contents.items.is_empty() || !contents.client.is_empty()
+            }) {
+                continue;
+            }
+            let mut source = match module.is_empty() {
+                true => "//! Types and XRPC methods generated from the lexicon schemas in `lexicons/`\n\
+                         // @generated by `cargo xtask codegen`, do not edit\n\
+                         #![allow(clippy::doc_lazy_continuation, clippy::enum_variant_names)]\n\
+                         #![allow(clippy::vec_init_then_push)]\n\n"
+                    .to_string(),
+                false => "// @generated by `cargo xtask codegen`, do not edit\n\n".to_string(),
+            };
+            for child in modules {
+                writeln!(source, "pub mod {child};").unwrap();
+            }
+            if module.is_empty() {
+                source.push_str("pub(crate) mod schemas;\n\n");
+                source.push_str(&self.known_records());
+            }
+            files.push(write_module(out, module, true, &source));
+        }
+        files.push(self.write_schemas(out));
+        files
+    }
+
+    /// Union of every record, tagged by its collection
+    fn known_records(&self) -> String {
+        let mut names: HashMap<&str, usize> = HashMap::new();
+        for (nsid, _) in &self.records {
+            *names.entry(lexicon_name(nsid)).or_default() += 1;
+        }
+        let mut out = String::from(
+            "open_union! {\n    \
+             /// A record of any collection in the bundled lexicons\n    \
+             #[derive(Debug, Clone, PartialEq)]\n    \
+             pub enum KnownRecord {\n",
+        );
+        for (nsid, path) in &self.records {
+            let variant = match names[lexicon_name(nsid)] {
+                1 => pascal(lexicon_name(nsid)),
+                _ => pascal(nsid),
+            };
+            let path = path.trim_start_matches(&format!("{ROOT}::"));
+            writeln!(out, "        {variant}(Box<{path}>) = {nsid:?},").unwrap();
+        }
+        out.push_str("    }\n}\n");
+        out
+    }
+
+    /// Embed every lexicon document so the runtime validator needs no files on disk
+    fn write_schemas(&self, out: &Path) -> PathBuf {
+        let mut source = String::from(
+            "//! Lexicon documents bundled for [`crate::validation::Validator`]\n\
+             // @generated by `cargo xtask codegen`, do not edit\n\n\
+             pub(crate) const LEXICONS: &[&str] = &[\n",
+        );
+        for nsid in self.lexicons.keys() {
+            let path = nsid.replace('.', "/");
+            writeln!(source, "include_str!(\"../../../lexicons/{path}.json\"),").unwrap();
+        }
+        source.push_str("];\n");
+        let path = out.join("schemas.rs");
+        std::fs::write(&path, source).unwrap();
+        path
+    }
+}
+
+fn write_module(out: &Path, module: &str, has_children: bool, source: &str) -> PathBuf {
+    let mut path = out.to_path_buf();
+    let segments: Vec<&str> = module.split('.').filter(|s| !s.is_empty()).collect();
+    match has_children {
+        true => {
+            path.extend(&segments);
+            path.push("mod.rs");
+        }
+        false => {
+            path.extend(&segments[..segments.len() - 1]);
+            path.push(format!("{}.rs", segments[segments.len() - 1]));
+        }
+    }
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, source).unwrap();
+    path
+}
+
+/// Object schemas a def declares, keyed like `Generator::generics`
+fn objects<'a>(def: &str, schema: &'a Value) -> Vec<(String, &'a Value)> {
+    match schema["type"].as_str().unwrap() {
+        "record" => vec![(def.to_string(), &schema["record"])],
+        "object" => vec![(def.to_string(), schema)],
+        _ => ["input", "output", "message"]
+            .into_iter()
+            .map(|body| (body.to_string(), &schema[body]["schema"]))
+            .filter(|(_, body)| body["type"] == "object")
+            .collect(),
+    }
+}
+
+fn string_set(value: &Value) -> HashSet<&str> {
+    value
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect()
+}